@@ -0,0 +1,135 @@
+use crate::{error::*, layout::*, *};
+use cauchy::*;
+
+/// Represents the Cholesky factorization of a positive definite banded matrix
+/// as `A = U^H * U` or `A = L * L^H`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct CholeskyFactorizedBanded<A: Scalar> {
+    /// layout of raw matrix
+    pub l: MatrixLayout,
+    /// number of super-diagonals of `U`, or sub-diagonals of `L`
+    pub kd: i32,
+    /// which triangular factor is stored
+    pub uplo: UPLO,
+    /// `(kd + 1) x n` band storage of the triangular factor in column-major order.
+    /// `u[i, j]` is stored in `ab[kd + i - j, j]` for [UPLO::Upper],
+    /// and `l[i, j]` in `ab[i - j, j]` for [UPLO::Lower].
+    pub ab: Vec<A>,
+}
+
+/// Compute Cholesky decomposition of positive definite banded matrix
+///
+/// Only the triangular part of [Banded] specified by `uplo` is referenced,
+/// i.e. the super-diagonals for [UPLO::Upper] and the sub-diagonals for [UPLO::Lower].
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | spbtrf | dpbtrf | cpbtrf | zpbtrf |
+///
+pub trait CholeskyBandedImpl: Scalar {
+    fn cholesky_banded(uplo: UPLO, a: &Banded<Self>) -> Result<CholeskyFactorizedBanded<Self>>;
+}
+
+macro_rules! impl_cholesky_banded {
+    ($s:ty, $trf:path) => {
+        impl CholeskyBandedImpl for $s {
+            fn cholesky_banded(
+                uplo: UPLO,
+                a: &Banded<Self>,
+            ) -> Result<CholeskyFactorizedBanded<Self>> {
+                let (n, _) = a.l.size();
+                let (kd, mut ab) = a.triangle(uplo);
+                let mut info = 0;
+                unsafe {
+                    $trf(
+                        uplo.as_ptr(),
+                        &n,
+                        &kd,
+                        AsPtr::as_mut_ptr(&mut ab),
+                        &(kd + 1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(CholeskyFactorizedBanded {
+                    l: a.l,
+                    kd,
+                    uplo,
+                    ab,
+                })
+            }
+        }
+    };
+}
+impl_cholesky_banded!(c64, lapack_sys::zpbtrf_);
+impl_cholesky_banded!(c32, lapack_sys::cpbtrf_);
+impl_cholesky_banded!(f64, lapack_sys::dpbtrf_);
+impl_cholesky_banded!(f32, lapack_sys::spbtrf_);
+
+/// Solve linear equation `A * x = b` using the Cholesky decomposition of banded matrix
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | spbtrs | dpbtrs | cpbtrs | zpbtrs |
+///
+pub trait SolveCholeskyBandedImpl: Scalar {
+    fn solve_cholesky_banded(
+        f: &CholeskyFactorizedBanded<Self>,
+        bl: MatrixLayout,
+        b: &mut [Self],
+    ) -> Result<()>;
+}
+
+macro_rules! impl_solve_cholesky_banded {
+    ($s:ty, $trs:path) => {
+        impl SolveCholeskyBandedImpl for $s {
+            fn solve_cholesky_banded(
+                f: &CholeskyFactorizedBanded<Self>,
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<()> {
+                let (n, _) = f.l.size();
+                // Transpose if b is C-continuous
+                let mut b_t = None;
+                let b_layout = match b_layout {
+                    MatrixLayout::C { .. } => {
+                        let (layout, t) = transpose(b_layout, b);
+                        b_t = Some(t);
+                        layout
+                    }
+                    MatrixLayout::F { .. } => b_layout,
+                };
+                let (ldb, nrhs) = b_layout.size();
+                let mut info = 0;
+                unsafe {
+                    $trs(
+                        f.uplo.as_ptr(),
+                        &n,
+                        &f.kd,
+                        &nrhs,
+                        AsPtr::as_ptr(&f.ab),
+                        &(f.kd + 1),
+                        AsPtr::as_mut_ptr(b_t.as_mut().map(|v| v.as_mut_slice()).unwrap_or(b)),
+                        &ldb,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                if let Some(b_t) = b_t {
+                    transpose_over(b_layout, &b_t, b);
+                }
+                Ok(())
+            }
+        }
+    };
+}
+impl_solve_cholesky_banded!(c64, lapack_sys::zpbtrs_);
+impl_solve_cholesky_banded!(c32, lapack_sys::cpbtrs_);
+impl_solve_cholesky_banded!(f64, lapack_sys::dpbtrs_);
+impl_solve_cholesky_banded!(f32, lapack_sys::spbtrs_);
@@ -0,0 +1,108 @@
+use crate::*;
+use cauchy::*;
+use num_traits::Zero;
+
+/// Represents the LU factorization of a banded matrix `A` as `A = P*L*U`.
+#[derive(Clone, PartialEq)]
+pub struct LUFactorizedBanded<A: Scalar> {
+    /// layout of raw matrix
+    pub l: MatrixLayout,
+    /// number of sub-diagonals of `A`
+    pub kl: i32,
+    /// number of super-diagonals of `A`
+    pub ku: i32,
+    /// `(2 * kl + ku + 1) x n` band storage of the factors.
+    /// `U` is stored as an upper triangular banded matrix with `kl + ku` super-diagonals
+    /// in the first `kl + ku + 1` rows,
+    /// and the multipliers used during the factorization are stored in the last `kl` rows.
+    pub lu: Vec<A>,
+    /// The pivot indices that define the permutation matrix `P`.
+    pub ipiv: Pivot,
+
+    pub a_opnorm_one: A::Real,
+}
+
+impl<A: Scalar> LUFactorizedBanded<A> {
+    /// Leading dimension of the band storage, i.e. `2 * kl + ku + 1`
+    pub fn ldab(&self) -> i32 {
+        2 * self.kl + self.ku + 1
+    }
+}
+
+pub struct LuBandedWork<T: Scalar> {
+    pub layout: MatrixLayout,
+    pub kl: i32,
+    pub ku: i32,
+    /// `(2 * kl + ku + 1) x n` band storage for the factors
+    pub lu: Vec<T>,
+    pub ipiv: Vec<MaybeUninit<i32>>,
+}
+
+pub trait LuBandedWorkImpl {
+    type Elem: Scalar;
+    fn new(layout: MatrixLayout, kl: i32, ku: i32) -> Self;
+    fn eval(self, a: Banded<Self::Elem>) -> Result<LUFactorizedBanded<Self::Elem>>;
+}
+
+macro_rules! impl_lu_banded_work {
+    ($s:ty, $trf:path) => {
+        impl LuBandedWorkImpl for LuBandedWork<$s> {
+            type Elem = $s;
+
+            fn new(layout: MatrixLayout, kl: i32, ku: i32) -> Self {
+                let (n, _) = layout.size();
+                // `?gbtrf` requires additional `kl` rows on the top of band storage for fill-in
+                let lu = vec![Self::Elem::zero(); ((2 * kl + ku + 1) * n) as usize];
+                let ipiv = vec_uninit(n as usize);
+                LuBandedWork {
+                    layout,
+                    kl,
+                    ku,
+                    lu,
+                    ipiv,
+                }
+            }
+
+            fn eval(mut self, a: Banded<Self::Elem>) -> Result<LUFactorizedBanded<Self::Elem>> {
+                let (n, _) = self.layout.size();
+                assert_eq!((a.kl, a.ku), (self.kl, self.ku));
+                // We have to calc one-norm before LU factorization
+                let a_opnorm_one = Self::Elem::opnorm_banded(NormType::One, &a);
+
+                let ldab = a.ldab() as usize;
+                let ldlu = 2 * self.kl + self.ku + 1;
+                for (dst, src) in self.lu.chunks_mut(ldlu as usize).zip(a.ab.chunks(ldab)) {
+                    dst[a.kl as usize..].copy_from_slice(src);
+                }
+
+                let mut info = 0;
+                unsafe {
+                    $trf(
+                        &n,
+                        &n,
+                        &a.kl,
+                        &a.ku,
+                        AsPtr::as_mut_ptr(&mut self.lu),
+                        &ldlu,
+                        AsPtr::as_mut_ptr(&mut self.ipiv),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                Ok(LUFactorizedBanded {
+                    l: a.l,
+                    kl: a.kl,
+                    ku: a.ku,
+                    lu: self.lu,
+                    ipiv: unsafe { self.ipiv.assume_init() },
+                    a_opnorm_one,
+                })
+            }
+        }
+    };
+}
+
+impl_lu_banded_work!(c64, lapack_sys::zgbtrf_);
+impl_lu_banded_work!(c32, lapack_sys::cgbtrf_);
+impl_lu_banded_work!(f64, lapack_sys::dgbtrf_);
+impl_lu_banded_work!(f32, lapack_sys::sgbtrf_);
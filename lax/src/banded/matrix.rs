@@ -0,0 +1,101 @@
+use crate::{layout::*, UPLO};
+use cauchy::*;
+use std::ops::{Index, IndexMut};
+
+/// Represents a banded matrix in LAPACK band storage.
+///
+/// A `n x n` matrix with `kl` sub-diagonals and `ku` super-diagonals
+/// is stored in a column-major `(kl + ku + 1) x n` matrix `ab`
+/// as `ab[ku + i - j, j] = a[i, j]` for `max(0, j - ku) <= i <= min(n - 1, j + kl)`.
+/// For example, when `n = 5`, `kl = 1`, and `ku = 2`,
+///
+/// ```text
+///   *,   *, a02, a13, a24,
+///   *, a01, a12, a23, a34,
+/// a00, a11, a22, a33, a44,
+/// a10, a21, a32, a43,   *,
+/// ```
+///
+/// where `*` elements are not used.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Banded<A: Scalar> {
+    /// layout of raw matrix
+    pub l: MatrixLayout,
+    /// number of sub-diagonals
+    pub kl: i32,
+    /// number of super-diagonals
+    pub ku: i32,
+    /// `(kl + ku + 1) x n` elements of band storage in column-major order
+    pub ab: Vec<A>,
+}
+
+impl<A: Scalar> Banded<A> {
+    /// Leading dimension of the band storage, i.e. `kl + ku + 1`
+    pub fn ldab(&self) -> i32 {
+        self.kl + self.ku + 1
+    }
+
+    /// Extract the super-diagonals (for [UPLO::Upper]) or the sub-diagonals (for [UPLO::Lower])
+    /// with the diagonal as `(kd + 1) x n` band storage used for symmetric/Hermitian banded matrix,
+    /// and returns it with `kd`
+    pub(crate) fn triangle(&self, uplo: UPLO) -> (i32, Vec<A>) {
+        let ldab = self.ldab() as usize;
+        let (kd, rows) = match uplo {
+            UPLO::Upper => (self.ku, 0..(self.ku as usize + 1)),
+            UPLO::Lower => (self.kl, self.ku as usize..ldab),
+        };
+        let ab = self
+            .ab
+            .chunks(ldab)
+            .flat_map(|col| col[rows.clone()].iter().cloned())
+            .collect();
+        (kd, ab)
+    }
+
+    fn position(&self, row: i32, col: i32) -> usize {
+        let (n, _) = self.l.size();
+        assert!(
+            std::cmp::max(row, col) < n,
+            "ndarray: index {:?} is out of bounds for array of shape {}",
+            [row, col],
+            n
+        );
+        assert!(
+            -self.ku <= row - col && row - col <= self.kl,
+            "ndarray-linalg::banded: index {:?} is not banded element",
+            [row, col]
+        );
+        (self.ku + row - col + col * self.ldab()) as usize
+    }
+}
+
+impl<A: Scalar> Index<(i32, i32)> for Banded<A> {
+    type Output = A;
+    #[inline]
+    fn index(&self, (row, col): (i32, i32)) -> &A {
+        &self.ab[self.position(row, col)]
+    }
+}
+
+impl<A: Scalar> Index<[i32; 2]> for Banded<A> {
+    type Output = A;
+    #[inline]
+    fn index(&self, [row, col]: [i32; 2]) -> &A {
+        &self[(row, col)]
+    }
+}
+
+impl<A: Scalar> IndexMut<(i32, i32)> for Banded<A> {
+    #[inline]
+    fn index_mut(&mut self, (row, col): (i32, i32)) -> &mut A {
+        let pos = self.position(row, col);
+        &mut self.ab[pos]
+    }
+}
+
+impl<A: Scalar> IndexMut<[i32; 2]> for Banded<A> {
+    #[inline]
+    fn index_mut(&mut self, [row, col]: [i32; 2]) -> &mut A {
+        &mut self[(row, col)]
+    }
+}
@@ -0,0 +1,16 @@
+//! Implement linear solver using LU and Cholesky decomposition
+//! for banded matrix
+
+mod cholesky;
+mod lu;
+mod matrix;
+mod opnorm;
+mod rcond;
+mod solve;
+
+pub use cholesky::*;
+pub use lu::*;
+pub use matrix::*;
+pub use opnorm::*;
+pub use rcond::*;
+pub use solve::*;
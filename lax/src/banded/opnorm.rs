@@ -0,0 +1,44 @@
+use crate::*;
+use cauchy::*;
+
+/// Operator norm of banded matrix
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | slangb | dlangb | clangb | zlangb |
+///
+pub trait OperatorNormBandedImpl: Scalar {
+    fn opnorm_banded(t: NormType, a: &Banded<Self>) -> Self::Real;
+}
+
+macro_rules! impl_operator_norm_banded {
+    ($s:ty, $langb:path) => {
+        impl OperatorNormBandedImpl for $s {
+            fn opnorm_banded(t: NormType, a: &Banded<Self>) -> Self::Real {
+                let (n, _) = a.l.size();
+                let mut work: Vec<MaybeUninit<Self::Real>> = match t {
+                    NormType::Infinity => vec_uninit(n as usize),
+                    _ => Vec::new(),
+                };
+                unsafe {
+                    $langb(
+                        t.as_ptr(),
+                        &n,
+                        &a.kl,
+                        &a.ku,
+                        AsPtr::as_ptr(&a.ab),
+                        &a.ldab(),
+                        AsPtr::as_mut_ptr(&mut work),
+                    )
+                }
+            }
+        }
+    };
+}
+impl_operator_norm_banded!(c64, lapack_sys::zlangb_);
+impl_operator_norm_banded!(c32, lapack_sys::clangb_);
+impl_operator_norm_banded!(f64, lapack_sys::dlangb_);
+impl_operator_norm_banded!(f32, lapack_sys::slangb_);
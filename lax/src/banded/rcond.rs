@@ -0,0 +1,114 @@
+use crate::*;
+use cauchy::*;
+use num_traits::Zero;
+
+pub struct RcondBandedWork<T: Scalar> {
+    pub work: Vec<MaybeUninit<T>>,
+    pub rwork: Option<Vec<MaybeUninit<T::Real>>>,
+    pub iwork: Option<Vec<MaybeUninit<i32>>>,
+}
+
+pub trait RcondBandedWorkImpl {
+    type Elem: Scalar;
+    fn new(layout: MatrixLayout) -> Self;
+    fn calc(&mut self, lu: &LUFactorizedBanded<Self::Elem>)
+        -> Result<<Self::Elem as Scalar>::Real>;
+}
+
+macro_rules! impl_rcond_banded_work_c {
+    ($c:ty, $gbcon:path) => {
+        impl RcondBandedWorkImpl for RcondBandedWork<$c> {
+            type Elem = $c;
+
+            fn new(layout: MatrixLayout) -> Self {
+                let (n, _) = layout.size();
+                let work = vec_uninit(2 * n as usize);
+                let rwork = vec_uninit(n as usize);
+                RcondBandedWork {
+                    work,
+                    rwork: Some(rwork),
+                    iwork: None,
+                }
+            }
+
+            fn calc(
+                &mut self,
+                lu: &LUFactorizedBanded<Self::Elem>,
+            ) -> Result<<Self::Elem as Scalar>::Real> {
+                let (n, _) = lu.l.size();
+                let mut rcond = <Self::Elem as Scalar>::Real::zero();
+                let mut info = 0;
+                unsafe {
+                    $gbcon(
+                        NormType::One.as_ptr(),
+                        &n,
+                        &lu.kl,
+                        &lu.ku,
+                        AsPtr::as_ptr(&lu.lu),
+                        &lu.ldab(),
+                        lu.ipiv.as_ptr(),
+                        &lu.a_opnorm_one,
+                        &mut rcond,
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        AsPtr::as_mut_ptr(self.rwork.as_mut().unwrap()),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(rcond)
+            }
+        }
+    };
+}
+
+impl_rcond_banded_work_c!(c64, lapack_sys::zgbcon_);
+impl_rcond_banded_work_c!(c32, lapack_sys::cgbcon_);
+
+macro_rules! impl_rcond_banded_work_r {
+    ($r:ty, $gbcon:path) => {
+        impl RcondBandedWorkImpl for RcondBandedWork<$r> {
+            type Elem = $r;
+
+            fn new(layout: MatrixLayout) -> Self {
+                let (n, _) = layout.size();
+                let work = vec_uninit(3 * n as usize);
+                let iwork = vec_uninit(n as usize);
+                RcondBandedWork {
+                    work,
+                    rwork: None,
+                    iwork: Some(iwork),
+                }
+            }
+
+            fn calc(
+                &mut self,
+                lu: &LUFactorizedBanded<Self::Elem>,
+            ) -> Result<<Self::Elem as Scalar>::Real> {
+                let (n, _) = lu.l.size();
+                let mut rcond = <Self::Elem as Scalar>::Real::zero();
+                let mut info = 0;
+                unsafe {
+                    $gbcon(
+                        NormType::One.as_ptr(),
+                        &n,
+                        &lu.kl,
+                        &lu.ku,
+                        AsPtr::as_ptr(&lu.lu),
+                        &lu.ldab(),
+                        lu.ipiv.as_ptr(),
+                        &lu.a_opnorm_one,
+                        &mut rcond,
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        AsPtr::as_mut_ptr(self.iwork.as_mut().unwrap()),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(rcond)
+            }
+        }
+    };
+}
+
+impl_rcond_banded_work_r!(f64, lapack_sys::dgbcon_);
+impl_rcond_banded_work_r!(f32, lapack_sys::sgbcon_);
@@ -0,0 +1,63 @@
+use crate::{error::*, layout::*, *};
+use cauchy::*;
+
+pub trait SolveBandedImpl: Scalar {
+    fn solve_banded(
+        lu: &LUFactorizedBanded<Self>,
+        bl: MatrixLayout,
+        t: Transpose,
+        b: &mut [Self],
+    ) -> Result<()>;
+}
+
+macro_rules! impl_solve_banded {
+    ($s:ty, $trs:path) => {
+        impl SolveBandedImpl for $s {
+            fn solve_banded(
+                lu: &LUFactorizedBanded<Self>,
+                b_layout: MatrixLayout,
+                t: Transpose,
+                b: &mut [Self],
+            ) -> Result<()> {
+                let (n, _) = lu.l.size();
+                // Transpose if b is C-continuous
+                let mut b_t = None;
+                let b_layout = match b_layout {
+                    MatrixLayout::C { .. } => {
+                        let (layout, t) = transpose(b_layout, b);
+                        b_t = Some(t);
+                        layout
+                    }
+                    MatrixLayout::F { .. } => b_layout,
+                };
+                let (ldb, nrhs) = b_layout.size();
+                let mut info = 0;
+                unsafe {
+                    $trs(
+                        t.as_ptr(),
+                        &n,
+                        &lu.kl,
+                        &lu.ku,
+                        &nrhs,
+                        AsPtr::as_ptr(&lu.lu),
+                        &lu.ldab(),
+                        lu.ipiv.as_ptr(),
+                        AsPtr::as_mut_ptr(b_t.as_mut().map(|v| v.as_mut_slice()).unwrap_or(b)),
+                        &ldb,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                if let Some(b_t) = b_t {
+                    transpose_over(b_layout, &b_t, b);
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_solve_banded!(c64, lapack_sys::zgbtrs_);
+impl_solve_banded!(c32, lapack_sys::cgbtrs_);
+impl_solve_banded!(f64, lapack_sys::dgbtrs_);
+impl_solve_banded!(f32, lapack_sys::sgbtrs_);
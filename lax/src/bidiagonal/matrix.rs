@@ -0,0 +1,97 @@
+use crate::UPLO;
+use cauchy::*;
+use std::ops::{Index, IndexMut};
+
+/// Represents a real upper or lower bidiagonal matrix as 2 one-dimensional vectors.
+///
+/// ```text
+/// Upper:                         Lower:
+/// [d0, e0,  0, ...,       0,     [d0,  0, ...,          0,
+///   0, d1, e1, ...,        ,      e0, d1,  0, ...,       ,
+///  ...          ...,  e{n-2},     ...           ...,      ,
+///   0, ...,        0, d{n-1},]     0, ...,  e{n-2}, d{n-1},]
+/// ```
+///
+/// The elements are real even for complex matrices `A`,
+/// since the bidiagonal form of a complex matrix computed by `?gebrd` is real.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bidiagonal<A: Scalar> {
+    /// Upper or lower bidiagonal
+    pub uplo: UPLO,
+    /// (n) diagonal elements of matrix.
+    pub d: Vec<A::Real>,
+    /// (n-1) off-diagonal elements of matrix.
+    pub e: Vec<A::Real>,
+}
+
+impl<A: Scalar> Bidiagonal<A> {
+    /// Size of the square matrix
+    pub fn len(&self) -> usize {
+        self.d.len()
+    }
+
+    /// Check if the matrix is empty
+    pub fn is_empty(&self) -> bool {
+        self.d.is_empty()
+    }
+}
+
+impl<A: Scalar> Index<(i32, i32)> for Bidiagonal<A> {
+    type Output = A::Real;
+    #[inline]
+    fn index(&self, (row, col): (i32, i32)) -> &A::Real {
+        let n = self.d.len() as i32;
+        assert!(
+            std::cmp::max(row, col) < n,
+            "ndarray: index {:?} is out of bounds for array of shape {}",
+            [row, col],
+            n
+        );
+        match (row - col, self.uplo) {
+            (0, _) => &self.d[row as usize],
+            (-1, UPLO::Upper) => &self.e[row as usize],
+            (1, UPLO::Lower) => &self.e[col as usize],
+            _ => panic!(
+                "ndarray-linalg::bidiagonal: index {:?} is not bidiagonal element",
+                [row, col]
+            ),
+        }
+    }
+}
+
+impl<A: Scalar> Index<[i32; 2]> for Bidiagonal<A> {
+    type Output = A::Real;
+    #[inline]
+    fn index(&self, [row, col]: [i32; 2]) -> &A::Real {
+        &self[(row, col)]
+    }
+}
+
+impl<A: Scalar> IndexMut<(i32, i32)> for Bidiagonal<A> {
+    #[inline]
+    fn index_mut(&mut self, (row, col): (i32, i32)) -> &mut A::Real {
+        let n = self.d.len() as i32;
+        assert!(
+            std::cmp::max(row, col) < n,
+            "ndarray: index {:?} is out of bounds for array of shape {}",
+            [row, col],
+            n
+        );
+        match (row - col, self.uplo) {
+            (0, _) => &mut self.d[row as usize],
+            (-1, UPLO::Upper) => &mut self.e[row as usize],
+            (1, UPLO::Lower) => &mut self.e[col as usize],
+            _ => panic!(
+                "ndarray-linalg::bidiagonal: index {:?} is not bidiagonal element",
+                [row, col]
+            ),
+        }
+    }
+}
+
+impl<A: Scalar> IndexMut<[i32; 2]> for Bidiagonal<A> {
+    #[inline]
+    fn index_mut(&mut self, [row, col]: [i32; 2]) -> &mut A::Real {
+        &mut self[(row, col)]
+    }
+}
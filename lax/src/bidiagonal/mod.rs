@@ -0,0 +1,10 @@
+//! Implement Golub-Kahan bidiagonalization of general matrix
+//! and singular value decomposition of bidiagonal matrix
+
+mod matrix;
+mod reduction;
+mod svd;
+
+pub use matrix::*;
+pub use reduction::*;
+pub use svd::*;
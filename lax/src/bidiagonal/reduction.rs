@@ -0,0 +1,282 @@
+//! Golub-Kahan bidiagonalization
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | sgebrd | dgebrd | cgebrd | zgebrd |
+//! | sorgbr | dorgbr | cungbr | zungbr |
+//!
+
+use crate::{error::*, layout::*, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Reduce a general $m \times n$ matrix $A$ into bidiagonal form $B = Q^H A P$
+///
+/// $B$ is upper bidiagonal if $m \ge n$, and lower bidiagonal otherwise.
+/// After [BidiagonalWork::calc], the elementary reflectors representing $Q$ and $P$
+/// are stored in the input matrix with the scalar factors `tauq` and `taup`.
+/// A row-major matrix is transposed into column-major storage before calling LAPACK,
+/// and the result is transposed back.
+pub struct BidiagonalWork<T: Scalar> {
+    pub m: i32,
+    pub n: i32,
+    pub layout: MatrixLayout,
+    pub d: Vec<MaybeUninit<T::Real>>,
+    pub e: Vec<MaybeUninit<T::Real>>,
+    pub tauq: Vec<MaybeUninit<T>>,
+    pub taup: Vec<MaybeUninit<T>>,
+    pub work: Vec<MaybeUninit<T>>,
+}
+
+/// Reference result of [BidiagonalWork::calc]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BidiagonalRef<'work, T: Scalar> {
+    pub d: &'work [T::Real],
+    pub e: &'work [T::Real],
+    pub tauq: &'work [T],
+    pub taup: &'work [T],
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Owned result of [BidiagonalWork::eval]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BidiagonalOwned<T: Scalar> {
+    /// Bidiagonal matrix $B$
+    pub b: Bidiagonal<T>,
+    /// Scalar factors of the elementary reflectors representing $Q$
+    pub tauq: Vec<T>,
+    /// Scalar factors of the elementary reflectors representing $P$
+    pub taup: Vec<T>,
+}
+
+pub trait BidiagonalWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(l: MatrixLayout) -> Result<Self>;
+    fn calc(&mut self, a: &mut [Self::Elem]) -> Result<BidiagonalRef<'_, Self::Elem>>;
+    fn eval(self, a: &mut [Self::Elem]) -> Result<BidiagonalOwned<Self::Elem>>;
+}
+
+macro_rules! impl_bidiagonal_work {
+    ($s:ty, $gebrd:path) => {
+        impl BidiagonalWorkImpl for BidiagonalWork<$s> {
+            type Elem = $s;
+
+            fn new(layout: MatrixLayout) -> Result<Self> {
+                let (m, n) = layout.size();
+                let k = std::cmp::min(m, n) as usize;
+                let d = vec_uninit(k);
+                let e = vec_uninit(k.saturating_sub(1));
+                let tauq = vec_uninit(k);
+                let taup = vec_uninit(k);
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                unsafe {
+                    $gebrd(
+                        &m,
+                        &n,
+                        std::ptr::null_mut(),
+                        &std::cmp::max(1, m),
+                        std::ptr::null_mut(),
+                        std::ptr::null_mut(),
+                        std::ptr::null_mut(),
+                        std::ptr::null_mut(),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let lwork = work_size[0].to_usize().unwrap();
+                let work = vec_uninit(lwork);
+                Ok(BidiagonalWork {
+                    m,
+                    n,
+                    layout,
+                    d,
+                    e,
+                    tauq,
+                    taup,
+                    work,
+                })
+            }
+
+            fn calc(&mut self, a: &mut [Self::Elem]) -> Result<BidiagonalRef<'_, Self::Elem>> {
+                let m = self.m;
+                let n = self.n;
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut a_t = match self.layout {
+                    MatrixLayout::C { .. } => Some(transpose(self.layout, a).1),
+                    MatrixLayout::F { .. } => None,
+                };
+                let mut info = 0;
+                unsafe {
+                    $gebrd(
+                        &m,
+                        &n,
+                        AsPtr::as_mut_ptr(a_t.as_deref_mut().unwrap_or(a)),
+                        &std::cmp::max(1, m),
+                        AsPtr::as_mut_ptr(&mut self.d),
+                        AsPtr::as_mut_ptr(&mut self.e),
+                        AsPtr::as_mut_ptr(&mut self.tauq),
+                        AsPtr::as_mut_ptr(&mut self.taup),
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                if let Some(a_t) = a_t {
+                    transpose_over(MatrixLayout::F { col: n, lda: m }, &a_t, a);
+                }
+                Ok(BidiagonalRef {
+                    d: unsafe { self.d.slice_assume_init_ref() },
+                    e: unsafe { self.e.slice_assume_init_ref() },
+                    tauq: unsafe { self.tauq.slice_assume_init_ref() },
+                    taup: unsafe { self.taup.slice_assume_init_ref() },
+                })
+            }
+
+            fn eval(mut self, a: &mut [Self::Elem]) -> Result<BidiagonalOwned<Self::Elem>> {
+                let _ = self.calc(a)?;
+                let uplo = if self.m >= self.n {
+                    UPLO::Upper
+                } else {
+                    UPLO::Lower
+                };
+                Ok(BidiagonalOwned {
+                    b: Bidiagonal {
+                        uplo,
+                        d: unsafe { self.d.assume_init() },
+                        e: unsafe { self.e.assume_init() },
+                    },
+                    tauq: unsafe { self.tauq.assume_init() },
+                    taup: unsafe { self.taup.assume_init() },
+                })
+            }
+        }
+    };
+}
+impl_bidiagonal_work!(c64, lapack_sys::zgebrd_);
+impl_bidiagonal_work!(c32, lapack_sys::cgebrd_);
+impl_bidiagonal_work!(f64, lapack_sys::dgebrd_);
+impl_bidiagonal_work!(f32, lapack_sys::sgebrd_);
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Helper trait to abstract `?orgbr` and `?ungbr` LAPACK routines
+/// generating $Q$ (`vect = b'Q'`) or $P^H$ (`vect = b'P'`) in column-major `a`
+pub trait OrgbrImpl: Scalar {
+    fn orgbr(
+        vect: u8,
+        m: i32,
+        n: i32,
+        k: i32,
+        a: &mut [Self],
+        lda: i32,
+        tau: &[Self],
+    ) -> Result<()>;
+}
+
+macro_rules! impl_orgbr {
+    ($s:ty, $orgbr:path) => {
+        impl OrgbrImpl for $s {
+            fn orgbr(
+                vect: u8,
+                m: i32,
+                n: i32,
+                k: i32,
+                a: &mut [Self],
+                lda: i32,
+                tau: &[Self],
+            ) -> Result<()> {
+                let lda = std::cmp::max(1, lda);
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $orgbr(
+                        &vect as *const u8 as *const i8,
+                        &m,
+                        &n,
+                        &k,
+                        AsPtr::as_mut_ptr(a),
+                        &lda,
+                        AsPtr::as_ptr(tau),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $orgbr(
+                        &vect as *const u8 as *const i8,
+                        &m,
+                        &n,
+                        &k,
+                        AsPtr::as_mut_ptr(a),
+                        &lda,
+                        AsPtr::as_ptr(tau),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(())
+            }
+        }
+    };
+}
+impl_orgbr!(c64, lapack_sys::zungbr_);
+impl_orgbr!(c32, lapack_sys::cungbr_);
+impl_orgbr!(f64, lapack_sys::dorgbr_);
+impl_orgbr!(f32, lapack_sys::sorgbr_);
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Generate the unitary matrices $Q$ and $P^H$ from the output of [BidiagonalWork]
+///
+/// For $k = \min(m, n)$, the first $k$ columns of $Q$ as a column-major $m \times k$ matrix,
+/// and the first $k$ rows of $P^H$ as a column-major $k \times n$ matrix are returned.
+pub trait BidiagonalQImpl: Scalar {
+    fn bidiagonal_q(l: MatrixLayout, a: &[Self], tauq: &[Self]) -> Result<Vec<Self>>;
+    fn bidiagonal_pt(l: MatrixLayout, a: &[Self], taup: &[Self]) -> Result<Vec<Self>>;
+}
+
+impl<T: OrgbrImpl> BidiagonalQImpl for T {
+    fn bidiagonal_q(l: MatrixLayout, a: &[Self], tauq: &[Self]) -> Result<Vec<Self>> {
+        let (m, n) = l.size();
+        let k = std::cmp::min(m, n);
+        let mut q = match l {
+            MatrixLayout::C { .. } => transpose(l, a).1,
+            MatrixLayout::F { .. } => a.to_vec(),
+        };
+        // Q is generated in the leading m x k part, i.e. `q[..m * k]` since lda = m
+        T::orgbr(b'Q', m, k, n, &mut q, m, tauq)?;
+        q.truncate((m * k) as usize);
+        Ok(q)
+    }
+
+    fn bidiagonal_pt(l: MatrixLayout, a: &[Self], taup: &[Self]) -> Result<Vec<Self>> {
+        let (m, n) = l.size();
+        let k = std::cmp::min(m, n);
+        let mut pt = match l {
+            MatrixLayout::C { .. } => transpose(l, a).1,
+            MatrixLayout::F { .. } => a.to_vec(),
+        };
+        // P^H is generated in the leading k x n part with lda = m
+        T::orgbr(b'P', k, n, m, &mut pt, m, taup)?;
+        let (m, n, k) = (m as usize, n as usize, k as usize);
+        if k < m {
+            for j in 0..n {
+                pt.copy_within(j * m..j * m + k, j * k);
+            }
+        }
+        pt.truncate(k * n);
+        Ok(pt)
+    }
+}
@@ -0,0 +1,158 @@
+//! Singular value decomposition of bidiagonal matrix
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    |
+//! |:-------|:-------|
+//! | sbdsqr | dbdsqr |
+//! | sbdsdc | dbdsdc |
+//!
+//! Since a [Bidiagonal] matrix is real, the singular vectors for complex `A`
+//! are computed by the real routines and converted into complex.
+
+use crate::{error::*, *};
+use cauchy::*;
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Helper trait to abstract `?bdsqr` and `?bdsdc` LAPACK routines
+/// for implementing [Lapack::svd_bidiagonal] and [Lapack::svddc_bidiagonal]
+///
+/// The singular values are returned in descending order,
+/// and the singular vectors $U$ and $V^T$ of $B = U \Sigma V^T$
+/// are returned as column-major $n \times n$ matrices.
+pub trait SvdBidiagonalImpl: Scalar {
+    fn svd_bidiagonal(calc_uvt: bool, b: &Bidiagonal<Self>) -> Result<SvdOwned<Self>>;
+    fn svddc_bidiagonal(calc_uvt: bool, b: &Bidiagonal<Self>) -> Result<SvdOwned<Self>>;
+}
+
+/// Column-major identity matrix
+fn eye<T: Scalar>(n: usize) -> Vec<T> {
+    let mut a = vec![T::zero(); n * n];
+    for i in 0..n {
+        a[i * n + i] = T::one();
+    }
+    a
+}
+
+macro_rules! impl_svd_bidiagonal_r {
+    ($r:ty, $bdsqr:path, $bdsdc:path) => {
+        impl SvdBidiagonalImpl for $r {
+            fn svd_bidiagonal(calc_uvt: bool, b: &Bidiagonal<Self>) -> Result<SvdOwned<Self>> {
+                let n = b.len() as i32;
+                let mut d = b.d.clone();
+                let mut e = b.e.clone();
+                let (ncv, ld) = if calc_uvt { (n, n) } else { (0, 1) };
+                let mut u = calc_uvt.then(|| eye::<Self>(n as usize));
+                let mut vt = calc_uvt.then(|| eye::<Self>(n as usize));
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(std::cmp::max(1, 4 * n) as usize);
+                let mut info = 0;
+                unsafe {
+                    $bdsqr(
+                        b.uplo.as_ptr(),
+                        &n,
+                        &ncv,
+                        &ncv,
+                        &0,
+                        AsPtr::as_mut_ptr(&mut d),
+                        AsPtr::as_mut_ptr(&mut e),
+                        AsPtr::as_mut_ptr(vt.as_deref_mut().unwrap_or(&mut [])),
+                        &std::cmp::max(1, ld),
+                        AsPtr::as_mut_ptr(u.as_deref_mut().unwrap_or(&mut [])),
+                        &std::cmp::max(1, ld),
+                        std::ptr::null_mut(),
+                        &1,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(SvdOwned { s: d, u, vt })
+            }
+
+            fn svddc_bidiagonal(calc_uvt: bool, b: &Bidiagonal<Self>) -> Result<SvdOwned<Self>> {
+                let n = b.len() as i32;
+                let mut d = b.d.clone();
+                let mut e = b.e.clone();
+                let (compq, ld, lwork) = if calc_uvt {
+                    (b'I', n, 3 * n * n + 4 * n)
+                } else {
+                    (b'N', 1, 4 * n)
+                };
+                let mut u: Option<Vec<MaybeUninit<Self>>> =
+                    calc_uvt.then(|| vec_uninit((n * n) as usize));
+                let mut vt: Option<Vec<MaybeUninit<Self>>> =
+                    calc_uvt.then(|| vec_uninit((n * n) as usize));
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(std::cmp::max(1, lwork) as usize);
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(std::cmp::max(1, 8 * n) as usize);
+                let mut info = 0;
+                unsafe {
+                    $bdsdc(
+                        b.uplo.as_ptr(),
+                        &compq as *const u8 as *const i8,
+                        &n,
+                        AsPtr::as_mut_ptr(&mut d),
+                        AsPtr::as_mut_ptr(&mut e),
+                        AsPtr::as_mut_ptr(u.as_deref_mut().unwrap_or(&mut [])),
+                        &std::cmp::max(1, ld),
+                        AsPtr::as_mut_ptr(vt.as_deref_mut().unwrap_or(&mut [])),
+                        &std::cmp::max(1, ld),
+                        std::ptr::null_mut(),
+                        std::ptr::null_mut(),
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(SvdOwned {
+                    s: d,
+                    u: u.map(|u| unsafe { u.assume_init() }),
+                    vt: vt.map(|vt| unsafe { vt.assume_init() }),
+                })
+            }
+        }
+    };
+}
+impl_svd_bidiagonal_r!(f64, lapack_sys::dbdsqr_, lapack_sys::dbdsdc_);
+impl_svd_bidiagonal_r!(f32, lapack_sys::sbdsqr_, lapack_sys::sbdsdc_);
+
+macro_rules! impl_svd_bidiagonal_c {
+    ($c:ty, $r:ty) => {
+        impl SvdBidiagonalImpl for $c {
+            fn svd_bidiagonal(calc_uvt: bool, b: &Bidiagonal<Self>) -> Result<SvdOwned<Self>> {
+                let SvdOwned { s, u, vt } =
+                    <$r as SvdBidiagonalImpl>::svd_bidiagonal(calc_uvt, &to_real(b))?;
+                Ok(SvdOwned {
+                    s,
+                    u: u.map(into_complex),
+                    vt: vt.map(into_complex),
+                })
+            }
+
+            fn svddc_bidiagonal(calc_uvt: bool, b: &Bidiagonal<Self>) -> Result<SvdOwned<Self>> {
+                let SvdOwned { s, u, vt } =
+                    <$r as SvdBidiagonalImpl>::svddc_bidiagonal(calc_uvt, &to_real(b))?;
+                Ok(SvdOwned {
+                    s,
+                    u: u.map(into_complex),
+                    vt: vt.map(into_complex),
+                })
+            }
+        }
+    };
+}
+impl_svd_bidiagonal_c!(c64, f64);
+impl_svd_bidiagonal_c!(c32, f32);
+
+fn to_real<T: Scalar>(b: &Bidiagonal<T>) -> Bidiagonal<T::Real> {
+    Bidiagonal {
+        uplo: b.uplo,
+        d: b.d.clone(),
+        e: b.e.clone(),
+    }
+}
+
+fn into_complex<T: Scalar>(a: Vec<T::Real>) -> Vec<T> {
+    a.into_iter().map(T::from_real).collect()
+}
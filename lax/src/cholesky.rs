@@ -3,6 +3,7 @@
 use super::*;
 use crate::{error::*, layout::*};
 use cauchy::*;
+use num_traits::Zero;
 
 /// Compute Cholesky decomposition according to [UPLO]
 ///
@@ -140,3 +141,247 @@ impl_solve_cholesky!(c64, lapack_sys::zpotrs_);
 impl_solve_cholesky!(c32, lapack_sys::cpotrs_);
 impl_solve_cholesky!(f64, lapack_sys::dpotrs_);
 impl_solve_cholesky!(f32, lapack_sys::spotrs_);
+
+/// Solve linear equation with equilibration, iterative refinement, and error bounds
+/// using Cholesky factorization
+///
+/// Row-major matrix is handled in the same manner as [SolveCholeskyImpl].
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | sposvx | dposvx | cposvx | zposvx |
+///
+pub trait SolveCholeskyExpertImpl: Scalar {
+    fn solve_cholesky_expert(
+        l: MatrixLayout,
+        uplo: UPLO,
+        a: &mut [Self],
+        factor: Option<&[Self]>,
+        b: &mut [Self],
+    ) -> Result<SolveExpertOwned<Self>>;
+}
+
+macro_rules! impl_solve_cholesky_expert_c {
+    ($c:ty, $posvx:path) => {
+        impl SolveCholeskyExpertImpl for $c {
+            fn solve_cholesky_expert(
+                l: MatrixLayout,
+                mut uplo: UPLO,
+                a: &mut [Self],
+                factor: Option<&[Self]>,
+                b: &mut [Self],
+            ) -> Result<SolveExpertOwned<Self>> {
+                let (n, _) = l.size();
+                // Use the given factorization, or equilibrate and factorize `a`
+                let (fact, mut af) = match factor {
+                    Some(af) => (b'F', af.to_vec()),
+                    None => (b'E', vec![Self::zero(); a.len()]),
+                };
+                let mut equed = b'N';
+                let mut s: Vec<MaybeUninit<<$c as Scalar>::Real>> = vec_uninit(n as usize);
+                let mut x: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut rcond = 0.0;
+                let mut ferr = 0.0;
+                let mut berr = 0.0;
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(std::cmp::max(1, 2 * n) as usize);
+                let mut rwork: Vec<MaybeUninit<<$c as Scalar>::Real>> =
+                    vec_uninit(std::cmp::max(1, n) as usize);
+                if matches!(l, MatrixLayout::C { .. }) {
+                    uplo = uplo.t();
+                    for val in b.iter_mut() {
+                        *val = val.conj();
+                    }
+                }
+                let mut info = 0;
+                unsafe {
+                    $posvx(
+                        &fact as *const u8 as *const i8,
+                        uplo.as_ptr(),
+                        &n,
+                        &1,
+                        AsPtr::as_mut_ptr(a),
+                        &l.lda(),
+                        AsPtr::as_mut_ptr(&mut af),
+                        &l.lda(),
+                        &mut equed as *mut u8 as *mut i8,
+                        AsPtr::as_mut_ptr(&mut s),
+                        AsPtr::as_mut_ptr(b),
+                        &l.lda(),
+                        AsPtr::as_mut_ptr(&mut x),
+                        &l.lda(),
+                        &mut rcond,
+                        &mut ferr,
+                        &mut berr,
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        &mut info,
+                    );
+                }
+                // `info = n + 1` means that the matrix is singular to working precision,
+                // but the solution and error bounds are computed.
+                if info != n + 1 {
+                    info.as_lapack_result()?;
+                }
+                b.copy_from_slice(unsafe { x.slice_assume_init_ref() });
+                if matches!(l, MatrixLayout::C { .. }) {
+                    for val in b.iter_mut() {
+                        *val = val.conj();
+                    }
+                }
+                Ok(SolveExpertOwned {
+                    rcond,
+                    ferr,
+                    berr,
+                    equed: Equilibration::from_equed(equed),
+                })
+            }
+        }
+    };
+}
+impl_solve_cholesky_expert_c!(c64, lapack_sys::zposvx_);
+impl_solve_cholesky_expert_c!(c32, lapack_sys::cposvx_);
+
+macro_rules! impl_solve_cholesky_expert_r {
+    ($f:ty, $posvx:path) => {
+        impl SolveCholeskyExpertImpl for $f {
+            fn solve_cholesky_expert(
+                l: MatrixLayout,
+                mut uplo: UPLO,
+                a: &mut [Self],
+                factor: Option<&[Self]>,
+                b: &mut [Self],
+            ) -> Result<SolveExpertOwned<Self>> {
+                let (n, _) = l.size();
+                // Use the given factorization, or equilibrate and factorize `a`
+                let (fact, mut af) = match factor {
+                    Some(af) => (b'F', af.to_vec()),
+                    None => (b'E', vec![Self::zero(); a.len()]),
+                };
+                let mut equed = b'N';
+                let mut s: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut x: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut rcond = 0.0;
+                let mut ferr = 0.0;
+                let mut berr = 0.0;
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(std::cmp::max(1, 3 * n) as usize);
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(std::cmp::max(1, n) as usize);
+                if matches!(l, MatrixLayout::C { .. }) {
+                    uplo = uplo.t();
+                }
+                let mut info = 0;
+                unsafe {
+                    $posvx(
+                        &fact as *const u8 as *const i8,
+                        uplo.as_ptr(),
+                        &n,
+                        &1,
+                        AsPtr::as_mut_ptr(a),
+                        &l.lda(),
+                        AsPtr::as_mut_ptr(&mut af),
+                        &l.lda(),
+                        &mut equed as *mut u8 as *mut i8,
+                        AsPtr::as_mut_ptr(&mut s),
+                        AsPtr::as_mut_ptr(b),
+                        &l.lda(),
+                        AsPtr::as_mut_ptr(&mut x),
+                        &l.lda(),
+                        &mut rcond,
+                        &mut ferr,
+                        &mut berr,
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    );
+                }
+                // `info = n + 1` means that the matrix is singular to working precision,
+                // but the solution and error bounds are computed.
+                if info != n + 1 {
+                    info.as_lapack_result()?;
+                }
+                b.copy_from_slice(unsafe { x.slice_assume_init_ref() });
+                Ok(SolveExpertOwned {
+                    rcond,
+                    ferr,
+                    berr,
+                    equed: Equilibration::from_equed(equed),
+                })
+            }
+        }
+    };
+}
+impl_solve_cholesky_expert_r!(f64, lapack_sys::dposvx_);
+impl_solve_cholesky_expert_r!(f32, lapack_sys::sposvx_);
+
+/// Compute Cholesky decomposition with complete pivoting
+/// for positive semidefinite matrix according to [UPLO]
+///
+/// Returns the permutation in LAPACK style (1-based) and the computed rank.
+/// Only the leading `rank` rows (`UPLO::Upper`) or columns (`UPLO::Lower`)
+/// of the factor are meaningful, and the trailing part of `a` is left as computed by LAPACK.
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | spstrf | dpstrf | cpstrf | zpstrf |
+///
+pub trait CholeskyPivotedImpl: Scalar {
+    fn cholesky_pivoted(
+        l: MatrixLayout,
+        uplo: UPLO,
+        a: &mut [Self],
+        tol: Self::Real,
+    ) -> Result<(Pivot, i32)>;
+}
+
+macro_rules! impl_cholesky_pivoted {
+    ($s:ty, $pstrf:path) => {
+        impl CholeskyPivotedImpl for $s {
+            fn cholesky_pivoted(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &mut [Self],
+                tol: Self::Real,
+            ) -> Result<(Pivot, i32)> {
+                let (n, _) = l.size();
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                }
+                let mut piv = vec![0; n as usize];
+                let mut rank = 0;
+                let mut work: Vec<MaybeUninit<Self::Real>> =
+                    vec_uninit(std::cmp::max(1, 2 * n) as usize);
+                let mut info = 0;
+                unsafe {
+                    $pstrf(
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &std::cmp::max(1, n),
+                        piv.as_mut_ptr(),
+                        &mut rank,
+                        &tol,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &mut info,
+                    );
+                }
+                // `info = 1` means that the matrix is rank deficient, which is not an error here
+                if info != 1 {
+                    info.as_lapack_result()?;
+                }
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                }
+                Ok((piv, rank))
+            }
+        }
+    };
+}
+impl_cholesky_pivoted!(c64, lapack_sys::zpstrf_);
+impl_cholesky_pivoted!(c32, lapack_sys::cpstrf_);
+impl_cholesky_pivoted!(f64, lapack_sys::dpstrf_);
+impl_cholesky_pivoted!(f32, lapack_sys::spstrf_);
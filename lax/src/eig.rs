@@ -7,8 +7,10 @@
 //! |:------|:------|:------|:------|
 //! | sgeev | dgeev | cgeev | zgeev |
 //!
+//! The expert driver [EigExpertImpl] uses `?geevx` instead.
+//!
 
-use crate::{error::*, layout::MatrixLayout, *};
+use crate::{error::*, layout::*, *};
 use cauchy::*;
 use num_traits::{ToPrimitive, Zero};
 
@@ -48,10 +50,17 @@ use num_traits::{ToPrimitive, Zero};
 /// A^\dagger V = V Λ ⟺ V^\dagger A = Λ V^\dagger
 /// $$
 ///
+/// Thus [EigWork::new] returns the right eigenvectors of a row-major matrix
+/// in `vl` of [EigRef] and [EigOwned].
+/// [EigWork::new_left_right] rearranges the results so that `vr` and `vl`
+/// are the right and left eigenvectors of the input matrix for both layouts.
+///
 #[non_exhaustive]
 pub struct EigWork<T: Scalar> {
     /// Problem size
     pub n: i32,
+    /// Layout of the input matrix
+    pub layout: MatrixLayout,
     /// Compute right eigenvectors or not
     pub jobvr: JobEv,
     /// Compute left eigenvectors or not
@@ -89,6 +98,14 @@ where
         EigWorkImpl::new(calc_v, l)
     }
 
+    /// Create new working memory for eigenvalues compution with both left and right eigenvectors.
+    ///
+    /// Unlike [EigWork::new], the right and left eigenvectors are returned in `vr` and `vl`
+    /// respectively for both layouts.
+    pub fn new_left_right(l: MatrixLayout) -> Result<Self> {
+        EigWorkImpl::new_left_right(l)
+    }
+
     /// Compute eigenvalues and vectors on this working memory.
     pub fn calc(&mut self, a: &mut [T]) -> Result<EigRef<T>> {
         EigWorkImpl::calc(self, a)
@@ -122,29 +139,69 @@ pub struct EigRef<'work, T: Scalar> {
     pub vl: Option<&'work [T::Complex]>,
 }
 
+#[cfg_attr(doc, katexit::katexit)]
+/// Owned result of eigenvalue problem of real matrix in the real representation by [EigWork::eval_real]
+///
+/// A complex conjugate pair of eigenvalues $\lambda_j = a + ib$, $\lambda_{j+1} = a - ib$ with $b > 0$
+/// appears consecutively, and the corresponding eigenvectors $v_j = x + iy$, $v_{j+1} = x - iy$
+/// are stored as the two real columns $x$ and $y$ in this order.
+/// These satisfy
+/// $$
+/// A \begin{pmatrix} x & y \end{pmatrix}
+/// = \begin{pmatrix} x & y \end{pmatrix} \begin{pmatrix} a & b \\\\ -b & a \end{pmatrix}
+/// $$
+#[derive(Debug, Clone, PartialEq)]
+pub struct EigRealOwned<T: Scalar> {
+    /// Real part of eigenvalues
+    pub eigs_re: Vec<T>,
+    /// Imaginary part of eigenvalues
+    pub eigs_im: Vec<T>,
+    /// Right eigenvectors in column-major order
+    pub vr: Option<Vec<T>>,
+}
+
+impl<T: Scalar> EigWork<T> {
+    /// Whether `vc_l` and `vc_r` hold the right and left eigenvectors of the input matrix,
+    /// which is the case only for a row-major matrix with [EigWork::new_left_right]
+    fn is_swapped(&self) -> bool {
+        matches!(self.layout, MatrixLayout::C { .. })
+            && self.jobvl.is_calc()
+            && self.jobvr.is_calc()
+    }
+
+    /// Eigenvalues with the eigenvectors arranged as described in [EigWork]
+    ///
+    /// # Safety
+    ///
+    /// The results must have been initialized by `calc`.
+    unsafe fn eig_ref(&self) -> EigRef<'_, T> {
+        let (vl, vr) = if self.is_swapped() {
+            (&self.vc_r, &self.vc_l)
+        } else {
+            (&self.vc_l, &self.vc_r)
+        };
+        EigRef {
+            eigs: self.eigs.slice_assume_init_ref(),
+            vl: vl.as_ref().map(|v| v.slice_assume_init_ref()),
+            vr: vr.as_ref().map(|v| v.slice_assume_init_ref()),
+        }
+    }
+}
+
 /// Helper trait for implementing [EigWork] methods
 pub trait EigWorkImpl: Sized {
     type Elem: Scalar;
     fn new(calc_v: bool, l: MatrixLayout) -> Result<Self>;
+    fn new_left_right(l: MatrixLayout) -> Result<Self>;
     fn calc<'work>(&'work mut self, a: &mut [Self::Elem]) -> Result<EigRef<'work, Self::Elem>>;
     fn eval(self, a: &mut [Self::Elem]) -> Result<EigOwned<Self::Elem>>;
 }
 
 macro_rules! impl_eig_work_c {
     ($c:ty, $ev:path) => {
-        impl EigWorkImpl for EigWork<$c> {
-            type Elem = $c;
-
-            fn new(calc_v: bool, l: MatrixLayout) -> Result<Self> {
+        impl EigWork<$c> {
+            fn with_jobs(jobvl: JobEv, jobvr: JobEv, l: MatrixLayout) -> Result<Self> {
                 let (n, _) = l.size();
-                let (jobvl, jobvr) = if calc_v {
-                    match l {
-                        MatrixLayout::C { .. } => (JobEv::All, JobEv::None),
-                        MatrixLayout::F { .. } => (JobEv::None, JobEv::All),
-                    }
-                } else {
-                    (JobEv::None, JobEv::None)
-                };
                 let mut eigs = vec_uninit(n as usize);
                 let mut rwork = vec_uninit(2 * n as usize);
 
@@ -178,6 +235,7 @@ macro_rules! impl_eig_work_c {
                 let work: Vec<MaybeUninit<$c>> = vec_uninit(lwork);
                 Ok(Self {
                     n,
+                    layout: l,
                     jobvl,
                     jobvr,
                     eigs,
@@ -191,6 +249,28 @@ macro_rules! impl_eig_work_c {
                     work,
                 })
             }
+        }
+
+        impl EigWorkImpl for EigWork<$c> {
+            type Elem = $c;
+
+            fn new(calc_v: bool, l: MatrixLayout) -> Result<Self> {
+                // Row-major matrix is regarded as the transpose of column-major one,
+                // and thus the right eigenvectors are computed from its left eigenvectors
+                let (jobvl, jobvr) = if calc_v {
+                    match l {
+                        MatrixLayout::C { .. } => (JobEv::All, JobEv::None),
+                        MatrixLayout::F { .. } => (JobEv::None, JobEv::All),
+                    }
+                } else {
+                    (JobEv::None, JobEv::None)
+                };
+                Self::with_jobs(jobvl, jobvr, l)
+            }
+
+            fn new_left_right(l: MatrixLayout) -> Result<Self> {
+                Self::with_jobs(JobEv::All, JobEv::All, l)
+            }
 
             fn calc<'work>(
                 &'work mut self,
@@ -218,31 +298,28 @@ macro_rules! impl_eig_work_c {
                 };
                 info.as_lapack_result()?;
                 // Hermite conjugate
-                if let Some(vl) = self.vc_l.as_mut() {
-                    for value in vl {
-                        let value = unsafe { value.assume_init_mut() };
-                        value.im = -value.im;
+                if let MatrixLayout::C { .. } = self.layout {
+                    for v in self.vc_l.iter_mut().chain(self.vc_r.iter_mut()) {
+                        for value in v.iter_mut() {
+                            let value = unsafe { value.assume_init_mut() };
+                            value.im = -value.im;
+                        }
                     }
                 }
-                Ok(EigRef {
-                    eigs: unsafe { self.eigs.slice_assume_init_ref() },
-                    vl: self
-                        .vc_l
-                        .as_ref()
-                        .map(|v| unsafe { v.slice_assume_init_ref() }),
-                    vr: self
-                        .vc_r
-                        .as_ref()
-                        .map(|v| unsafe { v.slice_assume_init_ref() }),
-                })
+                Ok(unsafe { self.eig_ref() })
             }
 
             fn eval(mut self, a: &mut [Self::Elem]) -> Result<EigOwned<Self::Elem>> {
                 let _eig_ref = self.calc(a)?;
+                let (vl, vr) = if self.is_swapped() {
+                    (self.vc_r, self.vc_l)
+                } else {
+                    (self.vc_l, self.vc_r)
+                };
                 Ok(EigOwned {
                     eigs: unsafe { self.eigs.assume_init() },
-                    vl: self.vc_l.map(|v| unsafe { v.assume_init() }),
-                    vr: self.vc_r.map(|v| unsafe { v.assume_init() }),
+                    vl: vl.map(|v| unsafe { v.assume_init() }),
+                    vr: vr.map(|v| unsafe { v.assume_init() }),
                 })
             }
         }
@@ -254,19 +331,9 @@ impl_eig_work_c!(c64, lapack_sys::zgeev_);
 
 macro_rules! impl_eig_work_r {
     ($f:ty, $ev:path) => {
-        impl EigWorkImpl for EigWork<$f> {
-            type Elem = $f;
-
-            fn new(calc_v: bool, l: MatrixLayout) -> Result<Self> {
+        impl EigWork<$f> {
+            fn with_jobs(jobvl: JobEv, jobvr: JobEv, l: MatrixLayout) -> Result<Self> {
                 let (n, _) = l.size();
-                let (jobvl, jobvr) = if calc_v {
-                    match l {
-                        MatrixLayout::C { .. } => (JobEv::All, JobEv::None),
-                        MatrixLayout::F { .. } => (JobEv::None, JobEv::All),
-                    }
-                } else {
-                    (JobEv::None, JobEv::None)
-                };
                 let mut eigs_re = vec_uninit(n as usize);
                 let mut eigs_im = vec_uninit(n as usize);
                 let mut vr_l = jobvl.then(|| vec_uninit((n * n) as usize));
@@ -303,6 +370,7 @@ macro_rules! impl_eig_work_r {
 
                 Ok(Self {
                     n,
+                    layout: l,
                     jobvr,
                     jobvl,
                     eigs: vec_uninit(n as usize),
@@ -317,10 +385,8 @@ macro_rules! impl_eig_work_r {
                 })
             }
 
-            fn calc<'work>(
-                &'work mut self,
-                a: &mut [Self::Elem],
-            ) -> Result<EigRef<'work, Self::Elem>> {
+            /// Call `?geev` to compute eigenvalues and eigenvectors in the real representation
+            fn geev(&mut self, a: &mut [$f]) -> Result<()> {
                 let lwork = self.work.len().to_i32().unwrap();
                 let mut info = 0;
                 unsafe {
@@ -342,6 +408,70 @@ macro_rules! impl_eig_work_r {
                     )
                 };
                 info.as_lapack_result()?;
+                Ok(())
+            }
+
+            /// Compute eigenvalues and right eigenvectors in the real representation
+            /// without converting them into complex numbers, see [EigRealOwned].
+            pub fn eval_real(mut self, a: &mut [$f]) -> Result<EigRealOwned<$f>> {
+                self.geev(a)?;
+                let eigs_re = unsafe { self.eigs_re.unwrap().assume_init() };
+                let eigs_im = unsafe { self.eigs_im.unwrap().assume_init() };
+                let vr = match self.layout {
+                    MatrixLayout::C { .. } => self.vr_l,
+                    MatrixLayout::F { .. } => self.vr_r,
+                };
+                let mut vr = vr.map(|v| unsafe { v.assume_init() });
+                if let (Some(v), MatrixLayout::C { .. }) = (vr.as_mut(), self.layout) {
+                    // Right eigenvectors of a row-major matrix are the complex conjugates of
+                    // the left eigenvectors of its transpose, i.e. flip the sign of `Im v`
+                    let n = self.n as usize;
+                    let mut col = 0;
+                    while col < n {
+                        if eigs_im[col].is_zero() {
+                            col += 1;
+                        } else {
+                            for value in &mut v[(col + 1) * n..(col + 2) * n] {
+                                *value = -*value;
+                            }
+                            col += 2;
+                        }
+                    }
+                }
+                Ok(EigRealOwned {
+                    eigs_re,
+                    eigs_im,
+                    vr,
+                })
+            }
+        }
+
+        impl EigWorkImpl for EigWork<$f> {
+            type Elem = $f;
+
+            fn new(calc_v: bool, l: MatrixLayout) -> Result<Self> {
+                // Row-major matrix is regarded as the transpose of column-major one,
+                // and thus the right eigenvectors are computed from its left eigenvectors
+                let (jobvl, jobvr) = if calc_v {
+                    match l {
+                        MatrixLayout::C { .. } => (JobEv::All, JobEv::None),
+                        MatrixLayout::F { .. } => (JobEv::None, JobEv::All),
+                    }
+                } else {
+                    (JobEv::None, JobEv::None)
+                };
+                Self::with_jobs(jobvl, jobvr, l)
+            }
+
+            fn new_left_right(l: MatrixLayout) -> Result<Self> {
+                Self::with_jobs(JobEv::All, JobEv::All, l)
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                a: &mut [Self::Elem],
+            ) -> Result<EigRef<'work, Self::Elem>> {
+                self.geev(a)?;
 
                 let eigs_re = self
                     .eigs_re
@@ -355,34 +485,30 @@ macro_rules! impl_eig_work_r {
                     .unwrap();
                 reconstruct_eigs(eigs_re, eigs_im, &mut self.eigs);
 
+                let is_c = matches!(self.layout, MatrixLayout::C { .. });
                 if let Some(v) = self.vr_l.as_ref() {
                     let v = unsafe { v.slice_assume_init_ref() };
-                    reconstruct_eigenvectors(true, eigs_im, v, self.vc_l.as_mut().unwrap());
+                    reconstruct_eigenvectors(is_c, eigs_im, v, self.vc_l.as_mut().unwrap());
                 }
                 if let Some(v) = self.vr_r.as_ref() {
                     let v = unsafe { v.slice_assume_init_ref() };
-                    reconstruct_eigenvectors(false, eigs_im, v, self.vc_r.as_mut().unwrap());
+                    reconstruct_eigenvectors(is_c, eigs_im, v, self.vc_r.as_mut().unwrap());
                 }
 
-                Ok(EigRef {
-                    eigs: unsafe { self.eigs.slice_assume_init_ref() },
-                    vl: self
-                        .vc_l
-                        .as_ref()
-                        .map(|v| unsafe { v.slice_assume_init_ref() }),
-                    vr: self
-                        .vc_r
-                        .as_ref()
-                        .map(|v| unsafe { v.slice_assume_init_ref() }),
-                })
+                Ok(unsafe { self.eig_ref() })
             }
 
             fn eval(mut self, a: &mut [Self::Elem]) -> Result<EigOwned<Self::Elem>> {
                 let _eig_ref = self.calc(a)?;
+                let (vl, vr) = if self.is_swapped() {
+                    (self.vc_r, self.vc_l)
+                } else {
+                    (self.vc_l, self.vc_r)
+                };
                 Ok(EigOwned {
                     eigs: unsafe { self.eigs.assume_init() },
-                    vl: self.vc_l.map(|v| unsafe { v.assume_init() }),
-                    vr: self.vc_r.map(|v| unsafe { v.assume_init() }),
+                    vl: vl.map(|v| unsafe { v.assume_init() }),
+                    vr: vr.map(|v| unsafe { v.assume_init() }),
                 })
             }
         }
@@ -391,6 +517,289 @@ macro_rules! impl_eig_work_r {
 impl_eig_work_r!(f32, lapack_sys::sgeev_);
 impl_eig_work_r!(f64, lapack_sys::dgeev_);
 
+#[cfg_attr(doc, katexit::katexit)]
+/// Result of the expert driver for eigenvalue problem of general matrix, i.e. [EigExpertImpl]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EigExpertOwned<T: Scalar> {
+    /// Eigenvalues
+    pub eigs: Vec<T::Complex>,
+    /// Right eigenvectors $v_i$ satisfying $A v_i = \lambda_i v_i$ in column-major order
+    pub vr: Vec<T::Complex>,
+    /// Left eigenvectors $u_i$ satisfying $u_i^\dagger A = \lambda_i u_i^\dagger$ in column-major order
+    pub vl: Vec<T::Complex>,
+    /// Reciprocal condition numbers of the eigenvalues
+    pub rconde: Vec<T::Real>,
+    /// Reciprocal condition numbers of the right eigenvectors
+    pub rcondv: Vec<T::Real>,
+    /// One norm of the balanced matrix
+    pub abnrm: T::Real,
+    /// Details of the permutations and scaling factors applied when balancing the matrix
+    pub scale: Vec<T::Real>,
+    /// Balancing permutes the matrix so that its rows and columns
+    /// out of `ilo..=ihi` (1-based) are already upper triangular
+    pub ilo: i32,
+    /// See [EigExpertOwned::ilo]
+    pub ihi: i32,
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Expert driver for eigenvalue problem of general matrix
+///
+/// This computes the eigenvalues, both right and left eigenvectors normalized to have unit 2-norm,
+/// and their reciprocal condition numbers after balancing the matrix as specified by [Balance].
+/// An approximate error bound of the computed eigenvalue $\lambda_i$ is given by
+/// $\epsilon \lVert A \rVert / \mathrm{rconde}_i$,
+/// and of the computed eigenvector by $\epsilon \lVert A \rVert / \mathrm{rcondv}_i$
+/// where $\lVert A \rVert$ is approximated by `abnrm`.
+///
+/// Row-major matrix is transposed into column-major in place,
+/// and thus the results are always for the given matrix $A$ itself.
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | sgeevx | dgeevx | cgeevx | zgeevx |
+///
+pub trait EigExpertImpl: Scalar {
+    fn eig_expert(
+        balance: Balance,
+        l: MatrixLayout,
+        a: &mut [Self],
+    ) -> Result<EigExpertOwned<Self>>;
+}
+
+macro_rules! impl_eig_expert_c {
+    ($c:ty, $evx:path) => {
+        impl EigExpertImpl for $c {
+            fn eig_expert(
+                balance: Balance,
+                l: MatrixLayout,
+                a: &mut [Self],
+            ) -> Result<EigExpertOwned<Self>> {
+                let (n, _) = l.size();
+                // LAPACK only accepts column-major matrices
+                if let MatrixLayout::C { .. } = l {
+                    square_transpose(l, a);
+                }
+                let mut eigs: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut vl: Vec<MaybeUninit<Self>> = vec_uninit((n * n) as usize);
+                let mut vr: Vec<MaybeUninit<Self>> = vec_uninit((n * n) as usize);
+                let mut scale: Vec<MaybeUninit<Self::Real>> = vec_uninit(n as usize);
+                let mut rconde: Vec<MaybeUninit<Self::Real>> = vec_uninit(n as usize);
+                let mut rcondv: Vec<MaybeUninit<Self::Real>> = vec_uninit(n as usize);
+                let mut rwork: Vec<MaybeUninit<Self::Real>> = vec_uninit(2 * n as usize);
+                let mut ilo = 0;
+                let mut ihi = 0;
+                let mut abnrm = Self::Real::zero();
+                let ldv = n.max(1);
+                // Condition numbers for both eigenvalues and eigenvectors
+                let sense = b'B';
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $evx(
+                        balance.as_ptr(),
+                        JobEv::All.as_ptr(),
+                        JobEv::All.as_ptr(),
+                        &sense as *const u8 as *const i8,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &ldv,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(&mut vl),
+                        &ldv,
+                        AsPtr::as_mut_ptr(&mut vr),
+                        &ldv,
+                        &mut ilo,
+                        &mut ihi,
+                        AsPtr::as_mut_ptr(&mut scale),
+                        &mut abnrm,
+                        AsPtr::as_mut_ptr(&mut rconde),
+                        AsPtr::as_mut_ptr(&mut rcondv),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                let lwork = lwork.to_i32().unwrap();
+                unsafe {
+                    $evx(
+                        balance.as_ptr(),
+                        JobEv::All.as_ptr(),
+                        JobEv::All.as_ptr(),
+                        &sense as *const u8 as *const i8,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &ldv,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(&mut vl),
+                        &ldv,
+                        AsPtr::as_mut_ptr(&mut vr),
+                        &ldv,
+                        &mut ilo,
+                        &mut ihi,
+                        AsPtr::as_mut_ptr(&mut scale),
+                        &mut abnrm,
+                        AsPtr::as_mut_ptr(&mut rconde),
+                        AsPtr::as_mut_ptr(&mut rcondv),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                Ok(EigExpertOwned {
+                    eigs: unsafe { eigs.assume_init() },
+                    vr: unsafe { vr.assume_init() },
+                    vl: unsafe { vl.assume_init() },
+                    rconde: unsafe { rconde.assume_init() },
+                    rcondv: unsafe { rcondv.assume_init() },
+                    abnrm,
+                    scale: unsafe { scale.assume_init() },
+                    ilo,
+                    ihi,
+                })
+            }
+        }
+    };
+}
+
+impl_eig_expert_c!(c32, lapack_sys::cgeevx_);
+impl_eig_expert_c!(c64, lapack_sys::zgeevx_);
+
+macro_rules! impl_eig_expert_r {
+    ($f:ty, $evx:path) => {
+        impl EigExpertImpl for $f {
+            fn eig_expert(
+                balance: Balance,
+                l: MatrixLayout,
+                a: &mut [Self],
+            ) -> Result<EigExpertOwned<Self>> {
+                let (n, _) = l.size();
+                // LAPACK only accepts column-major matrices
+                if let MatrixLayout::C { .. } = l {
+                    square_transpose(l, a);
+                }
+                let mut eigs_re: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut eigs_im: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut vl: Vec<MaybeUninit<Self>> = vec_uninit((n * n) as usize);
+                let mut vr: Vec<MaybeUninit<Self>> = vec_uninit((n * n) as usize);
+                let mut scale: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut rconde: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut rcondv: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit((2 * n).max(1) as usize);
+                let mut ilo = 0;
+                let mut ihi = 0;
+                let mut abnrm = Self::zero();
+                let ldv = n.max(1);
+                // Condition numbers for both eigenvalues and eigenvectors
+                let sense = b'B';
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size: [Self; 1] = [0.0];
+                unsafe {
+                    $evx(
+                        balance.as_ptr(),
+                        JobEv::All.as_ptr(),
+                        JobEv::All.as_ptr(),
+                        &sense as *const u8 as *const i8,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &ldv,
+                        AsPtr::as_mut_ptr(&mut eigs_re),
+                        AsPtr::as_mut_ptr(&mut eigs_im),
+                        AsPtr::as_mut_ptr(&mut vl),
+                        &ldv,
+                        AsPtr::as_mut_ptr(&mut vr),
+                        &ldv,
+                        &mut ilo,
+                        &mut ihi,
+                        AsPtr::as_mut_ptr(&mut scale),
+                        &mut abnrm,
+                        AsPtr::as_mut_ptr(&mut rconde),
+                        AsPtr::as_mut_ptr(&mut rcondv),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                let lwork = lwork.to_i32().unwrap();
+                unsafe {
+                    $evx(
+                        balance.as_ptr(),
+                        JobEv::All.as_ptr(),
+                        JobEv::All.as_ptr(),
+                        &sense as *const u8 as *const i8,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &ldv,
+                        AsPtr::as_mut_ptr(&mut eigs_re),
+                        AsPtr::as_mut_ptr(&mut eigs_im),
+                        AsPtr::as_mut_ptr(&mut vl),
+                        &ldv,
+                        AsPtr::as_mut_ptr(&mut vr),
+                        &ldv,
+                        &mut ilo,
+                        &mut ihi,
+                        AsPtr::as_mut_ptr(&mut scale),
+                        &mut abnrm,
+                        AsPtr::as_mut_ptr(&mut rconde),
+                        AsPtr::as_mut_ptr(&mut rcondv),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                let eigs_re = unsafe { eigs_re.assume_init() };
+                let eigs_im = unsafe { eigs_im.assume_init() };
+                let vl = unsafe { vl.assume_init() };
+                let vr = unsafe { vr.assume_init() };
+                let mut eigs = vec_uninit(n as usize);
+                reconstruct_eigs(&eigs_re, &eigs_im, &mut eigs);
+                let mut vc_l = vec_uninit((n * n) as usize);
+                reconstruct_eigenvectors(false, &eigs_im, &vl, &mut vc_l);
+                let mut vc_r = vec_uninit((n * n) as usize);
+                reconstruct_eigenvectors(false, &eigs_im, &vr, &mut vc_r);
+
+                Ok(EigExpertOwned {
+                    eigs: unsafe { eigs.assume_init() },
+                    vr: unsafe { vc_r.assume_init() },
+                    vl: unsafe { vc_l.assume_init() },
+                    rconde: unsafe { rconde.assume_init() },
+                    rcondv: unsafe { rcondv.assume_init() },
+                    abnrm,
+                    scale: unsafe { scale.assume_init() },
+                    ilo,
+                    ihi,
+                })
+            }
+        }
+    };
+}
+
+impl_eig_expert_r!(f32, lapack_sys::sgeevx_);
+impl_eig_expert_r!(f64, lapack_sys::dgeevx_);
+
 /// Reconstruct eigenvectors into complex-array
 ///
 /// From LAPACK API https://software.intel.com/en-us/node/469230
@@ -404,7 +813,7 @@ impl_eig_work_r!(f64, lapack_sys::dgeev_);
 ///
 /// In the C-layout case, we need the conjugates of the left
 /// eigenvectors, so the signs should be reversed.
-fn reconstruct_eigenvectors<T: Scalar>(
+pub(crate) fn reconstruct_eigenvectors<T: Scalar>(
     take_hermite_conjugate: bool,
     eig_im: &[T],
     vr: &[T],
@@ -441,7 +850,11 @@ fn reconstruct_eigenvectors<T: Scalar>(
 }
 
 /// Create complex eigenvalues from real and imaginary parts.
-fn reconstruct_eigs<T: Scalar>(re: &[T], im: &[T], eigs: &mut [MaybeUninit<T::Complex>]) {
+pub(crate) fn reconstruct_eigs<T: Scalar>(
+    re: &[T],
+    im: &[T],
+    eigs: &mut [MaybeUninit<T::Complex>],
+) {
     let n = eigs.len();
     assert_eq!(re.len(), n);
     assert_eq!(im.len(), n);
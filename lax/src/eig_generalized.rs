@@ -0,0 +1,413 @@
+//! Generalized eigenvalue problem for general matricies
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32   | f64   | c32   | c64   |
+//! |:------|:------|:------|:------|
+//! | sggev | dggev | cggev | zggev |
+//!
+
+use crate::{error::*, layout::MatrixLayout, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Generalized eigenvalue problem for general matrices
+///
+/// This solves $A v = \lambda B v$ for a pair of square matrices $(A, B)$.
+/// The generalized eigenvalues are returned as pairs $(\alpha_i, \beta_i)$
+/// where $\lambda_i = \alpha_i / \beta_i$.
+/// $\beta_i$ may be zero when $B$ is singular, i.e. the eigenvalue is infinite.
+/// $\beta_i$ is always real and non-negative for real matrices.
+///
+/// As in [crate::eig::EigWork], a row-major pair is interpreted as the transposed column-major pair,
+/// and its right eigenvectors are computed as the conjugate of the left eigenvectors of the transposed pair.
+/// Both matrices must be in the same layout.
+///
+#[non_exhaustive]
+pub struct EigGeneralizedWork<T: Scalar> {
+    /// Problem size
+    pub n: i32,
+    /// Compute right eigenvectors or not
+    pub jobvr: JobEv,
+    /// Compute left eigenvectors or not
+    pub jobvl: JobEv,
+
+    /// Numerator of eigenvalues
+    pub alpha: Vec<MaybeUninit<T::Complex>>,
+    /// Real part of numerator of eigenvalues used in real routines
+    pub alpha_re: Option<Vec<MaybeUninit<T::Real>>>,
+    /// Imaginary part of numerator of eigenvalues used in real routines
+    pub alpha_im: Option<Vec<MaybeUninit<T::Real>>>,
+    /// Denominator of eigenvalues
+    pub beta: Vec<MaybeUninit<T>>,
+
+    /// Left eigenvectors
+    pub vc_l: Option<Vec<MaybeUninit<T::Complex>>>,
+    /// Left eigenvectors used in real routines
+    pub vr_l: Option<Vec<MaybeUninit<T::Real>>>,
+    /// Right eigenvectors
+    pub vc_r: Option<Vec<MaybeUninit<T::Complex>>>,
+    /// Right eigenvectors used in real routines
+    pub vr_r: Option<Vec<MaybeUninit<T::Real>>>,
+
+    /// Working memory
+    pub work: Vec<MaybeUninit<T>>,
+    /// Working memory with `T::Real`
+    pub rwork: Option<Vec<MaybeUninit<T::Real>>>,
+}
+
+impl<T> EigGeneralizedWork<T>
+where
+    T: Scalar,
+    EigGeneralizedWork<T>: EigGeneralizedWorkImpl<Elem = T>,
+{
+    /// Create new working memory for generalized eigenvalues compution.
+    pub fn new(calc_v: bool, l: MatrixLayout) -> Result<Self> {
+        EigGeneralizedWorkImpl::new(calc_v, l)
+    }
+
+    /// Compute generalized eigenvalues and vectors on this working memory.
+    pub fn calc(&mut self, a: &mut [T], b: &mut [T]) -> Result<EigGeneralizedRef<'_, T>> {
+        EigGeneralizedWorkImpl::calc(self, a, b)
+    }
+
+    /// Compute generalized eigenvalues and vectors by consuming this working memory.
+    pub fn eval(self, a: &mut [T], b: &mut [T]) -> Result<EigGeneralizedOwned<T>> {
+        EigGeneralizedWorkImpl::eval(self, a, b)
+    }
+}
+
+/// Owned result of generalized eigenvalue problem by [EigGeneralizedWork::eval]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EigGeneralizedOwned<T: Scalar> {
+    /// Numerator of eigenvalues
+    pub alpha: Vec<T::Complex>,
+    /// Denominator of eigenvalues
+    pub beta: Vec<T>,
+    /// Right eigenvectors
+    pub vr: Option<Vec<T::Complex>>,
+    /// Left eigenvectors
+    pub vl: Option<Vec<T::Complex>>,
+}
+
+/// Reference result of generalized eigenvalue problem by [EigGeneralizedWork::calc]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EigGeneralizedRef<'work, T: Scalar> {
+    /// Numerator of eigenvalues
+    pub alpha: &'work [T::Complex],
+    /// Denominator of eigenvalues
+    pub beta: &'work [T],
+    /// Right eigenvectors
+    pub vr: Option<&'work [T::Complex]>,
+    /// Left eigenvectors
+    pub vl: Option<&'work [T::Complex]>,
+}
+
+/// Helper trait for implementing [EigGeneralizedWork] methods
+pub trait EigGeneralizedWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(calc_v: bool, l: MatrixLayout) -> Result<Self>;
+    fn calc<'work>(
+        &'work mut self,
+        a: &mut [Self::Elem],
+        b: &mut [Self::Elem],
+    ) -> Result<EigGeneralizedRef<'work, Self::Elem>>;
+    fn eval(
+        self,
+        a: &mut [Self::Elem],
+        b: &mut [Self::Elem],
+    ) -> Result<EigGeneralizedOwned<Self::Elem>>;
+}
+
+macro_rules! impl_eig_generalized_work_c {
+    ($c:ty, $ev:path) => {
+        impl EigGeneralizedWorkImpl for EigGeneralizedWork<$c> {
+            type Elem = $c;
+
+            fn new(calc_v: bool, l: MatrixLayout) -> Result<Self> {
+                let (n, _) = l.size();
+                let (jobvl, jobvr) = if calc_v {
+                    match l {
+                        MatrixLayout::C { .. } => (JobEv::All, JobEv::None),
+                        MatrixLayout::F { .. } => (JobEv::None, JobEv::All),
+                    }
+                } else {
+                    (JobEv::None, JobEv::None)
+                };
+                let mut alpha = vec_uninit(n as usize);
+                let mut beta = vec_uninit(n as usize);
+                let mut rwork = vec_uninit(8 * n as usize);
+
+                let mut vc_l = jobvl.then(|| vec_uninit((n * n) as usize));
+                let mut vc_r = jobvr.then(|| vec_uninit((n * n) as usize));
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [<$c>::zero()];
+                unsafe {
+                    $ev(
+                        jobvl.as_ptr(),
+                        jobvr.as_ptr(),
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut alpha),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(vc_l.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        AsPtr::as_mut_ptr(vc_r.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                let lwork = work_size[0].to_usize().unwrap();
+                let work: Vec<MaybeUninit<$c>> = vec_uninit(lwork);
+                Ok(Self {
+                    n,
+                    jobvl,
+                    jobvr,
+                    alpha,
+                    alpha_re: None,
+                    alpha_im: None,
+                    beta,
+                    rwork: Some(rwork),
+                    vc_l,
+                    vc_r,
+                    vr_l: None,
+                    vr_r: None,
+                    work,
+                })
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+            ) -> Result<EigGeneralizedRef<'work, Self::Elem>> {
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut info = 0;
+                unsafe {
+                    $ev(
+                        self.jobvl.as_ptr(),
+                        self.jobvr.as_ptr(),
+                        &self.n,
+                        AsPtr::as_mut_ptr(a),
+                        &self.n,
+                        AsPtr::as_mut_ptr(b),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.alpha),
+                        AsPtr::as_mut_ptr(&mut self.beta),
+                        AsPtr::as_mut_ptr(self.vc_l.as_deref_mut().unwrap_or(&mut [])),
+                        &self.n,
+                        AsPtr::as_mut_ptr(self.vc_r.as_deref_mut().unwrap_or(&mut [])),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(self.rwork.as_mut().unwrap()),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                // Hermite conjugate
+                if let Some(vl) = self.vc_l.as_mut() {
+                    for value in vl {
+                        let value = unsafe { value.assume_init_mut() };
+                        value.im = -value.im;
+                    }
+                }
+                Ok(EigGeneralizedRef {
+                    alpha: unsafe { self.alpha.slice_assume_init_ref() },
+                    beta: unsafe { self.beta.slice_assume_init_ref() },
+                    vl: self
+                        .vc_l
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() }),
+                    vr: self
+                        .vc_r
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() }),
+                })
+            }
+
+            fn eval(
+                mut self,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+            ) -> Result<EigGeneralizedOwned<Self::Elem>> {
+                let _eig_ref = self.calc(a, b)?;
+                Ok(EigGeneralizedOwned {
+                    alpha: unsafe { self.alpha.assume_init() },
+                    beta: unsafe { self.beta.assume_init() },
+                    vl: self.vc_l.map(|v| unsafe { v.assume_init() }),
+                    vr: self.vc_r.map(|v| unsafe { v.assume_init() }),
+                })
+            }
+        }
+    };
+}
+
+impl_eig_generalized_work_c!(c32, lapack_sys::cggev_);
+impl_eig_generalized_work_c!(c64, lapack_sys::zggev_);
+
+macro_rules! impl_eig_generalized_work_r {
+    ($f:ty, $ev:path) => {
+        impl EigGeneralizedWorkImpl for EigGeneralizedWork<$f> {
+            type Elem = $f;
+
+            fn new(calc_v: bool, l: MatrixLayout) -> Result<Self> {
+                let (n, _) = l.size();
+                let (jobvl, jobvr) = if calc_v {
+                    match l {
+                        MatrixLayout::C { .. } => (JobEv::All, JobEv::None),
+                        MatrixLayout::F { .. } => (JobEv::None, JobEv::All),
+                    }
+                } else {
+                    (JobEv::None, JobEv::None)
+                };
+                let mut alpha_re = vec_uninit(n as usize);
+                let mut alpha_im = vec_uninit(n as usize);
+                let mut beta = vec_uninit(n as usize);
+                let mut vr_l = jobvl.then(|| vec_uninit((n * n) as usize));
+                let mut vr_r = jobvr.then(|| vec_uninit((n * n) as usize));
+                let vc_l = jobvl.then(|| vec_uninit((n * n) as usize));
+                let vc_r = jobvr.then(|| vec_uninit((n * n) as usize));
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size: [$f; 1] = [0.0];
+                unsafe {
+                    $ev(
+                        jobvl.as_ptr(),
+                        jobvr.as_ptr(),
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut alpha_re),
+                        AsPtr::as_mut_ptr(&mut alpha_im),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(vr_l.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        AsPtr::as_mut_ptr(vr_r.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                let lwork = work_size[0].to_usize().unwrap();
+                let work = vec_uninit(lwork);
+
+                Ok(Self {
+                    n,
+                    jobvr,
+                    jobvl,
+                    alpha: vec_uninit(n as usize),
+                    alpha_re: Some(alpha_re),
+                    alpha_im: Some(alpha_im),
+                    beta,
+                    rwork: None,
+                    vr_l,
+                    vr_r,
+                    vc_l,
+                    vc_r,
+                    work,
+                })
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+            ) -> Result<EigGeneralizedRef<'work, Self::Elem>> {
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut info = 0;
+                unsafe {
+                    $ev(
+                        self.jobvl.as_ptr(),
+                        self.jobvr.as_ptr(),
+                        &self.n,
+                        AsPtr::as_mut_ptr(a),
+                        &self.n,
+                        AsPtr::as_mut_ptr(b),
+                        &self.n,
+                        AsPtr::as_mut_ptr(self.alpha_re.as_mut().unwrap()),
+                        AsPtr::as_mut_ptr(self.alpha_im.as_mut().unwrap()),
+                        AsPtr::as_mut_ptr(&mut self.beta),
+                        AsPtr::as_mut_ptr(self.vr_l.as_deref_mut().unwrap_or(&mut [])),
+                        &self.n,
+                        AsPtr::as_mut_ptr(self.vr_r.as_deref_mut().unwrap_or(&mut [])),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                let alpha_re = self
+                    .alpha_re
+                    .as_ref()
+                    .map(|e| unsafe { e.slice_assume_init_ref() })
+                    .unwrap();
+                let alpha_im = self
+                    .alpha_im
+                    .as_ref()
+                    .map(|e| unsafe { e.slice_assume_init_ref() })
+                    .unwrap();
+                eig::reconstruct_eigs(alpha_re, alpha_im, &mut self.alpha);
+
+                if let Some(v) = self.vr_l.as_ref() {
+                    let v = unsafe { v.slice_assume_init_ref() };
+                    eig::reconstruct_eigenvectors(true, alpha_im, v, self.vc_l.as_mut().unwrap());
+                }
+                if let Some(v) = self.vr_r.as_ref() {
+                    let v = unsafe { v.slice_assume_init_ref() };
+                    eig::reconstruct_eigenvectors(false, alpha_im, v, self.vc_r.as_mut().unwrap());
+                }
+
+                Ok(EigGeneralizedRef {
+                    alpha: unsafe { self.alpha.slice_assume_init_ref() },
+                    beta: unsafe { self.beta.slice_assume_init_ref() },
+                    vl: self
+                        .vc_l
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() }),
+                    vr: self
+                        .vc_r
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() }),
+                })
+            }
+
+            fn eval(
+                mut self,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+            ) -> Result<EigGeneralizedOwned<Self::Elem>> {
+                let _eig_ref = self.calc(a, b)?;
+                Ok(EigGeneralizedOwned {
+                    alpha: unsafe { self.alpha.assume_init() },
+                    beta: unsafe { self.beta.assume_init() },
+                    vl: self.vc_l.map(|v| unsafe { v.assume_init() }),
+                    vr: self.vc_r.map(|v| unsafe { v.assume_init() }),
+                })
+            }
+        }
+    };
+}
+
+impl_eig_generalized_work_r!(f32, lapack_sys::sggev_);
+impl_eig_generalized_work_r!(f64, lapack_sys::dggev_);
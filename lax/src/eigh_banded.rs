@@ -0,0 +1,490 @@
+//! Eigenvalue problem for symmetric/Hermitian banded matrices
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | ssbevd | dsbevd | chbevd | zhbevd |
+//! | ssbevx | dsbevx | chbevx | zhbevx |
+
+use super::*;
+use crate::{
+    eigh_range::{range_args, EighRangeRef},
+    error::*,
+    layout::MatrixLayout,
+};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+/// Compute all eigenvalues and eigenvectors using divide-and-conquer algorithm
+pub struct EighBandedWork<T: Scalar> {
+    pub n: i32,
+    pub jobz: JobEv,
+    pub eigs: Vec<MaybeUninit<T::Real>>,
+    pub z: Option<Vec<MaybeUninit<T>>>,
+    pub work: Vec<MaybeUninit<T>>,
+    pub rwork: Option<Vec<MaybeUninit<T::Real>>>,
+    pub iwork: Vec<MaybeUninit<i32>>,
+}
+
+pub trait EighBandedWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(calc_eigenvectors: bool, layout: MatrixLayout) -> Result<Self>;
+    fn calc<'work>(
+        &'work mut self,
+        uplo: UPLO,
+        a: &Banded<Self::Elem>,
+    ) -> Result<EighRangeRef<'work, Self::Elem>>;
+    fn eval(self, uplo: UPLO, a: &Banded<Self::Elem>) -> Result<EighRangeOwned<Self::Elem>>;
+}
+
+macro_rules! impl_eigh_banded_work_c {
+    ($c:ty, $evd:path) => {
+        impl EighBandedWorkImpl for EighBandedWork<$c> {
+            type Elem = $c;
+
+            fn new(calc_eigenvectors: bool, layout: MatrixLayout) -> Result<Self> {
+                let (n, _) = layout.size();
+                let jobz = if calc_eigenvectors {
+                    JobEv::All
+                } else {
+                    JobEv::None
+                };
+                let mut eigs = vec_uninit(n as usize);
+                let mut z = jobz.then(|| vec_uninit((n * n) as usize));
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                let mut rwork_size = [<$c as Scalar>::Real::zero()];
+                let mut iwork_size = [0];
+                unsafe {
+                    $evd(
+                        jobz.as_ptr(),
+                        UPLO::Upper.as_ptr(), // dummy, working memory is not affected by UPLO
+                        &n,
+                        &0, // dummy, working memory is not affected by KD
+                        std::ptr::null_mut(),
+                        &1,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(z.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut iwork_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let work = vec_uninit(work_size[0].to_usize().unwrap());
+                let rwork = vec_uninit(rwork_size[0].to_usize().unwrap());
+                let iwork = vec_uninit(iwork_size[0].to_usize().unwrap());
+                Ok(EighBandedWork {
+                    n,
+                    jobz,
+                    eigs,
+                    z,
+                    work,
+                    rwork: Some(rwork),
+                    iwork,
+                })
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                uplo: UPLO,
+                a: &Banded<Self::Elem>,
+            ) -> Result<EighRangeRef<'work, Self::Elem>> {
+                let (kd, mut ab) = a.triangle(uplo);
+                let lwork = self.work.len().to_i32().unwrap();
+                let lrwork = self.rwork.as_ref().unwrap().len().to_i32().unwrap();
+                let liwork = self.iwork.len().to_i32().unwrap();
+                let mut info = 0;
+                unsafe {
+                    $evd(
+                        self.jobz.as_ptr(),
+                        uplo.as_ptr(),
+                        &self.n,
+                        &kd,
+                        AsPtr::as_mut_ptr(&mut ab),
+                        &(kd + 1),
+                        AsPtr::as_mut_ptr(&mut self.eigs),
+                        AsPtr::as_mut_ptr(self.z.as_deref_mut().unwrap_or(&mut [])),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(self.rwork.as_mut().unwrap()),
+                        &lrwork,
+                        AsPtr::as_mut_ptr(&mut self.iwork),
+                        &liwork,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(EighRangeRef {
+                    eigs: unsafe { self.eigs.slice_assume_init_ref() },
+                    z: self
+                        .z
+                        .as_ref()
+                        .map(|z| unsafe { z.slice_assume_init_ref() }),
+                })
+            }
+
+            fn eval(
+                mut self,
+                uplo: UPLO,
+                a: &Banded<Self::Elem>,
+            ) -> Result<EighRangeOwned<Self::Elem>> {
+                let _eig = self.calc(uplo, a)?;
+                Ok(EighRangeOwned {
+                    eigs: unsafe { self.eigs.assume_init() },
+                    z: self.z.map(|z| unsafe { z.assume_init() }),
+                })
+            }
+        }
+    };
+}
+impl_eigh_banded_work_c!(c64, lapack_sys::zhbevd_);
+impl_eigh_banded_work_c!(c32, lapack_sys::chbevd_);
+
+macro_rules! impl_eigh_banded_work_r {
+    ($f:ty, $evd:path) => {
+        impl EighBandedWorkImpl for EighBandedWork<$f> {
+            type Elem = $f;
+
+            fn new(calc_eigenvectors: bool, layout: MatrixLayout) -> Result<Self> {
+                let (n, _) = layout.size();
+                let jobz = if calc_eigenvectors {
+                    JobEv::All
+                } else {
+                    JobEv::None
+                };
+                let mut eigs = vec_uninit(n as usize);
+                let mut z = jobz.then(|| vec_uninit((n * n) as usize));
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                let mut iwork_size = [0];
+                unsafe {
+                    $evd(
+                        jobz.as_ptr(),
+                        UPLO::Upper.as_ptr(), // dummy, working memory is not affected by UPLO
+                        &n,
+                        &0, // dummy, working memory is not affected by KD
+                        std::ptr::null_mut(),
+                        &1,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(z.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut iwork_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let work = vec_uninit(work_size[0].to_usize().unwrap());
+                let iwork = vec_uninit(iwork_size[0].to_usize().unwrap());
+                Ok(EighBandedWork {
+                    n,
+                    jobz,
+                    eigs,
+                    z,
+                    work,
+                    rwork: None,
+                    iwork,
+                })
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                uplo: UPLO,
+                a: &Banded<Self::Elem>,
+            ) -> Result<EighRangeRef<'work, Self::Elem>> {
+                let (kd, mut ab) = a.triangle(uplo);
+                let lwork = self.work.len().to_i32().unwrap();
+                let liwork = self.iwork.len().to_i32().unwrap();
+                let mut info = 0;
+                unsafe {
+                    $evd(
+                        self.jobz.as_ptr(),
+                        uplo.as_ptr(),
+                        &self.n,
+                        &kd,
+                        AsPtr::as_mut_ptr(&mut ab),
+                        &(kd + 1),
+                        AsPtr::as_mut_ptr(&mut self.eigs),
+                        AsPtr::as_mut_ptr(self.z.as_deref_mut().unwrap_or(&mut [])),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(&mut self.iwork),
+                        &liwork,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(EighRangeRef {
+                    eigs: unsafe { self.eigs.slice_assume_init_ref() },
+                    z: self
+                        .z
+                        .as_ref()
+                        .map(|z| unsafe { z.slice_assume_init_ref() }),
+                })
+            }
+
+            fn eval(
+                mut self,
+                uplo: UPLO,
+                a: &Banded<Self::Elem>,
+            ) -> Result<EighRangeOwned<Self::Elem>> {
+                let _eig = self.calc(uplo, a)?;
+                Ok(EighRangeOwned {
+                    eigs: unsafe { self.eigs.assume_init() },
+                    z: self.z.map(|z| unsafe { z.assume_init() }),
+                })
+            }
+        }
+    };
+}
+impl_eigh_banded_work_r!(f64, lapack_sys::dsbevd_);
+impl_eigh_banded_work_r!(f32, lapack_sys::ssbevd_);
+
+/// Compute a subset of eigenvalues and eigenvectors using bisection and inverse iteration
+pub struct EighBandedRangeWork<T: Scalar> {
+    pub n: i32,
+    pub jobz: JobEv,
+    pub range: EigRange<T::Real>,
+    pub eigs: Vec<MaybeUninit<T::Real>>,
+    pub z: Option<Vec<MaybeUninit<T>>>,
+    /// Orthogonal/unitary matrix used in the reduction to tridiagonal form
+    pub q: Option<Vec<MaybeUninit<T>>>,
+    pub work: Vec<MaybeUninit<T>>,
+    pub rwork: Option<Vec<MaybeUninit<T::Real>>>,
+    pub iwork: Vec<MaybeUninit<i32>>,
+    pub ifail: Vec<MaybeUninit<i32>>,
+}
+
+pub trait EighBandedRangeWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(
+        calc_eigenvectors: bool,
+        layout: MatrixLayout,
+        range: EigRange<<Self::Elem as Scalar>::Real>,
+    ) -> Result<Self>;
+    fn calc<'work>(
+        &'work mut self,
+        uplo: UPLO,
+        a: &Banded<Self::Elem>,
+    ) -> Result<EighRangeRef<'work, Self::Elem>>;
+    fn eval(self, uplo: UPLO, a: &Banded<Self::Elem>) -> Result<EighRangeOwned<Self::Elem>>;
+}
+
+macro_rules! impl_eigh_banded_range_work_c {
+    ($s:ty, $evx:path) => {
+        impl EighBandedRangeWorkImpl for EighBandedRangeWork<$s> {
+            type Elem = $s;
+
+            fn new(
+                calc_eigenvectors: bool,
+                layout: MatrixLayout,
+                range: EigRange<<$s as Scalar>::Real>,
+            ) -> Result<Self> {
+                let (n, _) = layout.size();
+                let jobz = if calc_eigenvectors {
+                    JobEv::All
+                } else {
+                    JobEv::None
+                };
+                let n_ = n as usize;
+                let m_max = range.max_len(n_);
+                Ok(EighBandedRangeWork {
+                    n,
+                    jobz,
+                    range,
+                    eigs: vec_uninit(n_),
+                    z: jobz.then(|| vec_uninit(n_ * m_max)),
+                    q: jobz.then(|| vec_uninit(n_ * n_)),
+                    work: vec_uninit(n_),
+                    rwork: Some(vec_uninit(7 * n_)),
+                    iwork: vec_uninit(5 * n_),
+                    ifail: vec_uninit(n_),
+                })
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                uplo: UPLO,
+                a: &Banded<Self::Elem>,
+            ) -> Result<EighRangeRef<'work, Self::Elem>> {
+                let (kd, mut ab) = a.triangle(uplo);
+                let (vl, vu, il, iu) = range_args(self.range);
+                let ld = std::cmp::max(1, self.n);
+                let mut m = 0;
+                let mut info = 0;
+                unsafe {
+                    $evx(
+                        self.jobz.as_ptr(),
+                        self.range.as_ptr(),
+                        uplo.as_ptr(),
+                        &self.n,
+                        &kd,
+                        AsPtr::as_mut_ptr(&mut ab),
+                        &(kd + 1),
+                        AsPtr::as_mut_ptr(self.q.as_deref_mut().unwrap_or(&mut [])),
+                        &ld,
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &Zero::zero(), // abstol: use default tolerance
+                        &mut m,
+                        AsPtr::as_mut_ptr(&mut self.eigs),
+                        AsPtr::as_mut_ptr(self.z.as_deref_mut().unwrap_or(&mut [])),
+                        &ld,
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        AsPtr::as_mut_ptr(self.rwork.as_mut().unwrap()),
+                        AsPtr::as_mut_ptr(&mut self.iwork),
+                        AsPtr::as_mut_ptr(&mut self.ifail),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                let m = m as usize;
+                let n = self.n as usize;
+                Ok(EighRangeRef {
+                    eigs: unsafe { &self.eigs.slice_assume_init_ref()[..m] },
+                    z: self
+                        .z
+                        .as_ref()
+                        .map(|z| unsafe { &z.slice_assume_init_ref()[..n * m] }),
+                })
+            }
+
+            fn eval(
+                mut self,
+                uplo: UPLO,
+                a: &Banded<Self::Elem>,
+            ) -> Result<EighRangeOwned<Self::Elem>> {
+                let m = self.calc(uplo, a)?.eigs.len();
+                let n = self.n as usize;
+                let mut eigs = unsafe { self.eigs.assume_init() };
+                eigs.truncate(m);
+                let z = self.z.map(|z| {
+                    let mut z = unsafe { z.assume_init() };
+                    z.truncate(n * m);
+                    z
+                });
+                Ok(EighRangeOwned { eigs, z })
+            }
+        }
+    };
+}
+impl_eigh_banded_range_work_c!(c64, lapack_sys::zhbevx_);
+impl_eigh_banded_range_work_c!(c32, lapack_sys::chbevx_);
+
+macro_rules! impl_eigh_banded_range_work_r {
+    ($s:ty, $evx:path) => {
+        impl EighBandedRangeWorkImpl for EighBandedRangeWork<$s> {
+            type Elem = $s;
+
+            fn new(
+                calc_eigenvectors: bool,
+                layout: MatrixLayout,
+                range: EigRange<<$s as Scalar>::Real>,
+            ) -> Result<Self> {
+                let (n, _) = layout.size();
+                let jobz = if calc_eigenvectors {
+                    JobEv::All
+                } else {
+                    JobEv::None
+                };
+                let n_ = n as usize;
+                let m_max = range.max_len(n_);
+                Ok(EighBandedRangeWork {
+                    n,
+                    jobz,
+                    range,
+                    eigs: vec_uninit(n_),
+                    z: jobz.then(|| vec_uninit(n_ * m_max)),
+                    q: jobz.then(|| vec_uninit(n_ * n_)),
+                    work: vec_uninit(7 * n_),
+                    rwork: None,
+                    iwork: vec_uninit(5 * n_),
+                    ifail: vec_uninit(n_),
+                })
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                uplo: UPLO,
+                a: &Banded<Self::Elem>,
+            ) -> Result<EighRangeRef<'work, Self::Elem>> {
+                let (kd, mut ab) = a.triangle(uplo);
+                let (vl, vu, il, iu) = range_args(self.range);
+                let ld = std::cmp::max(1, self.n);
+                let mut m = 0;
+                let mut info = 0;
+                unsafe {
+                    $evx(
+                        self.jobz.as_ptr(),
+                        self.range.as_ptr(),
+                        uplo.as_ptr(),
+                        &self.n,
+                        &kd,
+                        AsPtr::as_mut_ptr(&mut ab),
+                        &(kd + 1),
+                        AsPtr::as_mut_ptr(self.q.as_deref_mut().unwrap_or(&mut [])),
+                        &ld,
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &Zero::zero(), // abstol: use default tolerance
+                        &mut m,
+                        AsPtr::as_mut_ptr(&mut self.eigs),
+                        AsPtr::as_mut_ptr(self.z.as_deref_mut().unwrap_or(&mut [])),
+                        &ld,
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        AsPtr::as_mut_ptr(&mut self.iwork),
+                        AsPtr::as_mut_ptr(&mut self.ifail),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                let m = m as usize;
+                let n = self.n as usize;
+                Ok(EighRangeRef {
+                    eigs: unsafe { &self.eigs.slice_assume_init_ref()[..m] },
+                    z: self
+                        .z
+                        .as_ref()
+                        .map(|z| unsafe { &z.slice_assume_init_ref()[..n * m] }),
+                })
+            }
+
+            fn eval(
+                mut self,
+                uplo: UPLO,
+                a: &Banded<Self::Elem>,
+            ) -> Result<EighRangeOwned<Self::Elem>> {
+                let m = self.calc(uplo, a)?.eigs.len();
+                let n = self.n as usize;
+                let mut eigs = unsafe { self.eigs.assume_init() };
+                eigs.truncate(m);
+                let z = self.z.map(|z| {
+                    let mut z = unsafe { z.assume_init() };
+                    z.truncate(n * m);
+                    z
+                });
+                Ok(EighRangeOwned { eigs, z })
+            }
+        }
+    };
+}
+impl_eigh_banded_range_work_r!(f64, lapack_sys::dsbevx_);
+impl_eigh_banded_range_work_r!(f32, lapack_sys::ssbevx_);
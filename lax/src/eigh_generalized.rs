@@ -3,9 +3,11 @@
 //! LAPACK correspondance
 //! ----------------------
 //!
-//! | f32   | f64   | c32   | c64   |
-//! |:------|:------|:------|:------|
-//! | ssygv | dsygv | chegv | zhegv |
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | ssygv  | dsygv  | chegv  | zhegv  |
+//! | ssygst | dsygst | chegst | zhegst |
+//! | strtrs | dtrtrs | ctrtrs | ztrtrs |
 //!
 
 use super::*;
@@ -214,3 +216,89 @@ macro_rules! impl_eigh_generalized_work_r {
 }
 impl_eigh_generalized_work_r!(f64, lapack_sys::dsygv_);
 impl_eigh_generalized_work_r!(f32, lapack_sys::ssygv_);
+
+/// Solve generalized eigenvalue problem using MRRR algorithm
+///
+/// LAPACK does not provide a generalized driver for MRRR algorithm.
+/// This reduces the problem into the standard form $C = U^{-\dagger} A U^{-1}$ (or $C = L^{-1} A L^{-\dagger}$)
+/// using the Cholesky decomposition of $B$, solves it using `?syevr`/`?heevr`,
+/// and back-transforms the eigenvectors.
+/// `a` is overwritten by the eigenvectors, and `b` by the Cholesky factor.
+pub trait EighGeneralizedRrrImpl: Scalar {
+    fn eigh_generalized_rrr(
+        calc_eigenvec: bool,
+        layout: MatrixLayout,
+        uplo: UPLO,
+        a: &mut [Self],
+        b: &mut [Self],
+    ) -> Result<Vec<Self::Real>>;
+}
+
+macro_rules! impl_eigh_generalized_rrr {
+    ($s:ty, $gst:path, $trtrs:path) => {
+        impl EighGeneralizedRrrImpl for $s {
+            fn eigh_generalized_rrr(
+                calc_eigenvec: bool,
+                layout: MatrixLayout,
+                uplo: UPLO,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<Vec<Self::Real>> {
+                use crate::eigh_range::*;
+
+                assert_eq!(layout.len(), layout.lda());
+                let n = layout.len();
+                // Same as other drivers, matrices are regarded as column-major
+                let layout = MatrixLayout::F { col: n, lda: n };
+
+                <Self as Lapack>::cholesky(layout, uplo, b)?;
+                let mut info = 0;
+                unsafe {
+                    $gst(
+                        &1, // ITYPE A*x = (lambda)*B*x
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n,
+                        AsPtr::as_mut_ptr(b),
+                        &n,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                let work = EighRangeWork::<$s>::new(calc_eigenvec, layout, EigRange::All)?;
+                let EighRangeOwned { eigs, z } = work.eval(uplo, a)?;
+
+                if let Some(mut z) = z {
+                    // x = U^{-1} y, or x = L^{-H} y
+                    let trans = match uplo {
+                        UPLO::Upper => Transpose::No,
+                        UPLO::Lower => Transpose::Hermite,
+                    };
+                    unsafe {
+                        $trtrs(
+                            uplo.as_ptr(),
+                            trans.as_ptr(),
+                            Diag::NonUnit.as_ptr(),
+                            &n,
+                            &n,
+                            AsPtr::as_ptr(b),
+                            &n,
+                            AsPtr::as_mut_ptr(&mut z),
+                            &n,
+                            &mut info,
+                        );
+                    }
+                    info.as_lapack_result()?;
+                    a.copy_from_slice(&z);
+                }
+                Ok(eigs)
+            }
+        }
+    };
+}
+impl_eigh_generalized_rrr!(c64, lapack_sys::zhegst_, lapack_sys::ztrtrs_);
+impl_eigh_generalized_rrr!(c32, lapack_sys::chegst_, lapack_sys::ctrtrs_);
+impl_eigh_generalized_rrr!(f64, lapack_sys::dsygst_, lapack_sys::dtrtrs_);
+impl_eigh_generalized_rrr!(f32, lapack_sys::ssygst_, lapack_sys::strtrs_);
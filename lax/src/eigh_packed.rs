@@ -0,0 +1,168 @@
+//! Eigenvalue problem for symmetric/Hermitian matrices in packed storage
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | sspev  | dspev  | chpev  | zhpev  |
+
+use super::*;
+use crate::{eigh_range::EighRangeRef, error::*, layout::MatrixLayout};
+use cauchy::*;
+
+/// Compute all eigenvalues and eigenvectors using QR algorithm
+///
+/// The packed matrix is destroyed during the computation.
+pub struct EighPackedWork<T: Scalar> {
+    pub n: i32,
+    pub jobz: JobEv,
+    pub eigs: Vec<MaybeUninit<T::Real>>,
+    pub z: Option<Vec<MaybeUninit<T>>>,
+    pub work: Vec<MaybeUninit<T>>,
+    pub rwork: Option<Vec<MaybeUninit<T::Real>>>,
+}
+
+pub trait EighPackedWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(calc_eigenvectors: bool, layout: MatrixLayout) -> Result<Self>;
+    fn calc<'work>(
+        &'work mut self,
+        a: &mut Packed<Self::Elem>,
+    ) -> Result<EighRangeRef<'work, Self::Elem>>;
+    fn eval(self, a: &mut Packed<Self::Elem>) -> Result<EighRangeOwned<Self::Elem>>;
+}
+
+macro_rules! impl_eigh_packed_work_c {
+    ($c:ty, $ev:path) => {
+        impl EighPackedWorkImpl for EighPackedWork<$c> {
+            type Elem = $c;
+
+            fn new(calc_eigenvectors: bool, layout: MatrixLayout) -> Result<Self> {
+                let n = layout.len();
+                let jobz = if calc_eigenvectors {
+                    JobEv::All
+                } else {
+                    JobEv::None
+                };
+                let eigs = vec_uninit(n as usize);
+                let z = jobz.then(|| vec_uninit((n * n) as usize));
+                let work = vec_uninit(std::cmp::max(1, 2 * n - 1) as usize);
+                let rwork = vec_uninit(std::cmp::max(1, 3 * n - 2) as usize);
+                Ok(EighPackedWork {
+                    n,
+                    jobz,
+                    eigs,
+                    z,
+                    work,
+                    rwork: Some(rwork),
+                })
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                a: &mut Packed<Self::Elem>,
+            ) -> Result<EighRangeRef<'work, Self::Elem>> {
+                let mut info = 0;
+                unsafe {
+                    $ev(
+                        self.jobz.as_ptr(),
+                        a.uplo.as_ptr(),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut a.ap),
+                        AsPtr::as_mut_ptr(&mut self.eigs),
+                        AsPtr::as_mut_ptr(self.z.as_deref_mut().unwrap_or(&mut [])),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        AsPtr::as_mut_ptr(self.rwork.as_mut().unwrap()),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(EighRangeRef {
+                    eigs: unsafe { self.eigs.slice_assume_init_ref() },
+                    z: self
+                        .z
+                        .as_ref()
+                        .map(|z| unsafe { z.slice_assume_init_ref() }),
+                })
+            }
+
+            fn eval(mut self, a: &mut Packed<Self::Elem>) -> Result<EighRangeOwned<Self::Elem>> {
+                let _eig = self.calc(a)?;
+                Ok(EighRangeOwned {
+                    eigs: unsafe { self.eigs.assume_init() },
+                    z: self.z.map(|z| unsafe { z.assume_init() }),
+                })
+            }
+        }
+    };
+}
+impl_eigh_packed_work_c!(c64, lapack_sys::zhpev_);
+impl_eigh_packed_work_c!(c32, lapack_sys::chpev_);
+
+macro_rules! impl_eigh_packed_work_r {
+    ($f:ty, $ev:path) => {
+        impl EighPackedWorkImpl for EighPackedWork<$f> {
+            type Elem = $f;
+
+            fn new(calc_eigenvectors: bool, layout: MatrixLayout) -> Result<Self> {
+                let n = layout.len();
+                let jobz = if calc_eigenvectors {
+                    JobEv::All
+                } else {
+                    JobEv::None
+                };
+                let eigs = vec_uninit(n as usize);
+                let z = jobz.then(|| vec_uninit((n * n) as usize));
+                let work = vec_uninit(std::cmp::max(1, 3 * n) as usize);
+                Ok(EighPackedWork {
+                    n,
+                    jobz,
+                    eigs,
+                    z,
+                    work,
+                    rwork: None,
+                })
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                a: &mut Packed<Self::Elem>,
+            ) -> Result<EighRangeRef<'work, Self::Elem>> {
+                let mut info = 0;
+                unsafe {
+                    $ev(
+                        self.jobz.as_ptr(),
+                        a.uplo.as_ptr(),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut a.ap),
+                        AsPtr::as_mut_ptr(&mut self.eigs),
+                        AsPtr::as_mut_ptr(self.z.as_deref_mut().unwrap_or(&mut [])),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(EighRangeRef {
+                    eigs: unsafe { self.eigs.slice_assume_init_ref() },
+                    z: self
+                        .z
+                        .as_ref()
+                        .map(|z| unsafe { z.slice_assume_init_ref() }),
+                })
+            }
+
+            fn eval(mut self, a: &mut Packed<Self::Elem>) -> Result<EighRangeOwned<Self::Elem>> {
+                let _eig = self.calc(a)?;
+                Ok(EighRangeOwned {
+                    eigs: unsafe { self.eigs.assume_init() },
+                    z: self.z.map(|z| unsafe { z.assume_init() }),
+                })
+            }
+        }
+    };
+}
+impl_eigh_packed_work_r!(f64, lapack_sys::dspev_);
+impl_eigh_packed_work_r!(f32, lapack_sys::sspev_);
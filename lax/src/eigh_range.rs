@@ -0,0 +1,405 @@
+//! Eigenvalue problem for symmetric/Hermitian matricies computing a subset of the spectrum
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | ssyevr | dsyevr | cheevr | zheevr |
+
+use super::*;
+use crate::{error::*, layout::MatrixLayout};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+/// Eigenvalue problem for symmetric/Hermitian matrix
+/// computing only the eigenvalues (and eigenvectors) specified by [EigRange]
+///
+/// This uses the Relatively Robust Representations (RRR) algorithm.
+/// Eigenvalues are returned in ascending order,
+/// and the corresponding eigenvectors are stored as columns of
+/// a column-major $n \times m$ matrix where $m$ is the number of found eigenvalues.
+#[cfg_attr(doc, katexit::katexit)]
+pub struct EighRangeWork<T: Scalar> {
+    pub n: i32,
+    pub layout: MatrixLayout,
+    pub jobz: JobEv,
+    pub range: EigRange<T::Real>,
+    pub eigs: Vec<MaybeUninit<T::Real>>,
+    pub z: Option<Vec<MaybeUninit<T>>>,
+    pub isuppz: Vec<MaybeUninit<i32>>,
+    pub work: Vec<MaybeUninit<T>>,
+    pub rwork: Option<Vec<MaybeUninit<T::Real>>>,
+    pub iwork: Vec<MaybeUninit<i32>>,
+}
+
+/// Owned result of [EighRangeWork::eval]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EighRangeOwned<T: Scalar> {
+    /// Eigenvalues in ascending order
+    pub eigs: Vec<T::Real>,
+    /// Eigenvectors as a column-major matrix
+    pub z: Option<Vec<T>>,
+}
+
+/// Reference result of [EighRangeWork::calc]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EighRangeRef<'work, T: Scalar> {
+    /// Eigenvalues in ascending order
+    pub eigs: &'work [T::Real],
+    /// Eigenvectors as a column-major matrix
+    pub z: Option<&'work [T]>,
+}
+
+impl<T> EighRangeWork<T>
+where
+    T: Scalar,
+    EighRangeWork<T>: EighRangeWorkImpl<Elem = T>,
+{
+    pub fn new(
+        calc_eigenvectors: bool,
+        layout: MatrixLayout,
+        range: EigRange<T::Real>,
+    ) -> Result<Self> {
+        EighRangeWorkImpl::new(calc_eigenvectors, layout, range)
+    }
+
+    pub fn calc(&mut self, uplo: UPLO, a: &mut [T]) -> Result<EighRangeRef<'_, T>> {
+        EighRangeWorkImpl::calc(self, uplo, a)
+    }
+
+    pub fn eval(self, uplo: UPLO, a: &mut [T]) -> Result<EighRangeOwned<T>> {
+        EighRangeWorkImpl::eval(self, uplo, a)
+    }
+}
+
+pub trait EighRangeWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(
+        calc_eigenvectors: bool,
+        layout: MatrixLayout,
+        range: EigRange<<Self::Elem as Scalar>::Real>,
+    ) -> Result<Self>;
+    fn calc<'work>(
+        &'work mut self,
+        uplo: UPLO,
+        a: &mut [Self::Elem],
+    ) -> Result<EighRangeRef<'work, Self::Elem>>;
+    fn eval(self, uplo: UPLO, a: &mut [Self::Elem]) -> Result<EighRangeOwned<Self::Elem>>;
+}
+
+/// Split [EigRange] into `(vl, vu, il, iu)` arguments of LAPACK with 1-based indices
+pub(crate) fn range_args<R: Zero + Copy>(range: EigRange<R>) -> (R, R, i32, i32) {
+    match range {
+        EigRange::All => (R::zero(), R::zero(), 0, 0),
+        EigRange::Value(vl, vu) => (vl, vu, 0, 0),
+        EigRange::Index(il, iu) => (
+            R::zero(),
+            R::zero(),
+            il.to_i32().unwrap() + 1,
+            iu.to_i32().unwrap() + 1,
+        ),
+    }
+}
+
+macro_rules! impl_eigh_range_work_c {
+    ($c:ty, $evr:path) => {
+        impl EighRangeWorkImpl for EighRangeWork<$c> {
+            type Elem = $c;
+
+            fn new(
+                calc_eigenvectors: bool,
+                layout: MatrixLayout,
+                range: EigRange<<$c as Scalar>::Real>,
+            ) -> Result<Self> {
+                assert_eq!(layout.len(), layout.lda());
+                let n = layout.len();
+                let jobz = if calc_eigenvectors {
+                    JobEv::All
+                } else {
+                    JobEv::None
+                };
+                let m_max = range.max_len(n as usize);
+                let (vl, vu, il, iu) = range_args(range);
+                let mut eigs = vec_uninit(n as usize);
+                let mut z = jobz.then(|| vec_uninit(n as usize * m_max));
+                let mut isuppz = vec_uninit(2 * std::cmp::max(1, m_max));
+                let mut m = 0;
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                let mut rwork_size = [<$c as Scalar>::Real::zero()];
+                let mut iwork_size = [0];
+                unsafe {
+                    $evr(
+                        jobz.as_ptr(),
+                        range.as_ptr(),
+                        UPLO::Upper.as_ptr(), // dummy, working memory is not affected by UPLO
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &Zero::zero(), // abstol: use default tolerance
+                        &mut m,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(z.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut isuppz),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut iwork_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let work = vec_uninit(work_size[0].to_usize().unwrap());
+                let rwork = vec_uninit(rwork_size[0].to_usize().unwrap());
+                let iwork = vec_uninit(iwork_size[0].to_usize().unwrap());
+                Ok(EighRangeWork {
+                    n,
+                    layout,
+                    jobz,
+                    range,
+                    eigs,
+                    z,
+                    isuppz,
+                    work,
+                    rwork: Some(rwork),
+                    iwork,
+                })
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                uplo: UPLO,
+                a: &mut [Self::Elem],
+            ) -> Result<EighRangeRef<'work, Self::Elem>> {
+                // Row-major matrix is regarded as the transposed, i.e. complex conjugated, matrix
+                let uplo = match self.layout {
+                    MatrixLayout::C { .. } => uplo.t(),
+                    MatrixLayout::F { .. } => uplo,
+                };
+                let (vl, vu, il, iu) = range_args(self.range);
+                let lwork = self.work.len().to_i32().unwrap();
+                let lrwork = self.rwork.as_ref().unwrap().len().to_i32().unwrap();
+                let liwork = self.iwork.len().to_i32().unwrap();
+                let mut m = 0;
+                let mut info = 0;
+                unsafe {
+                    $evr(
+                        self.jobz.as_ptr(),
+                        self.range.as_ptr(),
+                        uplo.as_ptr(),
+                        &self.n,
+                        AsPtr::as_mut_ptr(a),
+                        &self.n,
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &Zero::zero(), // abstol: use default tolerance
+                        &mut m,
+                        AsPtr::as_mut_ptr(&mut self.eigs),
+                        AsPtr::as_mut_ptr(self.z.as_deref_mut().unwrap_or(&mut [])),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.isuppz),
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(self.rwork.as_mut().unwrap()),
+                        &lrwork,
+                        AsPtr::as_mut_ptr(&mut self.iwork),
+                        &liwork,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                let m = m as usize;
+                let n = self.n as usize;
+                if let MatrixLayout::C { .. } = self.layout {
+                    // eigenvectors of the original matrix are complex conjugate
+                    if let Some(z) = self.z.as_mut() {
+                        for value in &mut z[..n * m] {
+                            let value = unsafe { value.assume_init_mut() };
+                            value.im = -value.im;
+                        }
+                    }
+                }
+                Ok(EighRangeRef {
+                    eigs: unsafe { &self.eigs.slice_assume_init_ref()[..m] },
+                    z: self
+                        .z
+                        .as_ref()
+                        .map(|z| unsafe { &z.slice_assume_init_ref()[..n * m] }),
+                })
+            }
+
+            fn eval(
+                mut self,
+                uplo: UPLO,
+                a: &mut [Self::Elem],
+            ) -> Result<EighRangeOwned<Self::Elem>> {
+                let m = self.calc(uplo, a)?.eigs.len();
+                let n = self.n as usize;
+                let mut eigs = unsafe { self.eigs.assume_init() };
+                eigs.truncate(m);
+                let z = self.z.map(|z| {
+                    let mut z = unsafe { z.assume_init() };
+                    z.truncate(n * m);
+                    z
+                });
+                Ok(EighRangeOwned { eigs, z })
+            }
+        }
+    };
+}
+impl_eigh_range_work_c!(c64, lapack_sys::zheevr_);
+impl_eigh_range_work_c!(c32, lapack_sys::cheevr_);
+
+macro_rules! impl_eigh_range_work_r {
+    ($f:ty, $evr:path) => {
+        impl EighRangeWorkImpl for EighRangeWork<$f> {
+            type Elem = $f;
+
+            fn new(
+                calc_eigenvectors: bool,
+                layout: MatrixLayout,
+                range: EigRange<$f>,
+            ) -> Result<Self> {
+                assert_eq!(layout.len(), layout.lda());
+                let n = layout.len();
+                let jobz = if calc_eigenvectors {
+                    JobEv::All
+                } else {
+                    JobEv::None
+                };
+                let m_max = range.max_len(n as usize);
+                let (vl, vu, il, iu) = range_args(range);
+                let mut eigs = vec_uninit(n as usize);
+                let mut z = jobz.then(|| vec_uninit(n as usize * m_max));
+                let mut isuppz = vec_uninit(2 * std::cmp::max(1, m_max));
+                let mut m = 0;
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                let mut iwork_size = [0];
+                unsafe {
+                    $evr(
+                        jobz.as_ptr(),
+                        range.as_ptr(),
+                        UPLO::Upper.as_ptr(), // dummy, working memory is not affected by UPLO
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &0.0, // abstol: use default tolerance
+                        &mut m,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(z.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut isuppz),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut iwork_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let work = vec_uninit(work_size[0].to_usize().unwrap());
+                let iwork = vec_uninit(iwork_size[0].to_usize().unwrap());
+                Ok(EighRangeWork {
+                    n,
+                    layout,
+                    jobz,
+                    range,
+                    eigs,
+                    z,
+                    isuppz,
+                    work,
+                    rwork: None,
+                    iwork,
+                })
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                uplo: UPLO,
+                a: &mut [Self::Elem],
+            ) -> Result<EighRangeRef<'work, Self::Elem>> {
+                // Row-major matrix is regarded as the transposed matrix
+                let uplo = match self.layout {
+                    MatrixLayout::C { .. } => uplo.t(),
+                    MatrixLayout::F { .. } => uplo,
+                };
+                let (vl, vu, il, iu) = range_args(self.range);
+                let lwork = self.work.len().to_i32().unwrap();
+                let liwork = self.iwork.len().to_i32().unwrap();
+                let mut m = 0;
+                let mut info = 0;
+                unsafe {
+                    $evr(
+                        self.jobz.as_ptr(),
+                        self.range.as_ptr(),
+                        uplo.as_ptr(),
+                        &self.n,
+                        AsPtr::as_mut_ptr(a),
+                        &self.n,
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &0.0, // abstol: use default tolerance
+                        &mut m,
+                        AsPtr::as_mut_ptr(&mut self.eigs),
+                        AsPtr::as_mut_ptr(self.z.as_deref_mut().unwrap_or(&mut [])),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.isuppz),
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(&mut self.iwork),
+                        &liwork,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                let m = m as usize;
+                let n = self.n as usize;
+                Ok(EighRangeRef {
+                    eigs: unsafe { &self.eigs.slice_assume_init_ref()[..m] },
+                    z: self
+                        .z
+                        .as_ref()
+                        .map(|z| unsafe { &z.slice_assume_init_ref()[..n * m] }),
+                })
+            }
+
+            fn eval(
+                mut self,
+                uplo: UPLO,
+                a: &mut [Self::Elem],
+            ) -> Result<EighRangeOwned<Self::Elem>> {
+                let m = self.calc(uplo, a)?.eigs.len();
+                let n = self.n as usize;
+                let mut eigs = unsafe { self.eigs.assume_init() };
+                eigs.truncate(m);
+                let z = self.z.map(|z| {
+                    let mut z = unsafe { z.assume_init() };
+                    z.truncate(n * m);
+                    z
+                });
+                Ok(EighRangeOwned { eigs, z })
+            }
+        }
+    };
+}
+impl_eigh_range_work_r!(f64, lapack_sys::dsyevr_);
+impl_eigh_range_work_r!(f32, lapack_sys::ssyevr_);
@@ -0,0 +1,431 @@
+//! Eigenvalue problem for symmetric/Hermitian matricies with divide-and-conquer algorithm
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | ssyevd | dsyevd | cheevd | zheevd |
+//! | ssygvd | dsygvd | chegvd | zhegvd |
+
+use super::*;
+use crate::{error::*, layout::MatrixLayout};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+pub struct EighDcWork<T: Scalar> {
+    pub n: i32,
+    pub jobz: JobEv,
+    pub eigs: Vec<MaybeUninit<T::Real>>,
+    pub work: Vec<MaybeUninit<T>>,
+    pub rwork: Option<Vec<MaybeUninit<T::Real>>>,
+    pub iwork: Vec<MaybeUninit<i32>>,
+}
+
+pub trait EighDcWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(calc_eigenvectors: bool, layout: MatrixLayout) -> Result<Self>;
+    fn calc(&mut self, uplo: UPLO, a: &mut [Self::Elem])
+        -> Result<&[<Self::Elem as Scalar>::Real]>;
+    fn eval(self, uplo: UPLO, a: &mut [Self::Elem]) -> Result<Vec<<Self::Elem as Scalar>::Real>>;
+}
+
+macro_rules! impl_eighdc_work_c {
+    ($c:ty, $evd:path) => {
+        impl EighDcWorkImpl for EighDcWork<$c> {
+            type Elem = $c;
+
+            fn new(calc_eigenvectors: bool, layout: MatrixLayout) -> Result<Self> {
+                assert_eq!(layout.len(), layout.lda());
+                let n = layout.len();
+                let jobz = if calc_eigenvectors {
+                    JobEv::All
+                } else {
+                    JobEv::None
+                };
+                let mut eigs = vec_uninit(n as usize);
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                let mut rwork_size = [<$c as Scalar>::Real::zero()];
+                let mut iwork_size = [0];
+                unsafe {
+                    $evd(
+                        jobz.as_ptr(),
+                        UPLO::Upper.as_ptr(), // dummy, working memory is not affected by UPLO
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut iwork_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let work = vec_uninit(work_size[0].to_usize().unwrap());
+                let rwork = vec_uninit(rwork_size[0].to_usize().unwrap());
+                let iwork = vec_uninit(iwork_size[0].to_usize().unwrap());
+                Ok(EighDcWork {
+                    n,
+                    eigs,
+                    jobz,
+                    work,
+                    rwork: Some(rwork),
+                    iwork,
+                })
+            }
+
+            fn calc(
+                &mut self,
+                uplo: UPLO,
+                a: &mut [Self::Elem],
+            ) -> Result<&[<Self::Elem as Scalar>::Real]> {
+                let lwork = self.work.len().to_i32().unwrap();
+                let lrwork = self.rwork.as_ref().unwrap().len().to_i32().unwrap();
+                let liwork = self.iwork.len().to_i32().unwrap();
+                let mut info = 0;
+                unsafe {
+                    $evd(
+                        self.jobz.as_ptr(),
+                        uplo.as_ptr(),
+                        &self.n,
+                        AsPtr::as_mut_ptr(a),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.eigs),
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(self.rwork.as_mut().unwrap()),
+                        &lrwork,
+                        AsPtr::as_mut_ptr(&mut self.iwork),
+                        &liwork,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(unsafe { self.eigs.slice_assume_init_ref() })
+            }
+
+            fn eval(
+                mut self,
+                uplo: UPLO,
+                a: &mut [Self::Elem],
+            ) -> Result<Vec<<Self::Elem as Scalar>::Real>> {
+                let _eig = self.calc(uplo, a)?;
+                Ok(unsafe { self.eigs.assume_init() })
+            }
+        }
+    };
+}
+impl_eighdc_work_c!(c64, lapack_sys::zheevd_);
+impl_eighdc_work_c!(c32, lapack_sys::cheevd_);
+
+macro_rules! impl_eighdc_work_r {
+    ($f:ty, $evd:path) => {
+        impl EighDcWorkImpl for EighDcWork<$f> {
+            type Elem = $f;
+
+            fn new(calc_eigenvectors: bool, layout: MatrixLayout) -> Result<Self> {
+                assert_eq!(layout.len(), layout.lda());
+                let n = layout.len();
+                let jobz = if calc_eigenvectors {
+                    JobEv::All
+                } else {
+                    JobEv::None
+                };
+                let mut eigs = vec_uninit(n as usize);
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                let mut iwork_size = [0];
+                unsafe {
+                    $evd(
+                        jobz.as_ptr(),
+                        UPLO::Upper.as_ptr(), // dummy, working memory is not affected by UPLO
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut iwork_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let work = vec_uninit(work_size[0].to_usize().unwrap());
+                let iwork = vec_uninit(iwork_size[0].to_usize().unwrap());
+                Ok(EighDcWork {
+                    n,
+                    eigs,
+                    jobz,
+                    work,
+                    rwork: None,
+                    iwork,
+                })
+            }
+
+            fn calc(
+                &mut self,
+                uplo: UPLO,
+                a: &mut [Self::Elem],
+            ) -> Result<&[<Self::Elem as Scalar>::Real]> {
+                let lwork = self.work.len().to_i32().unwrap();
+                let liwork = self.iwork.len().to_i32().unwrap();
+                let mut info = 0;
+                unsafe {
+                    $evd(
+                        self.jobz.as_ptr(),
+                        uplo.as_ptr(),
+                        &self.n,
+                        AsPtr::as_mut_ptr(a),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.eigs),
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(&mut self.iwork),
+                        &liwork,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(unsafe { self.eigs.slice_assume_init_ref() })
+            }
+
+            fn eval(
+                mut self,
+                uplo: UPLO,
+                a: &mut [Self::Elem],
+            ) -> Result<Vec<<Self::Elem as Scalar>::Real>> {
+                let _eig = self.calc(uplo, a)?;
+                Ok(unsafe { self.eigs.assume_init() })
+            }
+        }
+    };
+}
+impl_eighdc_work_r!(f64, lapack_sys::dsyevd_);
+impl_eighdc_work_r!(f32, lapack_sys::ssyevd_);
+
+pub struct EighGeneralizedDcWork<T: Scalar> {
+    pub n: i32,
+    pub jobz: JobEv,
+    pub eigs: Vec<MaybeUninit<T::Real>>,
+    pub work: Vec<MaybeUninit<T>>,
+    pub rwork: Option<Vec<MaybeUninit<T::Real>>>,
+    pub iwork: Vec<MaybeUninit<i32>>,
+}
+
+pub trait EighGeneralizedDcWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(calc_eigenvectors: bool, layout: MatrixLayout) -> Result<Self>;
+    fn calc(
+        &mut self,
+        uplo: UPLO,
+        a: &mut [Self::Elem],
+        b: &mut [Self::Elem],
+    ) -> Result<&[<Self::Elem as Scalar>::Real]>;
+    fn eval(
+        self,
+        uplo: UPLO,
+        a: &mut [Self::Elem],
+        b: &mut [Self::Elem],
+    ) -> Result<Vec<<Self::Elem as Scalar>::Real>>;
+}
+
+macro_rules! impl_eigh_generalized_dc_work_c {
+    ($c:ty, $gvd:path) => {
+        impl EighGeneralizedDcWorkImpl for EighGeneralizedDcWork<$c> {
+            type Elem = $c;
+
+            fn new(calc_eigenvectors: bool, layout: MatrixLayout) -> Result<Self> {
+                assert_eq!(layout.len(), layout.lda());
+                let n = layout.len();
+                let jobz = if calc_eigenvectors {
+                    JobEv::All
+                } else {
+                    JobEv::None
+                };
+                let mut eigs = vec_uninit(n as usize);
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                let mut rwork_size = [<$c as Scalar>::Real::zero()];
+                let mut iwork_size = [0];
+                unsafe {
+                    $gvd(
+                        &1, // ITYPE A*x = (lambda)*B*x
+                        jobz.as_ptr(),
+                        UPLO::Upper.as_ptr(), // dummy, working memory is not affected by UPLO
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut iwork_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let work = vec_uninit(work_size[0].to_usize().unwrap());
+                let rwork = vec_uninit(rwork_size[0].to_usize().unwrap());
+                let iwork = vec_uninit(iwork_size[0].to_usize().unwrap());
+                Ok(EighGeneralizedDcWork {
+                    n,
+                    eigs,
+                    jobz,
+                    work,
+                    rwork: Some(rwork),
+                    iwork,
+                })
+            }
+
+            fn calc(
+                &mut self,
+                uplo: UPLO,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+            ) -> Result<&[<Self::Elem as Scalar>::Real]> {
+                let lwork = self.work.len().to_i32().unwrap();
+                let lrwork = self.rwork.as_ref().unwrap().len().to_i32().unwrap();
+                let liwork = self.iwork.len().to_i32().unwrap();
+                let mut info = 0;
+                unsafe {
+                    $gvd(
+                        &1, // ITYPE A*x = (lambda)*B*x
+                        self.jobz.as_ptr(),
+                        uplo.as_ptr(),
+                        &self.n,
+                        AsPtr::as_mut_ptr(a),
+                        &self.n,
+                        AsPtr::as_mut_ptr(b),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.eigs),
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(self.rwork.as_mut().unwrap()),
+                        &lrwork,
+                        AsPtr::as_mut_ptr(&mut self.iwork),
+                        &liwork,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(unsafe { self.eigs.slice_assume_init_ref() })
+            }
+
+            fn eval(
+                mut self,
+                uplo: UPLO,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+            ) -> Result<Vec<<Self::Elem as Scalar>::Real>> {
+                let _eig = self.calc(uplo, a, b)?;
+                Ok(unsafe { self.eigs.assume_init() })
+            }
+        }
+    };
+}
+impl_eigh_generalized_dc_work_c!(c64, lapack_sys::zhegvd_);
+impl_eigh_generalized_dc_work_c!(c32, lapack_sys::chegvd_);
+
+macro_rules! impl_eigh_generalized_dc_work_r {
+    ($f:ty, $gvd:path) => {
+        impl EighGeneralizedDcWorkImpl for EighGeneralizedDcWork<$f> {
+            type Elem = $f;
+
+            fn new(calc_eigenvectors: bool, layout: MatrixLayout) -> Result<Self> {
+                assert_eq!(layout.len(), layout.lda());
+                let n = layout.len();
+                let jobz = if calc_eigenvectors {
+                    JobEv::All
+                } else {
+                    JobEv::None
+                };
+                let mut eigs = vec_uninit(n as usize);
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                let mut iwork_size = [0];
+                unsafe {
+                    $gvd(
+                        &1, // ITYPE A*x = (lambda)*B*x
+                        jobz.as_ptr(),
+                        UPLO::Upper.as_ptr(), // dummy, working memory is not affected by UPLO
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut iwork_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let work = vec_uninit(work_size[0].to_usize().unwrap());
+                let iwork = vec_uninit(iwork_size[0].to_usize().unwrap());
+                Ok(EighGeneralizedDcWork {
+                    n,
+                    eigs,
+                    jobz,
+                    work,
+                    rwork: None,
+                    iwork,
+                })
+            }
+
+            fn calc(
+                &mut self,
+                uplo: UPLO,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+            ) -> Result<&[<Self::Elem as Scalar>::Real]> {
+                let lwork = self.work.len().to_i32().unwrap();
+                let liwork = self.iwork.len().to_i32().unwrap();
+                let mut info = 0;
+                unsafe {
+                    $gvd(
+                        &1, // ITYPE A*x = (lambda)*B*x
+                        self.jobz.as_ptr(),
+                        uplo.as_ptr(),
+                        &self.n,
+                        AsPtr::as_mut_ptr(a),
+                        &self.n,
+                        AsPtr::as_mut_ptr(b),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.eigs),
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(&mut self.iwork),
+                        &liwork,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(unsafe { self.eigs.slice_assume_init_ref() })
+            }
+
+            fn eval(
+                mut self,
+                uplo: UPLO,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+            ) -> Result<Vec<<Self::Elem as Scalar>::Real>> {
+                let _eig = self.calc(uplo, a, b)?;
+                Ok(unsafe { self.eigs.assume_init() })
+            }
+        }
+    };
+}
+impl_eigh_generalized_dc_work_r!(f64, lapack_sys::dsygvd_);
+impl_eigh_generalized_dc_work_r!(f32, lapack_sys::ssygvd_);
@@ -137,3 +137,184 @@ impl Diag {
         self as *const Diag as *const i8
     }
 }
+
+/// Specify which side a matrix is applied from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum Side {
+    /// Applied from the left, e.g. `A * B`
+    Left = b'L',
+    /// Applied from the right, e.g. `B * A`
+    Right = b'R',
+}
+
+impl Side {
+    pub fn as_ptr(&self) -> *const i8 {
+        self as *const Side as *const i8
+    }
+}
+
+/// Balancing applied to a general matrix before computing its eigenvalues, e.g. by `?geevx`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum Balance {
+    /// No balancing
+    None = b'N',
+    /// Permute the matrix to isolate eigenvalues, e.g. of a triangular part, if possible
+    Permute = b'P',
+    /// Scale the rows and columns by a diagonal similarity transform to make their norms closer
+    Scale = b'S',
+    /// Both permute and scale the matrix
+    Both = b'B',
+}
+
+impl Balance {
+    pub fn as_ptr(&self) -> *const i8 {
+        self as *const Balance as *const i8
+    }
+}
+
+/// Algorithm used by symmetric/Hermitian eigensolvers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EighDriver {
+    /// QR iteration, i.e. `?syev`/`?heev` (`?sygv`/`?hegv` for generalized problem)
+    QR,
+    /// Divide-and-conquer algorithm, i.e. `?syevd`/`?heevd` (`?sygvd`/`?hegvd`).
+    /// This is much faster than QR iteration for large matrices when eigenvectors are requested,
+    /// but requires larger workspace.
+    DivideAndConquer,
+    /// Multiple Relatively Robust Representations (MRRR), i.e. `?syevr`/`?heevr`
+    RRR,
+}
+
+/// Algorithm used by least squares solvers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LeastSquaresDriver {
+    /// QR or LQ factorization, i.e. `?gels`. This is the fastest, but `A` must have full rank.
+    QR,
+    /// Complete orthogonal factorization using QR factorization with column pivoting, i.e. `?gelsy`
+    CompleteOrthogonal,
+    /// SVD with divide-and-conquer algorithm, i.e. `?gelsd`
+    #[default]
+    DivideAndConquer,
+    /// SVD using QR iteration, i.e. `?gelss`
+    SVD,
+}
+
+/// Specifies which eigenvalues are computed by subset eigensolvers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EigRange<R> {
+    /// All eigenvalues
+    All,
+    /// All eigenvalues in the half-open interval `(vl, vu]`
+    Value(R, R),
+    /// The `il`-th through `iu`-th eigenvalues in ascending order.
+    /// Indices are 0-based and both ends are inclusive, i.e. `[il, iu]`.
+    Index(usize, usize),
+}
+
+impl<R> EigRange<R> {
+    /// Upper bound of the number of eigenvalues for a matrix of size `n`
+    pub fn max_len(&self, n: usize) -> usize {
+        match *self {
+            EigRange::All | EigRange::Value(..) => n,
+            EigRange::Index(il, iu) => (iu + 1).saturating_sub(il).min(n),
+        }
+    }
+
+    /// To use Fortran LAPACK API in lapack-sys crate
+    pub fn as_ptr(&self) -> *const i8 {
+        let range: &'static u8 = match self {
+            EigRange::All => &b'A',
+            EigRange::Value(..) => &b'V',
+            EigRange::Index(..) => &b'I',
+        };
+        range as *const u8 as *const i8
+    }
+}
+
+/// Equilibration applied by expert drivers, e.g. `?gesvx`, before factorizing the matrix
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Equilibration {
+    /// No equilibration
+    None,
+    /// Row equilibration, i.e. `A` is replaced by `diag(R) * A`
+    Row,
+    /// Column equilibration, i.e. `A` is replaced by `A * diag(C)`
+    Column,
+    /// Both row and column equilibration, i.e. `A` is replaced by `diag(R) * A * diag(C)`.
+    /// The symmetric equilibration `diag(S) * A * diag(S)` by `?posvx` is also reported as this.
+    Both,
+}
+
+impl Equilibration {
+    /// Convert from `equed` flag returned by LAPACK
+    pub fn from_equed(equed: u8) -> Self {
+        match equed {
+            b'R' => Equilibration::Row,
+            b'C' => Equilibration::Column,
+            b'B' | b'Y' => Equilibration::Both,
+            _ => Equilibration::None,
+        }
+    }
+
+    /// Equilibration of the transposed matrix
+    pub fn t(self) -> Self {
+        match self {
+            Equilibration::Row => Equilibration::Column,
+            Equilibration::Column => Equilibration::Row,
+            _ => self,
+        }
+    }
+}
+
+/// Result of mixed-precision iterative refinement by `?sgesv`-style drivers, e.g. `dsgesv`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Refinement {
+    /// Refinement of the single precision solution converged
+    /// after the given number of iterations
+    Converged(usize),
+    /// Refinement is skipped or does not converge,
+    /// and the system is solved by the full precision factorization instead.
+    /// The negative `iter` code returned by LAPACK is kept:
+    ///
+    /// - `-1`: the matrix is too small to benefit from mixed precision
+    /// - `-2`: overflow occurs when converting the matrix into single precision
+    /// - `-3`: single precision factorization fails
+    /// - `-31`: the maximum number of iterations is exceeded
+    FullPrecision(i32),
+}
+
+impl Refinement {
+    /// Convert from `iter` returned by LAPACK
+    pub fn from_iter(iter: i32) -> Self {
+        if iter >= 0 {
+            Refinement::Converged(iter as usize)
+        } else {
+            Refinement::FullPrecision(iter)
+        }
+    }
+
+    /// Number of refinement iterations, `None` if fallen back to full precision
+    pub fn iterations(&self) -> Option<usize> {
+        match self {
+            Refinement::Converged(iter) => Some(*iter),
+            Refinement::FullPrecision(_) => None,
+        }
+    }
+}
+
+/// Pivoting strategy for the factorization of symmetric/Hermitian indefinite matrix
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PivotStrategy {
+    /// Bunch-Kaufman diagonal pivoting, i.e. `?sytrf`/`?hetrf`
+    #[default]
+    BunchKaufman,
+    /// Bounded Bunch-Kaufman (rook) diagonal pivoting, i.e. `?sytrf_rook`/`?hetrf_rook`.
+    /// This bounds the elements of the triangular factor,
+    /// and is more stable than Bunch-Kaufman pivoting at slightly higher cost.
+    Rook,
+    /// Aasen's algorithm, i.e. `?sytrf_aa`/`?hetrf_aa`.
+    /// The block diagonal factor is replaced by a tridiagonal matrix.
+    Aasen,
+}
@@ -0,0 +1,466 @@
+//! Generalized singular value decomposition (GSVD)
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32     | f64     | c32     | c64     |
+//! |:--------|:--------|:--------|:--------|
+//! | sggsvd3 | dggsvd3 | cggsvd3 | zggsvd3 |
+//!
+
+use crate::{error::*, layout::MatrixLayout, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Generalized singular value decomposition of a pair of matrices
+///
+/// For $A$ of shape $(m, n)$ and $B$ of shape $(p, n)$, this computes
+/// $$
+/// A = U D_1 \begin{pmatrix} 0 & R \end{pmatrix} Q^\dagger, \quad
+/// B = V D_2 \begin{pmatrix} 0 & R \end{pmatrix} Q^\dagger
+/// $$
+/// where $U$, $V$ and $Q$ are unitary (orthogonal for real matrices),
+/// and $R$ is a non-singular upper triangular matrix of shape $(k + l, k + l)$.
+/// $D_1$ of shape $(m, k + l)$ and $D_2$ of shape $(p, k + l)$ are
+/// $$
+/// (D_1)_{ii} = \alpha_i \quad (0 \le i < \min(m, k + l)), \quad
+/// (D_2)_{i, k + i} = \beta_{k + i} \quad (0 \le i < l)
+/// $$
+/// and zero elsewhere, with $\alpha_i^2 + \beta_i^2 = 1$ for $i < k + l$.
+///
+/// A row-major matrix is transposed into column-major one before calling LAPACK,
+/// and $U$, $V$, $Q$ and $R$ are always returned in column-major.
+///
+pub struct GsvdWork<T: Scalar> {
+    pub m: i32,
+    pub n: i32,
+    pub p: i32,
+    pub a_layout: MatrixLayout,
+    pub b_layout: MatrixLayout,
+    pub alpha: Vec<MaybeUninit<T::Real>>,
+    pub beta: Vec<MaybeUninit<T::Real>>,
+    pub u: Option<Vec<MaybeUninit<T>>>,
+    pub v: Option<Vec<MaybeUninit<T>>>,
+    pub q: Option<Vec<MaybeUninit<T>>>,
+    pub work: Vec<MaybeUninit<T>>,
+    pub iwork: Vec<MaybeUninit<i32>>,
+    pub rwork: Option<Vec<MaybeUninit<T::Real>>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GsvdRef<'work, T: Scalar> {
+    /// Number of the infinite generalized singular values, i.e. $\beta_i = 0$
+    pub k: usize,
+    /// Rank of $B$, the number of the generalized singular values after the first $k$
+    pub l: usize,
+    pub alpha: &'work [T::Real],
+    pub beta: &'work [T::Real],
+    pub u: Option<&'work [T]>,
+    pub v: Option<&'work [T]>,
+    pub q: Option<&'work [T]>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GsvdOwned<T: Scalar> {
+    /// Number of the infinite generalized singular values, i.e. $\beta_i = 0$
+    pub k: usize,
+    /// Rank of $B$, the number of the generalized singular values after the first $k$
+    pub l: usize,
+    pub alpha: Vec<T::Real>,
+    pub beta: Vec<T::Real>,
+    pub u: Option<Vec<T>>,
+    pub v: Option<Vec<T>>,
+    pub q: Option<Vec<T>>,
+    /// Upper triangular matrix $R$ of shape $(k + l, k + l)$
+    pub r: Vec<T>,
+}
+
+pub trait GsvdWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(
+        a_layout: MatrixLayout,
+        b_layout: MatrixLayout,
+        calc_u: bool,
+        calc_v: bool,
+        calc_q: bool,
+    ) -> Result<Self>;
+    fn calc<'work>(
+        &'work mut self,
+        a: &mut [Self::Elem],
+        b: &mut [Self::Elem],
+    ) -> Result<GsvdRef<'work, Self::Elem>>;
+    fn eval(self, a: &mut [Self::Elem], b: &mut [Self::Elem]) -> Result<GsvdOwned<Self::Elem>>;
+}
+
+/// `jobu`, `jobv`, and `jobq` of `?ggsvd3`, which take `'U'`, `'V'`, and `'Q'` respectively instead of `'N'`
+fn job(calc: bool, flag: &'static u8) -> *const i8 {
+    let flag = if calc { flag } else { &b'N' };
+    flag as *const u8 as *const i8
+}
+
+impl<T: Scalar> GsvdWork<T> {
+    fn new_uninit(
+        a_layout: MatrixLayout,
+        b_layout: MatrixLayout,
+        calc_u: bool,
+        calc_v: bool,
+        calc_q: bool,
+    ) -> Result<Self> {
+        let (m, n) = a_layout.size();
+        let (p, n_b) = b_layout.size();
+        if n != n_b {
+            return Err(Error::InvalidShape);
+        }
+        Ok(GsvdWork {
+            m,
+            n,
+            p,
+            a_layout,
+            b_layout,
+            alpha: vec_uninit(n as usize),
+            beta: vec_uninit(n as usize),
+            u: calc_u.then(|| vec_uninit((m * m) as usize)),
+            v: calc_v.then(|| vec_uninit((p * p) as usize)),
+            q: calc_q.then(|| vec_uninit((n * n) as usize)),
+            work: Vec::new(),
+            iwork: vec_uninit(n as usize),
+            rwork: None,
+        })
+    }
+
+    /// Extract $R$ from `a` and `b` overwritten by `?ggsvd3`
+    ///
+    /// $R$ is stored in the last $k + l$ columns of $A$,
+    /// and its last $k + l - m$ rows are stored in $B$ if $m < k + l$.
+    fn r(&self, k: usize, l: usize, a: &[T], b: &[T]) -> Vec<T> {
+        let m = self.m as usize;
+        let n = self.n as usize;
+        let kl = k + l;
+        let at = |layout: MatrixLayout, x: &[T], i: usize, j: usize| match layout {
+            MatrixLayout::C { lda, .. } => x[i * lda as usize + j],
+            MatrixLayout::F { lda, .. } => x[i + j * lda as usize],
+        };
+        let mut r = vec![T::zero(); kl * kl];
+        for j in 0..kl {
+            for i in 0..=j {
+                r[i + j * kl] = if i < m {
+                    at(self.a_layout, a, i, n - kl + j)
+                } else {
+                    at(self.b_layout, b, i - k, n - kl + j)
+                };
+            }
+        }
+        r
+    }
+}
+
+macro_rules! impl_gsvd_work_c {
+    ($c:ty, $ggsvd3:path) => {
+        impl GsvdWorkImpl for GsvdWork<$c> {
+            type Elem = $c;
+
+            fn new(
+                a_layout: MatrixLayout,
+                b_layout: MatrixLayout,
+                calc_u: bool,
+                calc_v: bool,
+                calc_q: bool,
+            ) -> Result<Self> {
+                let mut work = Self::new_uninit(a_layout, b_layout, calc_u, calc_v, calc_q)?;
+                let (m, n, p) = (work.m, work.n, work.p);
+                let mut rwork = vec_uninit(std::cmp::max(1, 2 * n) as usize);
+
+                // eval work size
+                let mut info = 0;
+                let mut k = 0;
+                let mut l = 0;
+                let mut work_size = [<$c>::zero()];
+                unsafe {
+                    $ggsvd3(
+                        job(calc_u, &b'U'),
+                        job(calc_v, &b'V'),
+                        job(calc_q, &b'Q'),
+                        &m,
+                        &n,
+                        &p,
+                        &mut k,
+                        &mut l,
+                        std::ptr::null_mut(),
+                        &std::cmp::max(1, m),
+                        std::ptr::null_mut(),
+                        &std::cmp::max(1, p),
+                        AsPtr::as_mut_ptr(&mut work.alpha),
+                        AsPtr::as_mut_ptr(&mut work.beta),
+                        AsPtr::as_mut_ptr(work.u.as_deref_mut().unwrap_or(&mut [])),
+                        &std::cmp::max(1, m),
+                        AsPtr::as_mut_ptr(work.v.as_deref_mut().unwrap_or(&mut [])),
+                        &std::cmp::max(1, p),
+                        AsPtr::as_mut_ptr(work.q.as_deref_mut().unwrap_or(&mut [])),
+                        &std::cmp::max(1, n),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        AsPtr::as_mut_ptr(&mut work.iwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let lwork = work_size[0].to_usize().unwrap();
+                work.work = vec_uninit(lwork);
+                work.rwork = Some(rwork);
+                Ok(work)
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+            ) -> Result<GsvdRef<'work, Self::Elem>> {
+                let (m, n, p) = (self.m, self.n, self.p);
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut a_t = match self.a_layout {
+                    MatrixLayout::C { .. } => Some(transpose(self.a_layout, a).1),
+                    MatrixLayout::F { .. } => None,
+                };
+                let mut b_t = match self.b_layout {
+                    MatrixLayout::C { .. } => Some(transpose(self.b_layout, b).1),
+                    MatrixLayout::F { .. } => None,
+                };
+
+                let mut info = 0;
+                let mut k = 0;
+                let mut l = 0;
+                unsafe {
+                    $ggsvd3(
+                        job(self.u.is_some(), &b'U'),
+                        job(self.v.is_some(), &b'V'),
+                        job(self.q.is_some(), &b'Q'),
+                        &m,
+                        &n,
+                        &p,
+                        &mut k,
+                        &mut l,
+                        AsPtr::as_mut_ptr(a_t.as_deref_mut().unwrap_or(a)),
+                        &std::cmp::max(1, m),
+                        AsPtr::as_mut_ptr(b_t.as_deref_mut().unwrap_or(b)),
+                        &std::cmp::max(1, p),
+                        AsPtr::as_mut_ptr(&mut self.alpha),
+                        AsPtr::as_mut_ptr(&mut self.beta),
+                        AsPtr::as_mut_ptr(self.u.as_deref_mut().unwrap_or(&mut [])),
+                        &std::cmp::max(1, m),
+                        AsPtr::as_mut_ptr(self.v.as_deref_mut().unwrap_or(&mut [])),
+                        &std::cmp::max(1, p),
+                        AsPtr::as_mut_ptr(self.q.as_deref_mut().unwrap_or(&mut [])),
+                        &std::cmp::max(1, n),
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(self.rwork.as_mut().unwrap()),
+                        AsPtr::as_mut_ptr(&mut self.iwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                if let Some(a_t) = a_t {
+                    transpose_over(MatrixLayout::F { col: n, lda: m }, &a_t, a);
+                }
+                if let Some(b_t) = b_t {
+                    transpose_over(MatrixLayout::F { col: n, lda: p }, &b_t, b);
+                }
+
+                Ok(GsvdRef {
+                    k: k as usize,
+                    l: l as usize,
+                    alpha: unsafe { self.alpha.slice_assume_init_ref() },
+                    beta: unsafe { self.beta.slice_assume_init_ref() },
+                    u: self
+                        .u
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() }),
+                    v: self
+                        .v
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() }),
+                    q: self
+                        .q
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() }),
+                })
+            }
+
+            fn eval(
+                mut self,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+            ) -> Result<GsvdOwned<Self::Elem>> {
+                let GsvdRef { k, l, .. } = self.calc(a, b)?;
+                let r = self.r(k, l, a, b);
+                Ok(GsvdOwned {
+                    k,
+                    l,
+                    alpha: unsafe { self.alpha.assume_init() },
+                    beta: unsafe { self.beta.assume_init() },
+                    u: self.u.map(|v| unsafe { v.assume_init() }),
+                    v: self.v.map(|v| unsafe { v.assume_init() }),
+                    q: self.q.map(|v| unsafe { v.assume_init() }),
+                    r,
+                })
+            }
+        }
+    };
+}
+impl_gsvd_work_c!(c64, lapack_sys::zggsvd3_);
+impl_gsvd_work_c!(c32, lapack_sys::cggsvd3_);
+
+macro_rules! impl_gsvd_work_r {
+    ($f:ty, $ggsvd3:path) => {
+        impl GsvdWorkImpl for GsvdWork<$f> {
+            type Elem = $f;
+
+            fn new(
+                a_layout: MatrixLayout,
+                b_layout: MatrixLayout,
+                calc_u: bool,
+                calc_v: bool,
+                calc_q: bool,
+            ) -> Result<Self> {
+                let mut work = Self::new_uninit(a_layout, b_layout, calc_u, calc_v, calc_q)?;
+                let (m, n, p) = (work.m, work.n, work.p);
+
+                // eval work size
+                let mut info = 0;
+                let mut k = 0;
+                let mut l = 0;
+                let mut work_size = [<$f>::zero()];
+                unsafe {
+                    $ggsvd3(
+                        job(calc_u, &b'U'),
+                        job(calc_v, &b'V'),
+                        job(calc_q, &b'Q'),
+                        &m,
+                        &n,
+                        &p,
+                        &mut k,
+                        &mut l,
+                        std::ptr::null_mut(),
+                        &std::cmp::max(1, m),
+                        std::ptr::null_mut(),
+                        &std::cmp::max(1, p),
+                        AsPtr::as_mut_ptr(&mut work.alpha),
+                        AsPtr::as_mut_ptr(&mut work.beta),
+                        AsPtr::as_mut_ptr(work.u.as_deref_mut().unwrap_or(&mut [])),
+                        &std::cmp::max(1, m),
+                        AsPtr::as_mut_ptr(work.v.as_deref_mut().unwrap_or(&mut [])),
+                        &std::cmp::max(1, p),
+                        AsPtr::as_mut_ptr(work.q.as_deref_mut().unwrap_or(&mut [])),
+                        &std::cmp::max(1, n),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut work.iwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let lwork = work_size[0].to_usize().unwrap();
+                work.work = vec_uninit(lwork);
+                Ok(work)
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+            ) -> Result<GsvdRef<'work, Self::Elem>> {
+                let (m, n, p) = (self.m, self.n, self.p);
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut a_t = match self.a_layout {
+                    MatrixLayout::C { .. } => Some(transpose(self.a_layout, a).1),
+                    MatrixLayout::F { .. } => None,
+                };
+                let mut b_t = match self.b_layout {
+                    MatrixLayout::C { .. } => Some(transpose(self.b_layout, b).1),
+                    MatrixLayout::F { .. } => None,
+                };
+
+                let mut info = 0;
+                let mut k = 0;
+                let mut l = 0;
+                unsafe {
+                    $ggsvd3(
+                        job(self.u.is_some(), &b'U'),
+                        job(self.v.is_some(), &b'V'),
+                        job(self.q.is_some(), &b'Q'),
+                        &m,
+                        &n,
+                        &p,
+                        &mut k,
+                        &mut l,
+                        AsPtr::as_mut_ptr(a_t.as_deref_mut().unwrap_or(a)),
+                        &std::cmp::max(1, m),
+                        AsPtr::as_mut_ptr(b_t.as_deref_mut().unwrap_or(b)),
+                        &std::cmp::max(1, p),
+                        AsPtr::as_mut_ptr(&mut self.alpha),
+                        AsPtr::as_mut_ptr(&mut self.beta),
+                        AsPtr::as_mut_ptr(self.u.as_deref_mut().unwrap_or(&mut [])),
+                        &std::cmp::max(1, m),
+                        AsPtr::as_mut_ptr(self.v.as_deref_mut().unwrap_or(&mut [])),
+                        &std::cmp::max(1, p),
+                        AsPtr::as_mut_ptr(self.q.as_deref_mut().unwrap_or(&mut [])),
+                        &std::cmp::max(1, n),
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(&mut self.iwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                if let Some(a_t) = a_t {
+                    transpose_over(MatrixLayout::F { col: n, lda: m }, &a_t, a);
+                }
+                if let Some(b_t) = b_t {
+                    transpose_over(MatrixLayout::F { col: n, lda: p }, &b_t, b);
+                }
+
+                Ok(GsvdRef {
+                    k: k as usize,
+                    l: l as usize,
+                    alpha: unsafe { self.alpha.slice_assume_init_ref() },
+                    beta: unsafe { self.beta.slice_assume_init_ref() },
+                    u: self
+                        .u
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() }),
+                    v: self
+                        .v
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() }),
+                    q: self
+                        .q
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() }),
+                })
+            }
+
+            fn eval(
+                mut self,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+            ) -> Result<GsvdOwned<Self::Elem>> {
+                let GsvdRef { k, l, .. } = self.calc(a, b)?;
+                let r = self.r(k, l, a, b);
+                Ok(GsvdOwned {
+                    k,
+                    l,
+                    alpha: unsafe { self.alpha.assume_init() },
+                    beta: unsafe { self.beta.assume_init() },
+                    u: self.u.map(|v| unsafe { v.assume_init() }),
+                    v: self.v.map(|v| unsafe { v.assume_init() }),
+                    q: self.q.map(|v| unsafe { v.assume_init() }),
+                    r,
+                })
+            }
+        }
+    };
+}
+impl_gsvd_work_r!(f64, lapack_sys::dggsvd3_);
+impl_gsvd_work_r!(f32, lapack_sys::sggsvd3_);
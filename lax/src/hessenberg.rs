@@ -0,0 +1,191 @@
+//! Hessenberg reduction of general matrix
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | sgehrd | dgehrd | cgehrd | zgehrd |
+//! | sorghr | dorghr | cunghr | zunghr |
+//!
+//! Since the Hessenberg form of $A^T$ is not related to that of $A$ simply,
+//! a row-major matrix is transposed into column-major storage before calling LAPACK,
+//! and the result is transposed back.
+
+use crate::{error::*, layout::*, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Reduce a general matrix $A$ into upper Hessenberg form $H = Q^H A Q$
+///
+/// After [HessenbergWork::calc], $H$ is stored in the upper triangle and the first sub-diagonal
+/// of the input matrix, and the elementary reflectors representing $Q$ are stored below
+/// the first sub-diagonal with the scalar factors returned as `tau`.
+pub struct HessenbergWork<T: Scalar> {
+    pub n: i32,
+    pub layout: MatrixLayout,
+    pub tau: Vec<MaybeUninit<T>>,
+    pub work: Vec<MaybeUninit<T>>,
+}
+
+pub trait HessenbergWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(l: MatrixLayout) -> Result<Self>;
+    fn calc(&mut self, a: &mut [Self::Elem]) -> Result<&[Self::Elem]>;
+    fn eval(self, a: &mut [Self::Elem]) -> Result<Vec<Self::Elem>>;
+}
+
+macro_rules! impl_hessenberg_work {
+    ($s:ty, $gehrd:path) => {
+        impl HessenbergWorkImpl for HessenbergWork<$s> {
+            type Elem = $s;
+
+            fn new(layout: MatrixLayout) -> Result<Self> {
+                let (n, _) = layout.size();
+                let tau = vec_uninit(std::cmp::max(1, n - 1) as usize);
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                unsafe {
+                    $gehrd(
+                        &n,
+                        &1,
+                        &n,
+                        std::ptr::null_mut(),
+                        &std::cmp::max(1, n),
+                        std::ptr::null_mut(),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let lwork = work_size[0].to_usize().unwrap();
+                let work = vec_uninit(lwork);
+                Ok(HessenbergWork {
+                    n,
+                    layout,
+                    tau,
+                    work,
+                })
+            }
+
+            fn calc(&mut self, a: &mut [Self::Elem]) -> Result<&[Self::Elem]> {
+                let n = self.n;
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut a_t = match self.layout {
+                    MatrixLayout::C { .. } => Some(transpose(self.layout, a).1),
+                    MatrixLayout::F { .. } => None,
+                };
+                let mut info = 0;
+                unsafe {
+                    $gehrd(
+                        &n,
+                        &1,
+                        &n,
+                        AsPtr::as_mut_ptr(a_t.as_deref_mut().unwrap_or(a)),
+                        &std::cmp::max(1, n),
+                        AsPtr::as_mut_ptr(&mut self.tau),
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                if let Some(a_t) = a_t {
+                    transpose_over(MatrixLayout::F { col: n, lda: n }, &a_t, a);
+                }
+                let k = std::cmp::max(0, n - 1) as usize;
+                Ok(unsafe { &self.tau.slice_assume_init_ref()[..k] })
+            }
+
+            fn eval(mut self, a: &mut [Self::Elem]) -> Result<Vec<Self::Elem>> {
+                let k = self.calc(a)?.len();
+                let mut tau = unsafe { self.tau.assume_init() };
+                tau.truncate(k);
+                Ok(tau)
+            }
+        }
+    };
+}
+impl_hessenberg_work!(c64, lapack_sys::zgehrd_);
+impl_hessenberg_work!(c32, lapack_sys::cgehrd_);
+impl_hessenberg_work!(f64, lapack_sys::dgehrd_);
+impl_hessenberg_work!(f32, lapack_sys::sgehrd_);
+
+/// Generate the unitary matrix $Q$ from the result of [HessenbergWork]
+#[cfg_attr(doc, katexit::katexit)]
+pub struct HessenbergQWork<T: Scalar> {
+    pub n: i32,
+    pub layout: MatrixLayout,
+    pub work: Vec<MaybeUninit<T>>,
+}
+
+pub trait HessenbergQWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(l: MatrixLayout) -> Result<Self>;
+    fn calc(&mut self, a: &mut [Self::Elem], tau: &[Self::Elem]) -> Result<()>;
+}
+
+macro_rules! impl_hessenberg_q_work {
+    ($s:ty, $orghr:path) => {
+        impl HessenbergQWorkImpl for HessenbergQWork<$s> {
+            type Elem = $s;
+
+            fn new(layout: MatrixLayout) -> Result<Self> {
+                let (n, _) = layout.size();
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                unsafe {
+                    $orghr(
+                        &n,
+                        &1,
+                        &n,
+                        std::ptr::null_mut(),
+                        &std::cmp::max(1, n),
+                        std::ptr::null_mut(),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let lwork = work_size[0].to_usize().unwrap();
+                let work = vec_uninit(lwork);
+                Ok(HessenbergQWork { n, layout, work })
+            }
+
+            fn calc(&mut self, a: &mut [Self::Elem], tau: &[Self::Elem]) -> Result<()> {
+                let n = self.n;
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut a_t = match self.layout {
+                    MatrixLayout::C { .. } => Some(transpose(self.layout, a).1),
+                    MatrixLayout::F { .. } => None,
+                };
+                let mut info = 0;
+                unsafe {
+                    $orghr(
+                        &n,
+                        &1,
+                        &n,
+                        AsPtr::as_mut_ptr(a_t.as_deref_mut().unwrap_or(a)),
+                        &std::cmp::max(1, n),
+                        AsPtr::as_ptr(tau),
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                if let Some(a_t) = a_t {
+                    transpose_over(MatrixLayout::F { col: n, lda: n }, &a_t, a);
+                }
+                Ok(())
+            }
+        }
+    };
+}
+impl_hessenberg_q_work!(c64, lapack_sys::zunghr_);
+impl_hessenberg_q_work!(c32, lapack_sys::cunghr_);
+impl_hessenberg_q_work!(f64, lapack_sys::dorghr_);
+impl_hessenberg_q_work!(f32, lapack_sys::sorghr_);
@@ -1,29 +1,69 @@
 //! Least squares
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | sgels  | dgels  | cgels  | zgels  |
+//! | sgelsy | dgelsy | cgelsy | zgelsy |
+//! | sgelsd | dgelsd | cgelsd | zgelsd |
+//! | sgelss | dgelss | cgelss | zgelss |
 
 use crate::{error::*, layout::*, *};
 use cauchy::*;
-use num_traits::{ToPrimitive, Zero};
+use num_traits::{Float, ToPrimitive, Zero};
+
+/// Options for least squares solver
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LeastSquaresOptions<R> {
+    /// Algorithm used to solve the problem
+    pub driver: LeastSquaresDriver,
+    /// Used to determine the effective rank of `A`.
+    ///
+    /// Singular values `s[i] <= rcond * s[0]` are treated as zero for the SVD-based drivers,
+    /// and the condition number of the leading triangular part of `R` is kept below `1 / rcond`
+    /// for [LeastSquaresDriver::CompleteOrthogonal].
+    /// `None` means the machine precision. Ignored by [LeastSquaresDriver::QR].
+    pub rcond: Option<R>,
+}
+
+impl<R> Default for LeastSquaresOptions<R> {
+    fn default() -> Self {
+        LeastSquaresOptions {
+            driver: LeastSquaresDriver::default(),
+            rcond: None,
+        }
+    }
+}
 
 /// Result of LeastSquares
 pub struct LeastSquaresOwned<A: Scalar> {
-    /// singular values
+    /// singular values, empty unless the driver is based on SVD
     pub singular_values: Vec<A::Real>,
     /// The rank of the input matrix A
     pub rank: i32,
+    /// The driver used to solve the problem
+    pub driver: LeastSquaresDriver,
 }
 
 /// Result of LeastSquares
 pub struct LeastSquaresRef<'work, A: Scalar> {
-    /// singular values
+    /// singular values, empty unless the driver is based on SVD
     pub singular_values: &'work [A::Real],
     /// The rank of the input matrix A
     pub rank: i32,
+    /// The driver used to solve the problem
+    pub driver: LeastSquaresDriver,
 }
 
 pub struct LeastSquaresWork<T: Scalar> {
     pub a_layout: MatrixLayout,
     pub b_layout: MatrixLayout,
+    pub driver: LeastSquaresDriver,
+    pub rcond: T::Real,
     pub singular_values: Vec<MaybeUninit<T::Real>>,
+    pub jpvt: Vec<i32>,
     pub work: Vec<MaybeUninit<T>>,
     pub iwork: Vec<MaybeUninit<i32>>,
     pub rwork: Option<Vec<MaybeUninit<T::Real>>>,
@@ -31,7 +71,14 @@ pub struct LeastSquaresWork<T: Scalar> {
 
 pub trait LeastSquaresWorkImpl: Sized {
     type Elem: Scalar;
-    fn new(a_layout: MatrixLayout, b_layout: MatrixLayout) -> Result<Self>;
+    fn new(a_layout: MatrixLayout, b_layout: MatrixLayout) -> Result<Self> {
+        Self::new_with_options(a_layout, b_layout, LeastSquaresOptions::default())
+    }
+    fn new_with_options(
+        a_layout: MatrixLayout,
+        b_layout: MatrixLayout,
+        options: LeastSquaresOptions<<Self::Elem as Scalar>::Real>,
+    ) -> Result<Self>;
     fn calc(
         &mut self,
         a: &mut [Self::Elem],
@@ -44,61 +91,159 @@ pub trait LeastSquaresWorkImpl: Sized {
     ) -> Result<LeastSquaresOwned<Self::Elem>>;
 }
 
+/// `dgels_` in lapack-sys takes the hidden length of `trans` argument unlike others
+#[allow(clippy::too_many_arguments)]
+unsafe fn dgels_(
+    trans: *const i8,
+    m: *const i32,
+    n: *const i32,
+    nrhs: *const i32,
+    a: *mut f64,
+    lda: *const i32,
+    b: *mut f64,
+    ldb: *const i32,
+    work: *mut f64,
+    lwork: *const i32,
+    info: *mut i32,
+) {
+    lapack_sys::dgels_(trans, m, n, nrhs, a, lda, b, ldb, work, lwork, info, 1)
+}
+
 macro_rules! impl_least_squares_work_c {
-    ($c:ty, $lsd:path) => {
+    ($c:ty, $gels:path, $gelsy:path, $gelsd:path, $gelss:path) => {
         impl LeastSquaresWorkImpl for LeastSquaresWork<$c> {
             type Elem = $c;
 
-            fn new(a_layout: MatrixLayout, b_layout: MatrixLayout) -> Result<Self> {
+            fn new_with_options(
+                a_layout: MatrixLayout,
+                b_layout: MatrixLayout,
+                options: LeastSquaresOptions<<Self::Elem as Scalar>::Real>,
+            ) -> Result<Self> {
                 let (m, n) = a_layout.size();
                 let (m_, nrhs) = b_layout.size();
                 let k = m.min(n);
                 assert!(m_ >= m);
 
-                let rcond = -1.;
-                let mut singular_values = vec_uninit(k as usize);
+                let driver = options.driver;
+                let rcond = options.rcond.unwrap_or(match driver {
+                    // Negative RCOND means the machine precision for ?gelsd and ?gelss,
+                    // but disables the rank truncation for ?gelsy
+                    LeastSquaresDriver::CompleteOrthogonal => {
+                        <Self::Elem as Scalar>::Real::epsilon()
+                    }
+                    _ => -1.,
+                });
+                let mut singular_values = match driver {
+                    LeastSquaresDriver::DivideAndConquer | LeastSquaresDriver::SVD => {
+                        vec_uninit(k as usize)
+                    }
+                    LeastSquaresDriver::QR | LeastSquaresDriver::CompleteOrthogonal => Vec::new(),
+                };
+                let mut jpvt = match driver {
+                    LeastSquaresDriver::CompleteOrthogonal => vec![0; n as usize],
+                    _ => Vec::new(),
+                };
                 let mut rank: i32 = 0;
 
                 // eval work size
                 let mut info = 0;
                 let mut work_size = [Self::Elem::zero()];
                 let mut iwork_size = [0];
-                let mut rwork = [<Self::Elem as Scalar>::Real::zero()];
+                let mut rwork_size = [<Self::Elem as Scalar>::Real::zero()];
                 unsafe {
-                    $lsd(
-                        &m,
-                        &n,
-                        &nrhs,
-                        std::ptr::null_mut(),
-                        &m,
-                        std::ptr::null_mut(),
-                        &m_,
-                        AsPtr::as_mut_ptr(&mut singular_values),
-                        &rcond,
-                        &mut rank,
-                        AsPtr::as_mut_ptr(&mut work_size),
-                        &(-1),
-                        AsPtr::as_mut_ptr(&mut rwork),
-                        iwork_size.as_mut_ptr(),
-                        &mut info,
-                    )
+                    match driver {
+                        LeastSquaresDriver::QR => $gels(
+                            Transpose::No.as_ptr(),
+                            &m,
+                            &n,
+                            &nrhs,
+                            std::ptr::null_mut(),
+                            &m,
+                            std::ptr::null_mut(),
+                            &m_,
+                            AsPtr::as_mut_ptr(&mut work_size),
+                            &(-1),
+                            &mut info,
+                        ),
+                        LeastSquaresDriver::CompleteOrthogonal => $gelsy(
+                            &m,
+                            &n,
+                            &nrhs,
+                            std::ptr::null_mut(),
+                            &m,
+                            std::ptr::null_mut(),
+                            &m_,
+                            jpvt.as_mut_ptr(),
+                            &rcond,
+                            &mut rank,
+                            AsPtr::as_mut_ptr(&mut work_size),
+                            &(-1),
+                            std::ptr::null_mut(),
+                            &mut info,
+                        ),
+                        LeastSquaresDriver::DivideAndConquer => $gelsd(
+                            &m,
+                            &n,
+                            &nrhs,
+                            std::ptr::null_mut(),
+                            &m,
+                            std::ptr::null_mut(),
+                            &m_,
+                            AsPtr::as_mut_ptr(&mut singular_values),
+                            &rcond,
+                            &mut rank,
+                            AsPtr::as_mut_ptr(&mut work_size),
+                            &(-1),
+                            AsPtr::as_mut_ptr(&mut rwork_size),
+                            iwork_size.as_mut_ptr(),
+                            &mut info,
+                        ),
+                        LeastSquaresDriver::SVD => $gelss(
+                            &m,
+                            &n,
+                            &nrhs,
+                            std::ptr::null_mut(),
+                            &m,
+                            std::ptr::null_mut(),
+                            &m_,
+                            AsPtr::as_mut_ptr(&mut singular_values),
+                            &rcond,
+                            &mut rank,
+                            AsPtr::as_mut_ptr(&mut work_size),
+                            &(-1),
+                            std::ptr::null_mut(),
+                            &mut info,
+                        ),
+                    }
                 };
                 info.as_lapack_result()?;
 
                 let lwork = work_size[0].to_usize().unwrap();
-                let liwork = iwork_size[0].to_usize().unwrap();
-                let lrwork = rwork[0].to_usize().unwrap();
+                let liwork = match driver {
+                    LeastSquaresDriver::DivideAndConquer => iwork_size[0].to_usize().unwrap(),
+                    _ => 0,
+                };
 
                 let work = vec_uninit(lwork);
                 let iwork = vec_uninit(liwork);
-                let rwork = vec_uninit(lrwork);
+                let rwork = match driver {
+                    LeastSquaresDriver::QR => None,
+                    LeastSquaresDriver::CompleteOrthogonal => Some(vec_uninit(2 * n as usize)),
+                    LeastSquaresDriver::DivideAndConquer => {
+                        Some(vec_uninit(rwork_size[0].to_usize().unwrap()))
+                    }
+                    LeastSquaresDriver::SVD => Some(vec_uninit(std::cmp::max(1, 5 * k) as usize)),
+                };
 
                 Ok(LeastSquaresWork {
                     a_layout,
                     b_layout,
+                    driver,
+                    rcond,
                     work,
                     iwork,
-                    rwork: Some(rwork),
+                    rwork,
+                    jpvt,
                     singular_values,
                 })
             }
@@ -136,28 +281,88 @@ macro_rules! impl_least_squares_work_c {
                     MatrixLayout::F { .. } => self.b_layout,
                 };
 
-                let rcond: <Self::Elem as Scalar>::Real = -1.;
+                let a_ptr = AsPtr::as_mut_ptr(a_t.as_mut().map(|v| v.as_mut_slice()).unwrap_or(a));
+                let b_ptr = AsPtr::as_mut_ptr(b_t.as_mut().map(|v| v.as_mut_slice()).unwrap_or(b));
                 let mut rank: i32 = 0;
 
                 let mut info = 0;
                 unsafe {
-                    $lsd(
-                        &m,
-                        &n,
-                        &nrhs,
-                        AsPtr::as_mut_ptr(a_t.as_mut().map(|v| v.as_mut_slice()).unwrap_or(a)),
-                        &m,
-                        AsPtr::as_mut_ptr(b_t.as_mut().map(|v| v.as_mut_slice()).unwrap_or(b)),
-                        &m_,
-                        AsPtr::as_mut_ptr(&mut self.singular_values),
-                        &rcond,
-                        &mut rank,
-                        AsPtr::as_mut_ptr(&mut self.work),
-                        &lwork,
-                        AsPtr::as_mut_ptr(self.rwork.as_mut().unwrap()),
-                        AsPtr::as_mut_ptr(&mut self.iwork),
-                        &mut info,
-                    );
+                    match self.driver {
+                        LeastSquaresDriver::QR => {
+                            $gels(
+                                Transpose::No.as_ptr(),
+                                &m,
+                                &n,
+                                &nrhs,
+                                a_ptr,
+                                &m,
+                                b_ptr,
+                                &m_,
+                                AsPtr::as_mut_ptr(&mut self.work),
+                                &lwork,
+                                &mut info,
+                            );
+                            // `?gels` assumes that A has full rank
+                            rank = m.min(n);
+                        }
+                        LeastSquaresDriver::CompleteOrthogonal => {
+                            // All columns are free to be pivoted
+                            self.jpvt.iter_mut().for_each(|p| *p = 0);
+                            $gelsy(
+                                &m,
+                                &n,
+                                &nrhs,
+                                a_ptr,
+                                &m,
+                                b_ptr,
+                                &m_,
+                                self.jpvt.as_mut_ptr(),
+                                &self.rcond,
+                                &mut rank,
+                                AsPtr::as_mut_ptr(&mut self.work),
+                                &lwork,
+                                AsPtr::as_mut_ptr(self.rwork.as_mut().unwrap()),
+                                &mut info,
+                            );
+                        }
+                        LeastSquaresDriver::DivideAndConquer => {
+                            $gelsd(
+                                &m,
+                                &n,
+                                &nrhs,
+                                a_ptr,
+                                &m,
+                                b_ptr,
+                                &m_,
+                                AsPtr::as_mut_ptr(&mut self.singular_values),
+                                &self.rcond,
+                                &mut rank,
+                                AsPtr::as_mut_ptr(&mut self.work),
+                                &lwork,
+                                AsPtr::as_mut_ptr(self.rwork.as_mut().unwrap()),
+                                AsPtr::as_mut_ptr(&mut self.iwork),
+                                &mut info,
+                            );
+                        }
+                        LeastSquaresDriver::SVD => {
+                            $gelss(
+                                &m,
+                                &n,
+                                &nrhs,
+                                a_ptr,
+                                &m,
+                                b_ptr,
+                                &m_,
+                                AsPtr::as_mut_ptr(&mut self.singular_values),
+                                &self.rcond,
+                                &mut rank,
+                                AsPtr::as_mut_ptr(&mut self.work),
+                                &lwork,
+                                AsPtr::as_mut_ptr(self.rwork.as_mut().unwrap()),
+                                &mut info,
+                            );
+                        }
+                    }
                 }
                 info.as_lapack_result()?;
 
@@ -172,6 +377,7 @@ macro_rules! impl_least_squares_work_c {
                 Ok(LeastSquaresRef {
                     singular_values,
                     rank,
+                    driver: self.driver,
                 })
             }
 
@@ -180,32 +386,66 @@ macro_rules! impl_least_squares_work_c {
                 a: &mut [Self::Elem],
                 b: &mut [Self::Elem],
             ) -> Result<LeastSquaresOwned<Self::Elem>> {
-                let LeastSquaresRef { rank, .. } = self.calc(a, b)?;
+                let LeastSquaresRef { rank, driver, .. } = self.calc(a, b)?;
                 let singular_values = unsafe { self.singular_values.assume_init() };
                 Ok(LeastSquaresOwned {
                     singular_values,
                     rank,
+                    driver,
                 })
             }
         }
     };
 }
-impl_least_squares_work_c!(c64, lapack_sys::zgelsd_);
-impl_least_squares_work_c!(c32, lapack_sys::cgelsd_);
+impl_least_squares_work_c!(
+    c64,
+    lapack_sys::zgels_,
+    lapack_sys::zgelsy_,
+    lapack_sys::zgelsd_,
+    lapack_sys::zgelss_
+);
+impl_least_squares_work_c!(
+    c32,
+    lapack_sys::cgels_,
+    lapack_sys::cgelsy_,
+    lapack_sys::cgelsd_,
+    lapack_sys::cgelss_
+);
 
 macro_rules! impl_least_squares_work_r {
-    ($c:ty, $lsd:path) => {
-        impl LeastSquaresWorkImpl for LeastSquaresWork<$c> {
-            type Elem = $c;
-
-            fn new(a_layout: MatrixLayout, b_layout: MatrixLayout) -> Result<Self> {
+    ($f:ty, $gels:path, $gelsy:path, $gelsd:path, $gelss:path) => {
+        impl LeastSquaresWorkImpl for LeastSquaresWork<$f> {
+            type Elem = $f;
+
+            fn new_with_options(
+                a_layout: MatrixLayout,
+                b_layout: MatrixLayout,
+                options: LeastSquaresOptions<<Self::Elem as Scalar>::Real>,
+            ) -> Result<Self> {
                 let (m, n) = a_layout.size();
                 let (m_, nrhs) = b_layout.size();
                 let k = m.min(n);
                 assert!(m_ >= m);
 
-                let rcond = -1.;
-                let mut singular_values = vec_uninit(k as usize);
+                let driver = options.driver;
+                let rcond = options.rcond.unwrap_or(match driver {
+                    // Negative RCOND means the machine precision for ?gelsd and ?gelss,
+                    // but disables the rank truncation for ?gelsy
+                    LeastSquaresDriver::CompleteOrthogonal => {
+                        <Self::Elem as Scalar>::Real::epsilon()
+                    }
+                    _ => -1.,
+                });
+                let mut singular_values = match driver {
+                    LeastSquaresDriver::DivideAndConquer | LeastSquaresDriver::SVD => {
+                        vec_uninit(k as usize)
+                    }
+                    LeastSquaresDriver::QR | LeastSquaresDriver::CompleteOrthogonal => Vec::new(),
+                };
+                let mut jpvt = match driver {
+                    LeastSquaresDriver::CompleteOrthogonal => vec![0; n as usize],
+                    _ => Vec::new(),
+                };
                 let mut rank: i32 = 0;
 
                 // eval work size
@@ -213,37 +453,89 @@ macro_rules! impl_least_squares_work_r {
                 let mut work_size = [Self::Elem::zero()];
                 let mut iwork_size = [0];
                 unsafe {
-                    $lsd(
-                        &m,
-                        &n,
-                        &nrhs,
-                        std::ptr::null_mut(),
-                        &m,
-                        std::ptr::null_mut(),
-                        &m_,
-                        AsPtr::as_mut_ptr(&mut singular_values),
-                        &rcond,
-                        &mut rank,
-                        AsPtr::as_mut_ptr(&mut work_size),
-                        &(-1),
-                        iwork_size.as_mut_ptr(),
-                        &mut info,
-                    )
+                    match driver {
+                        LeastSquaresDriver::QR => $gels(
+                            Transpose::No.as_ptr(),
+                            &m,
+                            &n,
+                            &nrhs,
+                            std::ptr::null_mut(),
+                            &m,
+                            std::ptr::null_mut(),
+                            &m_,
+                            AsPtr::as_mut_ptr(&mut work_size),
+                            &(-1),
+                            &mut info,
+                        ),
+                        LeastSquaresDriver::CompleteOrthogonal => $gelsy(
+                            &m,
+                            &n,
+                            &nrhs,
+                            std::ptr::null_mut(),
+                            &m,
+                            std::ptr::null_mut(),
+                            &m_,
+                            jpvt.as_mut_ptr(),
+                            &rcond,
+                            &mut rank,
+                            AsPtr::as_mut_ptr(&mut work_size),
+                            &(-1),
+                            &mut info,
+                        ),
+                        LeastSquaresDriver::DivideAndConquer => $gelsd(
+                            &m,
+                            &n,
+                            &nrhs,
+                            std::ptr::null_mut(),
+                            &m,
+                            std::ptr::null_mut(),
+                            &m_,
+                            AsPtr::as_mut_ptr(&mut singular_values),
+                            &rcond,
+                            &mut rank,
+                            AsPtr::as_mut_ptr(&mut work_size),
+                            &(-1),
+                            iwork_size.as_mut_ptr(),
+                            &mut info,
+                        ),
+                        LeastSquaresDriver::SVD => $gelss(
+                            &m,
+                            &n,
+                            &nrhs,
+                            std::ptr::null_mut(),
+                            &m,
+                            std::ptr::null_mut(),
+                            &m_,
+                            AsPtr::as_mut_ptr(&mut singular_values),
+                            &rcond,
+                            &mut rank,
+                            AsPtr::as_mut_ptr(&mut work_size),
+                            &(-1),
+                            &mut info,
+                        ),
+                    }
                 };
                 info.as_lapack_result()?;
 
                 let lwork = work_size[0].to_usize().unwrap();
-                let liwork = iwork_size[0].to_usize().unwrap();
+                let liwork = match driver {
+                    LeastSquaresDriver::DivideAndConquer => iwork_size[0].to_usize().unwrap(),
+                    _ => 0,
+                };
 
                 let work = vec_uninit(lwork);
                 let iwork = vec_uninit(liwork);
+                let rwork = None;
 
                 Ok(LeastSquaresWork {
                     a_layout,
                     b_layout,
+                    driver,
+                    rcond,
                     work,
                     iwork,
-                    rwork: None,
+                    rwork,
+                    jpvt,
                     singular_values,
                 })
             }
@@ -281,27 +573,85 @@ macro_rules! impl_least_squares_work_r {
                     MatrixLayout::F { .. } => self.b_layout,
                 };
 
-                let rcond: <Self::Elem as Scalar>::Real = -1.;
+                let a_ptr = AsPtr::as_mut_ptr(a_t.as_mut().map(|v| v.as_mut_slice()).unwrap_or(a));
+                let b_ptr = AsPtr::as_mut_ptr(b_t.as_mut().map(|v| v.as_mut_slice()).unwrap_or(b));
                 let mut rank: i32 = 0;
 
                 let mut info = 0;
                 unsafe {
-                    $lsd(
-                        &m,
-                        &n,
-                        &nrhs,
-                        AsPtr::as_mut_ptr(a_t.as_mut().map(|v| v.as_mut_slice()).unwrap_or(a)),
-                        &m,
-                        AsPtr::as_mut_ptr(b_t.as_mut().map(|v| v.as_mut_slice()).unwrap_or(b)),
-                        &m_,
-                        AsPtr::as_mut_ptr(&mut self.singular_values),
-                        &rcond,
-                        &mut rank,
-                        AsPtr::as_mut_ptr(&mut self.work),
-                        &lwork,
-                        AsPtr::as_mut_ptr(&mut self.iwork),
-                        &mut info,
-                    );
+                    match self.driver {
+                        LeastSquaresDriver::QR => {
+                            $gels(
+                                Transpose::No.as_ptr(),
+                                &m,
+                                &n,
+                                &nrhs,
+                                a_ptr,
+                                &m,
+                                b_ptr,
+                                &m_,
+                                AsPtr::as_mut_ptr(&mut self.work),
+                                &lwork,
+                                &mut info,
+                            );
+                            // `?gels` assumes that A has full rank
+                            rank = m.min(n);
+                        }
+                        LeastSquaresDriver::CompleteOrthogonal => {
+                            // All columns are free to be pivoted
+                            self.jpvt.iter_mut().for_each(|p| *p = 0);
+                            $gelsy(
+                                &m,
+                                &n,
+                                &nrhs,
+                                a_ptr,
+                                &m,
+                                b_ptr,
+                                &m_,
+                                self.jpvt.as_mut_ptr(),
+                                &self.rcond,
+                                &mut rank,
+                                AsPtr::as_mut_ptr(&mut self.work),
+                                &lwork,
+                                &mut info,
+                            );
+                        }
+                        LeastSquaresDriver::DivideAndConquer => {
+                            $gelsd(
+                                &m,
+                                &n,
+                                &nrhs,
+                                a_ptr,
+                                &m,
+                                b_ptr,
+                                &m_,
+                                AsPtr::as_mut_ptr(&mut self.singular_values),
+                                &self.rcond,
+                                &mut rank,
+                                AsPtr::as_mut_ptr(&mut self.work),
+                                &lwork,
+                                AsPtr::as_mut_ptr(&mut self.iwork),
+                                &mut info,
+                            );
+                        }
+                        LeastSquaresDriver::SVD => {
+                            $gelss(
+                                &m,
+                                &n,
+                                &nrhs,
+                                a_ptr,
+                                &m,
+                                b_ptr,
+                                &m_,
+                                AsPtr::as_mut_ptr(&mut self.singular_values),
+                                &self.rcond,
+                                &mut rank,
+                                AsPtr::as_mut_ptr(&mut self.work),
+                                &lwork,
+                                &mut info,
+                            );
+                        }
+                    }
                 }
                 info.as_lapack_result()?;
 
@@ -316,6 +666,7 @@ macro_rules! impl_least_squares_work_r {
                 Ok(LeastSquaresRef {
                     singular_values,
                     rank,
+                    driver: self.driver,
                 })
             }
 
@@ -324,15 +675,28 @@ macro_rules! impl_least_squares_work_r {
                 a: &mut [Self::Elem],
                 b: &mut [Self::Elem],
             ) -> Result<LeastSquaresOwned<Self::Elem>> {
-                let LeastSquaresRef { rank, .. } = self.calc(a, b)?;
+                let LeastSquaresRef { rank, driver, .. } = self.calc(a, b)?;
                 let singular_values = unsafe { self.singular_values.assume_init() };
                 Ok(LeastSquaresOwned {
                     singular_values,
                     rank,
+                    driver,
                 })
             }
         }
     };
 }
-impl_least_squares_work_r!(f64, lapack_sys::dgelsd_);
-impl_least_squares_work_r!(f32, lapack_sys::sgelsd_);
+impl_least_squares_work_r!(
+    f64,
+    dgels_,
+    lapack_sys::dgelsy_,
+    lapack_sys::dgelsd_,
+    lapack_sys::dgelss_
+);
+impl_least_squares_work_r!(
+    f32,
+    lapack_sys::sgels_,
+    lapack_sys::sgelsy_,
+    lapack_sys::sgelsd_,
+    lapack_sys::sgelss_
+);
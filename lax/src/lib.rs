@@ -52,8 +52,16 @@
 //! According to the property input metrix, several types of triangular decomposition are used:
 //!
 //! - [solve] module provides methods for LU-decomposition for general matrix.
-//! - [solveh] module provides methods for Bunch-Kaufman diagonal pivoting method for symmetric/Hermitian indefinite matrix.
-//! - [cholesky] module provides methods for Cholesky decomposition for symmetric/Hermitian positive dinite matrix.
+//! - [solveh] module provides methods for Bunch-Kaufman diagonal pivoting method for symmetric/Hermitian indefinite matrix,
+//!   and its rook pivoting variant and Aasen's algorithm.
+//! - [cholesky] module provides methods for Cholesky decomposition for symmetric/Hermitian positive dinite matrix,
+//!   and pivoted Cholesky decomposition for positive semidefinite matrix.
+//! - [triangular] module provides methods for solving, inverting, and multiplying triangular matrix.
+//! - [banded] module provides methods for LU and Cholesky decomposition for banded matrix.
+//! - [packed] module provides methods for Cholesky and Bunch-Kaufman decomposition for symmetric/Hermitian matrix in packed storage.
+//! - [rcond] module provides methods for estimating condition number using the results of LU, Cholesky, and Bunch-Kaufman decompositions.
+//! - [solve_mixed] module provides methods for LU and Cholesky decomposition in single precision with iterative refinement in double precision.
+//!   These are collected in [LapackMixed] trait, which is implemented only for [f64] and [c64].
 //!
 //! Eigenvalue Problem
 //! -------------------
@@ -62,15 +70,28 @@
 //! there are several types of eigenvalue problem API
 //!
 //! - [eig] module for eigenvalue problem for general matrix.
+//!   The real representation of the results for real matrix is available through [LapackReal] trait.
 //! - [eigh] module for eigenvalue problem for symmetric/Hermitian matrix.
+//! - [eighdc] module for eigenvalue problem for symmetric/Hermitian matrix with divide-and-conquer algorithm.
+//! - [eigh_range] module for computing a subset of eigenvalues of symmetric/Hermitian matrix.
+//! - [eigh_banded] module for eigenvalue problem for symmetric/Hermitian banded matrix.
+//! - [eigh_packed] module for eigenvalue problem for symmetric/Hermitian matrix in packed storage.
+//! - [tridiagonal] module for eigenvalue problem for symmetric/Hermitian tridiagonal matrix.
 //! - [eigh_generalized] module for generalized eigenvalue problem for symmetric/Hermitian matrix.
+//! - [eig_generalized] module for generalized eigenvalue problem for general matrix.
+//! - [hessenberg] module for Hessenberg reduction for general matrix.
+//! - [schur] module for Schur decomposition for general matrix.
+//! - [schur_generalized] module for generalized Schur decomposition for general matrix.
+//! - [sylvester] module for Sylvester equation with the matrices in Schur canonical form.
 //!
 //! Singular Value Decomposition
 //! -----------------------------
 //!
 //! - [svd] module for singular value decomposition (SVD) for general matrix
 //! - [svddc] module for singular value decomposition (SVD) with divided-and-conquer algorithm for general matrix
-//! - [least_squares] module for solving least square problem using SVD
+//! - [bidiagonal] module for bidiagonal reduction of general matrix and SVD for bidiagonal matrix
+//! - [gsvd] module for generalized singular value decomposition (GSVD) for a pair of general matrices
+//! - [least_squares] module for solving least square problem using QR, complete orthogonal decomposition, or SVD
 //!
 
 #![deny(rustdoc::broken_intra_doc_links, rustdoc::private_intra_doc_links)]
@@ -85,26 +106,50 @@ extern crate openblas_src as _src;
 extern crate netlib_src as _src;
 
 pub mod alloc;
+pub mod banded;
+pub mod bidiagonal;
 pub mod cholesky;
 pub mod eig;
+pub mod eig_generalized;
 pub mod eigh;
+pub mod eigh_banded;
 pub mod eigh_generalized;
+pub mod eigh_packed;
+pub mod eigh_range;
+pub mod eighdc;
 pub mod error;
 pub mod flags;
+pub mod gsvd;
+pub mod hessenberg;
 pub mod layout;
 pub mod least_squares;
 pub mod opnorm;
+pub mod packed;
 pub mod qr;
 pub mod rcond;
+pub mod schur;
+pub mod schur_generalized;
 pub mod solve;
+pub mod solve_mixed;
 pub mod solveh;
 pub mod svd;
 pub mod svddc;
+pub mod sylvester;
 pub mod triangular;
 pub mod tridiagonal;
 
+pub use self::banded::{Banded, CholeskyFactorizedBanded, LUFactorizedBanded};
+pub use self::bidiagonal::{Bidiagonal, BidiagonalOwned};
+pub use self::eig::{EigExpertOwned, EigOwned, EigRealOwned};
+pub use self::eig_generalized::EigGeneralizedOwned;
+pub use self::eigh_range::EighRangeOwned;
 pub use self::flags::*;
-pub use self::least_squares::LeastSquaresOwned;
+pub use self::gsvd::GsvdOwned;
+pub use self::least_squares::{LeastSquaresOptions, LeastSquaresOwned};
+pub use self::packed::Packed;
+pub use self::schur::SchurOwned;
+pub use self::schur_generalized::SchurGeneralizedOwned;
+pub use self::solve::SolveExpertOwned;
 pub use self::svd::{SvdOwned, SvdRef};
 pub use self::tridiagonal::{LUFactorizedTridiagonal, Tridiagonal};
 
@@ -124,6 +169,23 @@ pub trait Lapack: Scalar {
         a: &mut [Self],
     ) -> Result<(Vec<Self::Complex>, Vec<Self::Complex>)>;
 
+    /// Compute eigenvalues with both left and right eigenvectors for a general matrix
+    ///
+    /// Both `vl` and `vr` of the result are `Some`.
+    /// The eigenvectors are normalized to have unit 2-norm, and returned in column-major order.
+    fn eig_left_right(l: MatrixLayout, a: &mut [Self]) -> Result<EigOwned<Self>>;
+
+    /// Compute eigenvalues, right and left eigenvectors, and their reciprocal condition numbers
+    /// for a general matrix after balancing it
+    ///
+    /// `a` is overwritten, and the eigenvectors are returned in column-major order.
+    /// See [eig::EigExpertImpl] for details.
+    fn eig_expert(
+        balance: Balance,
+        l: MatrixLayout,
+        a: &mut [Self],
+    ) -> Result<EigExpertOwned<Self>>;
+
     /// Compute right eigenvalue and eigenvectors for a symmetric or Hermitian matrix
     fn eigh(
         calc_eigenvec: bool,
@@ -132,6 +194,83 @@ pub trait Lapack: Scalar {
         a: &mut [Self],
     ) -> Result<Vec<Self::Real>>;
 
+    /// Compute right eigenvalue and eigenvectors for a symmetric or Hermitian matrix
+    /// using the algorithm specified by [EighDriver]
+    fn eigh_with_driver(
+        calc_eigenvec: bool,
+        layout: MatrixLayout,
+        uplo: UPLO,
+        driver: EighDriver,
+        a: &mut [Self],
+    ) -> Result<Vec<Self::Real>>;
+
+    /// Compute a subset of eigenvalues and eigenvectors for a symmetric or Hermitian matrix
+    ///
+    /// Only the eigenvalues specified by `range` are computed in ascending order.
+    /// The eigenvectors are returned as a column-major $n \times m$ matrix
+    /// where $m$ is the number of found eigenvalues.
+    fn eigh_range(
+        calc_eigenvec: bool,
+        layout: MatrixLayout,
+        uplo: UPLO,
+        range: EigRange<Self::Real>,
+        a: &mut [Self],
+    ) -> Result<EighRangeOwned<Self>>;
+
+    /// Compute eigenvalues and eigenvectors for a symmetric or Hermitian banded matrix
+    /// using divide-and-conquer algorithm
+    ///
+    /// Only the super-diagonals (for [UPLO::Upper]) or the sub-diagonals (for [UPLO::Lower])
+    /// of `a` are referenced.
+    /// The eigenvectors are returned as a column-major $n \times n$ matrix.
+    fn eigh_banded(
+        calc_eigenvec: bool,
+        uplo: UPLO,
+        a: &Banded<Self>,
+    ) -> Result<EighRangeOwned<Self>>;
+
+    /// Compute a subset of eigenvalues and eigenvectors for a symmetric or Hermitian banded matrix
+    ///
+    /// Only the eigenvalues specified by `range` are computed in ascending order.
+    /// The eigenvectors are returned as a column-major $n \times m$ matrix
+    /// where $m$ is the number of found eigenvalues.
+    fn eigh_banded_range(
+        calc_eigenvec: bool,
+        uplo: UPLO,
+        range: EigRange<Self::Real>,
+        a: &Banded<Self>,
+    ) -> Result<EighRangeOwned<Self>>;
+
+    /// Compute eigenvalues and eigenvectors for a symmetric or Hermitian matrix in packed storage
+    ///
+    /// `a` is destroyed during the computation.
+    /// The eigenvectors are returned as a column-major $n \times n$ matrix.
+    fn eigh_packed(calc_eigenvec: bool, a: &mut Packed<Self>) -> Result<EighRangeOwned<Self>>;
+
+    /// Compute eigenvalues and eigenvectors for a symmetric or Hermitian tridiagonal matrix
+    ///
+    /// Only the diagonal `d` and the sub-diagonal `dl` of `a` are referenced.
+    /// All eigenvalues are computed by implicit QL/QR method (`?stev`),
+    /// and a subset specified by `range` is computed by the Relatively Robust Representations
+    /// algorithm (`?stemr`). Eigenvalues are returned in ascending order,
+    /// and the eigenvectors are returned as a column-major $n \times m$ matrix
+    /// where $m$ is the number of found eigenvalues.
+    fn eigh_tridiagonal(
+        calc_eigenvec: bool,
+        range: EigRange<Self::Real>,
+        a: &Tridiagonal<Self>,
+    ) -> Result<EighRangeOwned<Self>>;
+
+    /// Compute a subset of eigenvalues and eigenvectors for a symmetric or Hermitian tridiagonal matrix
+    /// using bisection (`?stebz`) and inverse iteration (`?stein`)
+    ///
+    /// This is efficient when only a few eigenvectors are required.
+    /// The result is returned in the same manner as [Lapack::eigh_tridiagonal].
+    fn eigh_tridiagonal_inverse_iteration(
+        range: EigRange<Self::Real>,
+        a: &Tridiagonal<Self>,
+    ) -> Result<EighRangeOwned<Self>>;
+
     /// Compute right eigenvalue and eigenvectors for a symmetric or Hermitian matrix
     fn eigh_generalized(
         calc_eigenvec: bool,
@@ -141,6 +280,92 @@ pub trait Lapack: Scalar {
         b: &mut [Self],
     ) -> Result<Vec<Self::Real>>;
 
+    /// Compute right eigenvalue and eigenvectors for a symmetric or Hermitian matrix
+    /// using the algorithm specified by [EighDriver]
+    fn eigh_generalized_with_driver(
+        calc_eigenvec: bool,
+        layout: MatrixLayout,
+        uplo: UPLO,
+        driver: EighDriver,
+        a: &mut [Self],
+        b: &mut [Self],
+    ) -> Result<Vec<Self::Real>>;
+
+    /// Compute generalized eigenvalues and right eigenvectors for a pair of general matrices
+    ///
+    /// The generalized eigenvalues are $\lambda_i = \alpha_i / \beta_i$,
+    /// which satisfy $A v_i = \lambda_i B v_i$. $\beta_i = 0$ corresponds to an infinite eigenvalue.
+    /// The right eigenvectors $v_i$ are returned in `vr` in column-major order for both layouts,
+    /// and `vl` is always `None`.
+    /// `a` and `b` must be in the same layout `l`.
+    fn eig_generalized(
+        calc_v: bool,
+        l: MatrixLayout,
+        a: &mut [Self],
+        b: &mut [Self],
+    ) -> Result<EigGeneralizedOwned<Self>>;
+
+    /// Compute Schur decomposition $A = ZTZ^\dagger$ for a general matrix
+    ///
+    /// $T$ is overwritten into `a`, and $Z$ is returned in the same layout if `calc_v` is true.
+    /// For real matrices, $T$ is quasi upper triangular with 2-by-2 blocks for complex conjugate eigenvalues.
+    ///
+    /// To manage memory more strictly, use [schur::SchurWork].
+    fn schur(calc_v: bool, l: MatrixLayout, a: &mut [Self]) -> Result<SchurOwned<Self>>;
+
+    /// Reorder Schur decomposition computed by [Lapack::schur]
+    /// to move the selected eigenvalues to the leading block of $T$.
+    ///
+    /// Returns the reordered eigenvalues and the dimension of the invariant subspace
+    /// spanned by the leading columns of $Z$.
+    fn schur_reorder(
+        l: MatrixLayout,
+        select: &[bool],
+        t: &mut [Self],
+        z: &mut [Self],
+    ) -> Result<(Vec<Self::Complex>, i32)>;
+
+    /// Solve Sylvester equation $\mathrm{op}(A) X + s X \mathrm{op}(B) = \alpha C$
+    /// for the matrices in Schur canonical form computed by [Lapack::schur]
+    ///
+    /// $C$ is overwritten by $X$, and the scaling factor $\alpha \le 1$ is returned.
+    /// See [sylvester::SylvesterImpl] for details.
+    fn sylvester(
+        l: MatrixLayout,
+        trana: Transpose,
+        tranb: Transpose,
+        isgn: i32,
+        a: &[Self],
+        b: &[Self],
+        c: &mut [Self],
+    ) -> Result<Self::Real>;
+
+    /// Compute generalized Schur (QZ) decomposition $A = QSZ^\dagger$, $B = QTZ^\dagger$
+    /// for a pair of general matrices
+    ///
+    /// $S$ and $T$ are overwritten into `a` and `b`, and $Q$ and $Z$ are returned if `calc_v` is true.
+    /// `a` and `b` must be in the same layout `l`.
+    fn schur_generalized(
+        calc_v: bool,
+        l: MatrixLayout,
+        a: &mut [Self],
+        b: &mut [Self],
+    ) -> Result<SchurGeneralizedOwned<Self>>;
+
+    /// Reorder generalized Schur decomposition computed by [Lapack::schur_generalized]
+    /// to move the selected eigenvalues to the leading blocks of $S$ and $T$.
+    ///
+    /// Returns the reordered numerators and denominators of the eigenvalues,
+    /// and the dimension of the deflating subspace spanned by the leading columns of $Z$.
+    fn schur_generalized_reorder(
+        l: MatrixLayout,
+        select: &[bool],
+        s: &mut [Self],
+        t: &mut [Self],
+        q: &mut [Self],
+        z: &mut [Self],
+    ) -> Result<(Vec<Self::Complex>, Vec<Self>, i32)>;
+
     /// Execute Householder reflection as the first step of QR-decomposition
     ///
     /// For C-continuous array,
@@ -153,12 +378,74 @@ pub trait Lapack: Scalar {
     /// Execute QR-decomposition at once
     fn qr(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>>;
 
+    /// Execute Householder reflection with column pivoting as the first step of
+    /// QR-decomposition $ AP = QR $
+    ///
+    /// Returns the scalar factors of reflectors and the pivot indices `jpvt`,
+    /// where the $j$-th column of $AP$ is the `jpvt[j]`-th column of $A$ (1-based as LAPACK).
+    /// Different from [Lapack::householder], the result is stored as the QR-decomposition of $A$
+    /// even for C-continuous array.
+    fn householder_pivoted(l: MatrixLayout, a: &mut [Self]) -> Result<(Vec<Self>, Pivot)>;
+
+    /// Reduce a general matrix into upper Hessenberg form $ H = Q^H A Q $
+    ///
+    /// $H$ is stored in the upper triangle and the first sub-diagonal of `a`,
+    /// and the Householder reflectors representing $Q$ are stored below it.
+    /// Returns the scalar factors of the reflectors.
+    fn hessenberg(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>>;
+
+    /// Reconstruct the unitary matrix $Q$ from the output of [Lapack::hessenberg]
+    fn hessenberg_q(l: MatrixLayout, a: &mut [Self], tau: &[Self]) -> Result<()>;
+
     /// Compute singular-value decomposition (SVD)
     fn svd(l: MatrixLayout, calc_u: bool, calc_vt: bool, a: &mut [Self]) -> Result<SvdOwned<Self>>;
 
     /// Compute singular value decomposition (SVD) with divide-and-conquer algorithm
     fn svddc(layout: MatrixLayout, jobz: JobSvd, a: &mut [Self]) -> Result<SvdOwned<Self>>;
 
+    /// Reduce a general matrix into bidiagonal form $ B = Q^H A P $ by Golub-Kahan bidiagonalization
+    ///
+    /// The Householder reflectors representing $Q$ and $P$ are stored in `a`,
+    /// and their scalar factors are returned with $B$.
+    fn bidiagonal(l: MatrixLayout, a: &mut [Self]) -> Result<BidiagonalOwned<Self>>;
+
+    /// Reconstruct the first $\min(m, n)$ columns of $Q$ from the output of [Lapack::bidiagonal]
+    ///
+    /// $Q$ is returned as a column-major matrix.
+    fn bidiagonal_q(l: MatrixLayout, a: &[Self], tauq: &[Self]) -> Result<Vec<Self>>;
+
+    /// Reconstruct the first $\min(m, n)$ rows of $P^H$ from the output of [Lapack::bidiagonal]
+    ///
+    /// $P^H$ is returned as a column-major matrix.
+    fn bidiagonal_pt(l: MatrixLayout, a: &[Self], taup: &[Self]) -> Result<Vec<Self>>;
+
+    /// Compute singular value decomposition $ B = U \Sigma V^T $ of a bidiagonal matrix
+    /// using implicit zero-shift QR algorithm
+    ///
+    /// $U$ and $V^T$ are returned as column-major matrices.
+    fn svd_bidiagonal(calc_uvt: bool, b: &Bidiagonal<Self>) -> Result<SvdOwned<Self>>;
+
+    /// Compute singular value decomposition of a bidiagonal matrix
+    /// using divide-and-conquer algorithm
+    ///
+    /// $U$ and $V^T$ are returned as column-major matrices.
+    fn svddc_bidiagonal(calc_uvt: bool, b: &Bidiagonal<Self>) -> Result<SvdOwned<Self>>;
+
+    /// Compute generalized singular value decomposition (GSVD) of a pair of matrices $(A, B)$
+    ///
+    /// $A$ of shape $(m, n)$ and $B$ of shape $(p, n)$ are destroyed during the computation.
+    /// $U$, $V$, $Q$ and $R$ are returned in column-major layout.
+    /// See [gsvd::GsvdWork] for the structure of the decomposition.
+    fn ggsvd3(
+        calc_u: bool,
+        calc_v: bool,
+        calc_q: bool,
+        a_layout: MatrixLayout,
+        a: &mut [Self],
+        b_layout: MatrixLayout,
+        b: &mut [Self],
+    ) -> Result<GsvdOwned<Self>>;
+
     /// Compute a vector $x$ which minimizes Euclidian norm $\| Ax - b\|$
     /// for a given matrix $A$ and a vector $b$.
     fn least_squares(
@@ -175,6 +462,16 @@ pub trait Lapack: Scalar {
         b: &mut [Self],
     ) -> Result<LeastSquaresOwned<Self>>;
 
+    /// Solve least square problems $\argmin_X \| AX - B\|$
+    /// using the driver and the rank cutoff specified by `options`
+    fn least_squares_nrhs_with_options(
+        a_layout: MatrixLayout,
+        a: &mut [Self],
+        b_layout: MatrixLayout,
+        b: &mut [Self],
+        options: LeastSquaresOptions<Self::Real>,
+    ) -> Result<LeastSquaresOwned<Self>>;
+
     /// Computes the LU decomposition of a general $m \times n$ matrix
     /// with partial pivoting with row interchanges.
     ///
@@ -209,6 +506,19 @@ pub trait Lapack: Scalar {
     /// Solve linear equations $Ax = b$ using the output of LU-decomposition
     fn solve(l: MatrixLayout, t: Transpose, a: &[Self], p: &Pivot, b: &mut [Self]) -> Result<()>;
 
+    /// Solve linear equations $Ax = b$ with equilibration, iterative refinement, and error bounds
+    ///
+    /// If the output of LU-decomposition of $A$ by [Lapack::lu] is given as `lu`, it is reused.
+    /// Otherwise $A$ is equilibrated and factorized, and `a` is overwritten by the equilibrated matrix.
+    /// `b` is overwritten by the solution $x$.
+    fn solve_expert(
+        l: MatrixLayout,
+        t: Transpose,
+        a: &mut [Self],
+        lu: Option<(&[Self], &Pivot)>,
+        b: &mut [Self],
+    ) -> Result<SolveExpertOwned<Self>>;
+
     /// Factorize symmetric/Hermitian matrix using Bunch-Kaufman diagonal pivoting method
     ///
     /// For a given symmetric matrix $A$,
@@ -230,6 +540,40 @@ pub trait Lapack: Scalar {
     /// Solve symmetric/Hermitian linear equation $Ax = b$ using the result of [Lapack::bk]
     fn solveh(l: MatrixLayout, uplo: UPLO, a: &[Self], ipiv: &Pivot, b: &mut [Self]) -> Result<()>;
 
+    /// Factorize symmetric/Hermitian matrix with the given [PivotStrategy]
+    ///
+    /// [PivotStrategy::BunchKaufman] is identical to [Lapack::bk].
+    /// [PivotStrategy::Rook] computes the same form $A = U D U^H$ as [Lapack::bk]
+    /// with the different pivot selection, and
+    /// [PivotStrategy::Aasen] computes $A = U^H T U$ with a symmetric/Hermitian tridiagonal $T$.
+    ///
+    /// Unlike [Lapack::bk], this does not fail when $D$ is exactly singular,
+    /// since LAPACK completes the factorization in this case.
+    /// Solving linear equations with such a factorization yields non-finite values.
+    fn ldl(l: MatrixLayout, uplo: UPLO, strategy: PivotStrategy, a: &mut [Self]) -> Result<Pivot>;
+
+    /// Solve symmetric/Hermitian linear equation $Ax = b$ using the result of [Lapack::ldl]
+    fn solve_ldl(
+        l: MatrixLayout,
+        uplo: UPLO,
+        strategy: PivotStrategy,
+        a: &[Self],
+        ipiv: &Pivot,
+        b: &mut [Self],
+    ) -> Result<()>;
+
+    /// Solve symmetric/Hermitian linear equation $Ax = b$ with iterative refinement and error bounds
+    ///
+    /// If the result of [Lapack::bk] is given as `bk`, it is reused. Otherwise $A$ is factorized.
+    /// `b` is overwritten by the solution $x$.
+    fn solveh_expert(
+        l: MatrixLayout,
+        uplo: UPLO,
+        a: &[Self],
+        bk: Option<(&[Self], &Pivot)>,
+        b: &mut [Self],
+    ) -> Result<SolveExpertOwned<Self>>;
+
     /// Solve symmetric/Hermitian positive-definite linear equations using Cholesky decomposition
     ///
     /// For a given positive definite matrix $A$,
@@ -252,11 +596,63 @@ pub trait Lapack: Scalar {
     /// Solve linear equation $Ax = b$ using $U$ or $L$ calculated by [Lapack::cholesky]
     fn solve_cholesky(l: MatrixLayout, uplo: UPLO, a: &[Self], b: &mut [Self]) -> Result<()>;
 
+    /// Solve symmetric/Hermitian positive-definite linear equation $Ax = b$
+    /// with equilibration, iterative refinement, and error bounds
+    ///
+    /// If $U$ or $L$ calculated by [Lapack::cholesky] is given as `factor`, it is reused.
+    /// Otherwise $A$ is equilibrated and factorized, and `a` is overwritten by the equilibrated matrix.
+    /// `b` is overwritten by the solution $x$.
+    fn solve_cholesky_expert(
+        l: MatrixLayout,
+        uplo: UPLO,
+        a: &mut [Self],
+        factor: Option<&[Self]>,
+        b: &mut [Self],
+    ) -> Result<SolveExpertOwned<Self>>;
+
+    /// Compute Cholesky decomposition with complete pivoting $P^T A P = U^H U$ or $P^T A P = L L^H$
+    /// for positive semidefinite matrix
+    ///
+    /// The algorithm stops when the largest remaining diagonal element is not larger than `tol`,
+    /// and the rank is detected by this criterion.
+    /// If `tol` is negative, $n \epsilon \max_k |A_{kk}|$ is used as the default.
+    /// The permutation $P$ is returned as [Pivot] in LAPACK style,
+    /// i.e. the $k$-th column of $P$ is the `piv[k]`-th unit vector (1-based), with the rank.
+    fn cholesky_pivoted(
+        l: MatrixLayout,
+        uplo: UPLO,
+        a: &mut [Self],
+        tol: Self::Real,
+    ) -> Result<(Pivot, i32)>;
+
     /// Estimates the the reciprocal of the condition number of the matrix in 1-norm.
     ///
     /// `anorm` should be the 1-norm of the matrix `a`.
     fn rcond(l: MatrixLayout, a: &[Self], anorm: Self::Real) -> Result<Self::Real>;
 
+    /// Estimates the the reciprocal of the condition number of the matrix in 1-norm
+    /// using the result of [Lapack::cholesky].
+    ///
+    /// `anorm` should be the 1-norm of the original matrix.
+    fn rcond_cholesky(
+        l: MatrixLayout,
+        uplo: UPLO,
+        a: &[Self],
+        anorm: Self::Real,
+    ) -> Result<Self::Real>;
+
+    /// Estimates the the reciprocal of the condition number of the matrix in 1-norm
+    /// using the result of [Lapack::bk].
+    ///
+    /// `anorm` should be the 1-norm of the original matrix.
+    fn rcond_hermitian(
+        l: MatrixLayout,
+        uplo: UPLO,
+        a: &[Self],
+        ipiv: &Pivot,
+        anorm: Self::Real,
+    ) -> Result<Self::Real>;
+
     /// Compute norm of matrices
     ///
     /// For a $n \times m$ matrix
@@ -294,6 +690,11 @@ pub trait Lapack: Scalar {
     ///
     fn opnorm(t: NormType, l: MatrixLayout, a: &[Self]) -> Self::Real;
 
+    /// Compute norm of symmetric/Hermitian matrices using only the triangular part specified by `uplo`
+    ///
+    /// The 1-norm and $\infty$-norm are equal for these matrices.
+    fn opnorm_hermitian(t: NormType, l: MatrixLayout, uplo: UPLO, a: &[Self]) -> Self::Real;
+
     fn solve_triangular(
         al: MatrixLayout,
         bl: MatrixLayout,
@@ -303,6 +704,24 @@ pub trait Lapack: Scalar {
         b: &mut [Self],
     ) -> Result<()>;
 
+    /// Computes the inverse of triangular matrix in place
+    fn inv_triangular(l: MatrixLayout, uplo: UPLO, d: Diag, a: &mut [Self]) -> Result<()>;
+
+    /// Estimates the reciprocal of the condition number of triangular matrix in 1-norm
+    fn rcond_triangular(l: MatrixLayout, uplo: UPLO, d: Diag, a: &[Self]) -> Result<Self::Real>;
+
+    /// Multiplies triangular matrix $A$ to $B$ in place,
+    /// i.e. $B \gets AB$ for [Side::Left] and $B \gets BA$ for [Side::Right]
+    fn multiply_triangular(
+        al: MatrixLayout,
+        bl: MatrixLayout,
+        side: Side,
+        uplo: UPLO,
+        d: Diag,
+        a: &[Self],
+        b: &mut [Self],
+    ) -> Result<()>;
+
     /// Computes the LU factorization of a tridiagonal `m x n` matrix `a` using
     /// partial pivoting with row interchanges.
     fn lu_tridiagonal(a: Tridiagonal<Self>) -> Result<LUFactorizedTridiagonal<Self>>;
@@ -315,6 +734,65 @@ pub trait Lapack: Scalar {
         t: Transpose,
         b: &mut [Self],
     ) -> Result<()>;
+
+    /// Computes the LU factorization of a banded matrix `a` using
+    /// partial pivoting with row interchanges.
+    fn lu_banded(a: Banded<Self>) -> Result<LUFactorizedBanded<Self>>;
+
+    /// Estimates the reciprocal of the condition number of a banded matrix in 1-norm
+    /// using its LU factorization
+    fn rcond_banded(lu: &LUFactorizedBanded<Self>) -> Result<Self::Real>;
+
+    /// Solves a linear equation using the LU factorization of a banded matrix
+    fn solve_banded(
+        lu: &LUFactorizedBanded<Self>,
+        bl: MatrixLayout,
+        t: Transpose,
+        b: &mut [Self],
+    ) -> Result<()>;
+
+    /// Computes operator norm of a banded matrix
+    fn opnorm_banded(t: NormType, a: &Banded<Self>) -> Self::Real;
+
+    /// Computes the Cholesky factorization of a positive definite banded matrix
+    ///
+    /// Only the super-diagonals (for [UPLO::Upper]) or the sub-diagonals (for [UPLO::Lower])
+    /// of `a` are referenced.
+    fn cholesky_banded(uplo: UPLO, a: &Banded<Self>) -> Result<CholeskyFactorizedBanded<Self>>;
+
+    /// Solves a linear equation using the Cholesky factorization of a banded matrix
+    fn solve_cholesky_banded(
+        f: &CholeskyFactorizedBanded<Self>,
+        bl: MatrixLayout,
+        b: &mut [Self],
+    ) -> Result<()>;
+
+    /// Computes the Cholesky factorization of a positive definite matrix in packed storage
+    ///
+    /// The triangular factor overwrites `a`.
+    fn cholesky_packed(a: &mut Packed<Self>) -> Result<()>;
+
+    /// Computes the inverse of a positive definite matrix in packed storage
+    /// using its Cholesky factorization computed by [Lapack::cholesky_packed]
+    fn inv_cholesky_packed(a: &mut Packed<Self>) -> Result<()>;
+
+    /// Solves a linear equation using the Cholesky factorization in packed storage
+    /// computed by [Lapack::cholesky_packed]
+    fn solve_cholesky_packed(a: &Packed<Self>, bl: MatrixLayout, b: &mut [Self]) -> Result<()>;
+
+    /// Computes the Bunch-Kaufman factorization of a symmetric/Hermitian matrix in packed storage
+    ///
+    /// The factorization overwrites `a`, and the pivot indices are returned.
+    fn bk_packed(a: &mut Packed<Self>) -> Result<Pivot>;
+
+    /// Solves a linear equation using the Bunch-Kaufman factorization in packed storage
+    /// computed by [Lapack::bk_packed]
+    fn solveh_packed(
+        a: &Packed<Self>,
+        ipiv: &Pivot,
+        bl: MatrixLayout,
+        b: &mut [Self],
+    ) -> Result<()>;
 }
 
 macro_rules! impl_lapack {
@@ -331,6 +809,21 @@ macro_rules! impl_lapack {
                 Ok((eigs, vr.or(vl).unwrap_or_default()))
             }
 
+            fn eig_left_right(l: MatrixLayout, a: &mut [Self]) -> Result<EigOwned<Self>> {
+                use eig::*;
+                let work = EigWork::<$s>::new_left_right(l)?;
+                work.eval(a)
+            }
+
+            fn eig_expert(
+                balance: Balance,
+                l: MatrixLayout,
+                a: &mut [Self],
+            ) -> Result<EigExpertOwned<Self>> {
+                use eig::*;
+                EigExpertImpl::eig_expert(balance, l, a)
+            }
+
             fn eigh(
                 calc_eigenvec: bool,
                 layout: MatrixLayout,
@@ -342,6 +835,94 @@ macro_rules! impl_lapack {
                 work.eval(uplo, a)
             }
 
+            fn eigh_with_driver(
+                calc_eigenvec: bool,
+                layout: MatrixLayout,
+                uplo: UPLO,
+                driver: EighDriver,
+                a: &mut [Self],
+            ) -> Result<Vec<Self::Real>> {
+                match driver {
+                    EighDriver::QR => Self::eigh(calc_eigenvec, layout, uplo, a),
+                    EighDriver::DivideAndConquer => {
+                        use eighdc::*;
+                        let work = EighDcWork::<$s>::new(calc_eigenvec, layout)?;
+                        work.eval(uplo, a)
+                    }
+                    EighDriver::RRR => {
+                        use eigh_range::*;
+                        let n = layout.len();
+                        // Same as other drivers, `a` is regarded as column-major
+                        let layout = MatrixLayout::F { col: n, lda: n };
+                        let work = EighRangeWork::<$s>::new(calc_eigenvec, layout, EigRange::All)?;
+                        let EighRangeOwned { eigs, z } = work.eval(uplo, a)?;
+                        if let Some(z) = z {
+                            a.copy_from_slice(&z);
+                        }
+                        Ok(eigs)
+                    }
+                }
+            }
+
+            fn eigh_range(
+                calc_eigenvec: bool,
+                layout: MatrixLayout,
+                uplo: UPLO,
+                range: EigRange<Self::Real>,
+                a: &mut [Self],
+            ) -> Result<EighRangeOwned<Self>> {
+                use eigh_range::*;
+                let work = EighRangeWork::<$s>::new(calc_eigenvec, layout, range)?;
+                work.eval(uplo, a)
+            }
+
+            fn eigh_banded(
+                calc_eigenvec: bool,
+                uplo: UPLO,
+                a: &Banded<Self>,
+            ) -> Result<EighRangeOwned<Self>> {
+                use eigh_banded::*;
+                let work = EighBandedWork::<$s>::new(calc_eigenvec, a.l)?;
+                work.eval(uplo, a)
+            }
+
+            fn eigh_banded_range(
+                calc_eigenvec: bool,
+                uplo: UPLO,
+                range: EigRange<Self::Real>,
+                a: &Banded<Self>,
+            ) -> Result<EighRangeOwned<Self>> {
+                use eigh_banded::*;
+                let work = EighBandedRangeWork::<$s>::new(calc_eigenvec, a.l, range)?;
+                work.eval(uplo, a)
+            }
+
+            fn eigh_packed(
+                calc_eigenvec: bool,
+                a: &mut Packed<Self>,
+            ) -> Result<EighRangeOwned<Self>> {
+                use eigh_packed::*;
+                let work = EighPackedWork::<$s>::new(calc_eigenvec, a.l)?;
+                work.eval(a)
+            }
+
+            fn eigh_tridiagonal(
+                calc_eigenvec: bool,
+                range: EigRange<Self::Real>,
+                a: &Tridiagonal<Self>,
+            ) -> Result<EighRangeOwned<Self>> {
+                use tridiagonal::*;
+                EighTridiagonalImpl::eigh_tridiagonal(calc_eigenvec, range, a)
+            }
+
+            fn eigh_tridiagonal_inverse_iteration(
+                range: EigRange<Self::Real>,
+                a: &Tridiagonal<Self>,
+            ) -> Result<EighRangeOwned<Self>> {
+                use tridiagonal::*;
+                EighTridiagonalImpl::eigh_tridiagonal_inverse_iteration(range, a)
+            }
+
             fn eigh_generalized(
                 calc_eigenvec: bool,
                 layout: MatrixLayout,
@@ -354,6 +935,129 @@ macro_rules! impl_lapack {
                 work.eval(uplo, a, b)
             }
 
+            fn eigh_generalized_with_driver(
+                calc_eigenvec: bool,
+                layout: MatrixLayout,
+                uplo: UPLO,
+                driver: EighDriver,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<Vec<Self::Real>> {
+                match driver {
+                    EighDriver::QR => Self::eigh_generalized(calc_eigenvec, layout, uplo, a, b),
+                    EighDriver::DivideAndConquer => {
+                        use eighdc::*;
+                        let work = EighGeneralizedDcWork::<$s>::new(calc_eigenvec, layout)?;
+                        work.eval(uplo, a, b)
+                    }
+                    EighDriver::RRR => {
+                        use eigh_generalized::*;
+                        Self::eigh_generalized_rrr(calc_eigenvec, layout, uplo, a, b)
+                    }
+                }
+            }
+
+            fn eig_generalized(
+                calc_v: bool,
+                l: MatrixLayout,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<EigGeneralizedOwned<Self>> {
+                use eig_generalized::*;
+                let work = EigGeneralizedWork::<$s>::new(calc_v, l)?;
+                let EigGeneralizedOwned {
+                    alpha,
+                    beta,
+                    vr,
+                    vl,
+                } = work.eval(a, b)?;
+                // Right eigenvectors of a row-major pair are computed as the left ones of the transposed pair
+                Ok(EigGeneralizedOwned {
+                    alpha,
+                    beta,
+                    vr: vr.or(vl),
+                    vl: None,
+                })
+            }
+
+            fn schur(calc_v: bool, l: MatrixLayout, a: &mut [Self]) -> Result<SchurOwned<Self>> {
+                use schur::*;
+                let work = SchurWork::<$s>::new(calc_v, l)?;
+                work.eval(a)
+            }
+
+            fn sylvester(
+                l: MatrixLayout,
+                trana: Transpose,
+                tranb: Transpose,
+                isgn: i32,
+                a: &[Self],
+                b: &[Self],
+                c: &mut [Self],
+            ) -> Result<Self::Real> {
+                use sylvester::*;
+                SylvesterImpl::sylvester(l, trana, tranb, isgn, a, b, c)
+            }
+
+            fn schur_reorder(
+                l: MatrixLayout,
+                select: &[bool],
+                t: &mut [Self],
+                z: &mut [Self],
+            ) -> Result<(Vec<Self::Complex>, i32)> {
+                use schur::*;
+                SchurReorderImpl::schur_reorder(l, select, t, z)
+            }
+
+            fn schur_generalized(
+                calc_v: bool,
+                l: MatrixLayout,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<SchurGeneralizedOwned<Self>> {
+                use schur_generalized::*;
+                let work = SchurGeneralizedWork::<$s>::new(calc_v, l)?;
+                work.eval(a, b)
+            }
+
+            fn schur_generalized_reorder(
+                l: MatrixLayout,
+                select: &[bool],
+                s: &mut [Self],
+                t: &mut [Self],
+                q: &mut [Self],
+                z: &mut [Self],
+            ) -> Result<(Vec<Self::Complex>, Vec<Self>, i32)> {
+                use schur_generalized::*;
+                SchurGeneralizedReorderImpl::schur_generalized_reorder(l, select, s, t, q, z)
+            }
+
+            fn bidiagonal(l: MatrixLayout, a: &mut [Self]) -> Result<BidiagonalOwned<Self>> {
+                use bidiagonal::*;
+                let work = BidiagonalWork::<$s>::new(l)?;
+                work.eval(a)
+            }
+
+            fn bidiagonal_q(l: MatrixLayout, a: &[Self], tauq: &[Self]) -> Result<Vec<Self>> {
+                use bidiagonal::*;
+                BidiagonalQImpl::bidiagonal_q(l, a, tauq)
+            }
+
+            fn bidiagonal_pt(l: MatrixLayout, a: &[Self], taup: &[Self]) -> Result<Vec<Self>> {
+                use bidiagonal::*;
+                BidiagonalQImpl::bidiagonal_pt(l, a, taup)
+            }
+
+            fn svd_bidiagonal(calc_uvt: bool, b: &Bidiagonal<Self>) -> Result<SvdOwned<Self>> {
+                use bidiagonal::*;
+                SvdBidiagonalImpl::svd_bidiagonal(calc_uvt, b)
+            }
+
+            fn svddc_bidiagonal(calc_uvt: bool, b: &Bidiagonal<Self>) -> Result<SvdOwned<Self>> {
+                use bidiagonal::*;
+                SvdBidiagonalImpl::svddc_bidiagonal(calc_uvt, b)
+            }
+
             fn householder(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>> {
                 use qr::*;
                 let work = HouseholderWork::<$s>::new(l)?;
@@ -374,6 +1078,24 @@ macro_rules! impl_lapack {
                 Ok(r)
             }
 
+            fn householder_pivoted(l: MatrixLayout, a: &mut [Self]) -> Result<(Vec<Self>, Pivot)> {
+                use qr::*;
+                let work = HouseholderPivotedWork::<$s>::new(l)?;
+                work.eval(a)
+            }
+
+            fn hessenberg(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>> {
+                use hessenberg::*;
+                let work = HessenbergWork::<$s>::new(l)?;
+                work.eval(a)
+            }
+
+            fn hessenberg_q(l: MatrixLayout, a: &mut [Self], tau: &[Self]) -> Result<()> {
+                use hessenberg::*;
+                let mut work = HessenbergQWork::<$s>::new(l)?;
+                work.calc(a, tau)
+            }
+
             fn svd(
                 l: MatrixLayout,
                 calc_u: bool,
@@ -391,6 +1113,20 @@ macro_rules! impl_lapack {
                 work.eval(a)
             }
 
+            fn ggsvd3(
+                calc_u: bool,
+                calc_v: bool,
+                calc_q: bool,
+                a_layout: MatrixLayout,
+                a: &mut [Self],
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<GsvdOwned<Self>> {
+                use gsvd::*;
+                let work = GsvdWork::<$s>::new(a_layout, b_layout, calc_u, calc_v, calc_q)?;
+                work.eval(a, b)
+            }
+
             fn least_squares(
                 l: MatrixLayout,
                 a: &mut [Self],
@@ -411,6 +1147,18 @@ macro_rules! impl_lapack {
                 work.eval(a, b)
             }
 
+            fn least_squares_nrhs_with_options(
+                a_layout: MatrixLayout,
+                a: &mut [Self],
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+                options: LeastSquaresOptions<Self::Real>,
+            ) -> Result<LeastSquaresOwned<Self>> {
+                use least_squares::*;
+                let work = LeastSquaresWork::<$s>::new_with_options(a_layout, b_layout, options)?;
+                work.eval(a, b)
+            }
+
             fn lu(l: MatrixLayout, a: &mut [Self]) -> Result<Pivot> {
                 use solve::*;
                 LuImpl::lu(l, a)
@@ -434,6 +1182,17 @@ macro_rules! impl_lapack {
                 SolveImpl::solve(l, t, a, p, b)
             }
 
+            fn solve_expert(
+                l: MatrixLayout,
+                t: Transpose,
+                a: &mut [Self],
+                lu: Option<(&[Self], &Pivot)>,
+                b: &mut [Self],
+            ) -> Result<SolveExpertOwned<Self>> {
+                use solve::*;
+                SolveExpertImpl::solve_expert(l, t, a, lu, b)
+            }
+
             fn bk(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<Pivot> {
                 use solveh::*;
                 let work = BkWork::<$s>::new(l)?;
@@ -457,6 +1216,45 @@ macro_rules! impl_lapack {
                 SolvehImpl::solveh(l, uplo, a, ipiv, b)
             }
 
+            fn ldl(
+                l: MatrixLayout,
+                uplo: UPLO,
+                strategy: PivotStrategy,
+                a: &mut [Self],
+            ) -> Result<Pivot> {
+                use solveh::*;
+                let mut work = BkWork::<$s>::new_with_strategy(l, strategy)?;
+                // `info > 0` means that `D` is exactly singular, but the factorization is completed
+                match work.calc(uplo, a) {
+                    Ok(_) | Err(Error::LapackComputationalFailure { .. }) => {}
+                    Err(e) => return Err(e),
+                }
+                Ok(unsafe { work.ipiv.assume_init() })
+            }
+
+            fn solve_ldl(
+                l: MatrixLayout,
+                uplo: UPLO,
+                strategy: PivotStrategy,
+                a: &[Self],
+                ipiv: &Pivot,
+                b: &mut [Self],
+            ) -> Result<()> {
+                use solveh::*;
+                SolveLdlImpl::solve_ldl(l, uplo, strategy, a, ipiv, b)
+            }
+
+            fn solveh_expert(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &[Self],
+                bk: Option<(&[Self], &Pivot)>,
+                b: &mut [Self],
+            ) -> Result<SolveExpertOwned<Self>> {
+                use solveh::*;
+                SolvehExpertImpl::solveh_expert(l, uplo, a, bk, b)
+            }
+
             fn cholesky(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<()> {
                 use cholesky::*;
                 CholeskyImpl::cholesky(l, uplo, a)
@@ -477,18 +1275,70 @@ macro_rules! impl_lapack {
                 SolveCholeskyImpl::solve_cholesky(l, uplo, a, b)
             }
 
+            fn solve_cholesky_expert(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &mut [Self],
+                factor: Option<&[Self]>,
+                b: &mut [Self],
+            ) -> Result<SolveExpertOwned<Self>> {
+                use cholesky::*;
+                SolveCholeskyExpertImpl::solve_cholesky_expert(l, uplo, a, factor, b)
+            }
+
+            fn cholesky_pivoted(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &mut [Self],
+                tol: Self::Real,
+            ) -> Result<(Pivot, i32)> {
+                use cholesky::*;
+                CholeskyPivotedImpl::cholesky_pivoted(l, uplo, a, tol)
+            }
+
             fn rcond(l: MatrixLayout, a: &[Self], anorm: Self::Real) -> Result<Self::Real> {
                 use rcond::*;
                 let mut work = RcondWork::<$s>::new(l);
                 work.calc(a, anorm)
             }
 
+            fn rcond_cholesky(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &[Self],
+                anorm: Self::Real,
+            ) -> Result<Self::Real> {
+                use rcond::*;
+                RcondCholeskyImpl::rcond_cholesky(l, uplo, a, anorm)
+            }
+
+            fn rcond_hermitian(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &[Self],
+                ipiv: &Pivot,
+                anorm: Self::Real,
+            ) -> Result<Self::Real> {
+                use rcond::*;
+                RcondHermitianImpl::rcond_hermitian(l, uplo, a, ipiv, anorm)
+            }
+
             fn opnorm(t: NormType, l: MatrixLayout, a: &[Self]) -> Self::Real {
                 use opnorm::*;
                 let mut work = OperatorNormWork::<$s>::new(t, l);
                 work.calc(a)
             }
 
+            fn opnorm_hermitian(
+                t: NormType,
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &[Self],
+            ) -> Self::Real {
+                use opnorm::*;
+                OperatorNormHermitianImpl::opnorm_hermitian(t, l, uplo, a)
+            }
+
             fn solve_triangular(
                 al: MatrixLayout,
                 bl: MatrixLayout,
@@ -501,6 +1351,34 @@ macro_rules! impl_lapack {
                 SolveTriangularImpl::solve_triangular(al, bl, uplo, d, a, b)
             }
 
+            fn inv_triangular(l: MatrixLayout, uplo: UPLO, d: Diag, a: &mut [Self]) -> Result<()> {
+                use triangular::*;
+                InvTriangularImpl::inv_triangular(l, uplo, d, a)
+            }
+
+            fn rcond_triangular(
+                l: MatrixLayout,
+                uplo: UPLO,
+                d: Diag,
+                a: &[Self],
+            ) -> Result<Self::Real> {
+                use triangular::*;
+                RcondTriangularImpl::rcond_triangular(l, uplo, d, a)
+            }
+
+            fn multiply_triangular(
+                al: MatrixLayout,
+                bl: MatrixLayout,
+                side: Side,
+                uplo: UPLO,
+                d: Diag,
+                a: &[Self],
+                b: &mut [Self],
+            ) -> Result<()> {
+                use triangular::*;
+                MultiplyTriangularImpl::multiply_triangular(al, bl, side, uplo, d, a, b)
+            }
+
             fn lu_tridiagonal(a: Tridiagonal<Self>) -> Result<LUFactorizedTridiagonal<Self>> {
                 use tridiagonal::*;
                 let work = LuTridiagonalWork::<$s>::new(a.l);
@@ -522,6 +1400,84 @@ macro_rules! impl_lapack {
                 use tridiagonal::*;
                 SolveTridiagonalImpl::solve_tridiagonal(lu, bl, t, b)
             }
+
+            fn lu_banded(a: Banded<Self>) -> Result<LUFactorizedBanded<Self>> {
+                use banded::*;
+                let work = LuBandedWork::<$s>::new(a.l, a.kl, a.ku);
+                work.eval(a)
+            }
+
+            fn rcond_banded(lu: &LUFactorizedBanded<Self>) -> Result<Self::Real> {
+                use banded::*;
+                let mut work = RcondBandedWork::<$s>::new(lu.l);
+                work.calc(lu)
+            }
+
+            fn solve_banded(
+                lu: &LUFactorizedBanded<Self>,
+                bl: MatrixLayout,
+                t: Transpose,
+                b: &mut [Self],
+            ) -> Result<()> {
+                use banded::*;
+                SolveBandedImpl::solve_banded(lu, bl, t, b)
+            }
+
+            fn opnorm_banded(t: NormType, a: &Banded<Self>) -> Self::Real {
+                use banded::*;
+                OperatorNormBandedImpl::opnorm_banded(t, a)
+            }
+
+            fn cholesky_banded(
+                uplo: UPLO,
+                a: &Banded<Self>,
+            ) -> Result<CholeskyFactorizedBanded<Self>> {
+                use banded::*;
+                CholeskyBandedImpl::cholesky_banded(uplo, a)
+            }
+
+            fn solve_cholesky_banded(
+                f: &CholeskyFactorizedBanded<Self>,
+                bl: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<()> {
+                use banded::*;
+                SolveCholeskyBandedImpl::solve_cholesky_banded(f, bl, b)
+            }
+
+            fn cholesky_packed(a: &mut Packed<Self>) -> Result<()> {
+                use packed::*;
+                CholeskyPackedImpl::cholesky_packed(a)
+            }
+
+            fn inv_cholesky_packed(a: &mut Packed<Self>) -> Result<()> {
+                use packed::*;
+                InvCholeskyPackedImpl::inv_cholesky_packed(a)
+            }
+
+            fn solve_cholesky_packed(
+                a: &Packed<Self>,
+                bl: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<()> {
+                use packed::*;
+                SolveCholeskyPackedImpl::solve_cholesky_packed(a, bl, b)
+            }
+
+            fn bk_packed(a: &mut Packed<Self>) -> Result<Pivot> {
+                use packed::*;
+                BkPackedImpl::bk_packed(a)
+            }
+
+            fn solveh_packed(
+                a: &Packed<Self>,
+                ipiv: &Pivot,
+                bl: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<()> {
+                use packed::*;
+                SolvehPackedImpl::solveh_packed(a, ipiv, bl, b)
+            }
         }
     };
 }
@@ -529,3 +1485,83 @@ impl_lapack!(c64);
 impl_lapack!(c32);
 impl_lapack!(f64);
 impl_lapack!(f32);
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Trait for double precision types which implements mixed-precision LAPACK subroutines
+///
+/// This trait is implemented only for [f64] and [c64],
+/// since the factorization is computed in the corresponding single precision type.
+pub trait LapackMixed: Lapack {
+    /// Solve linear equations $Ax = b$ using LU-decomposition in single precision
+    /// with iterative refinement in double precision
+    ///
+    /// `a` is used as workspace and its contents are unspecified after the call.
+    /// `b` is overwritten by the solution $x$.
+    /// If the refinement does not converge, the system is solved by LU-decomposition
+    /// in double precision, and [Refinement::FullPrecision] is returned.
+    fn solve_mixed(l: MatrixLayout, a: &mut [Self], b: &mut [Self]) -> Result<Refinement>;
+
+    /// Solve symmetric/Hermitian positive definite linear equations $Ax = b$
+    /// using Cholesky decomposition in single precision with iterative refinement in double precision
+    ///
+    /// `a` is used as workspace and its contents are unspecified after the call.
+    /// `b` is overwritten by the solution $x$.
+    /// If the refinement does not converge, the system is solved by Cholesky decomposition
+    /// in double precision, and [Refinement::FullPrecision] is returned.
+    fn solve_cholesky_mixed(
+        l: MatrixLayout,
+        uplo: UPLO,
+        a: &mut [Self],
+        b: &mut [Self],
+    ) -> Result<Refinement>;
+}
+
+macro_rules! impl_lapack_mixed {
+    ($s:ty) => {
+        impl LapackMixed for $s {
+            fn solve_mixed(l: MatrixLayout, a: &mut [Self], b: &mut [Self]) -> Result<Refinement> {
+                use solve_mixed::*;
+                SolveMixedImpl::solve_mixed(l, a, b)
+            }
+
+            fn solve_cholesky_mixed(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<Refinement> {
+                use solve_mixed::*;
+                SolveCholeskyMixedImpl::solve_cholesky_mixed(l, uplo, a, b)
+            }
+        }
+    };
+}
+impl_lapack_mixed!(c64);
+impl_lapack_mixed!(f64);
+
+/// Trait for real types which implements LAPACK subroutines keeping the real representation of results
+///
+/// This trait is implemented only for [f32] and [f64].
+pub trait LapackReal: Lapack {
+    /// Compute eigenvalues and the right eigenvectors of a general real matrix
+    /// without converting them into complex numbers, see [EigRealOwned]
+    fn eig_real(calc_v: bool, l: MatrixLayout, a: &mut [Self]) -> Result<EigRealOwned<Self>>;
+}
+
+macro_rules! impl_lapack_real {
+    ($s:ty) => {
+        impl LapackReal for $s {
+            fn eig_real(
+                calc_v: bool,
+                l: MatrixLayout,
+                a: &mut [Self],
+            ) -> Result<EigRealOwned<Self>> {
+                use eig::*;
+                let work = EigWork::<$s>::new(calc_v, l)?;
+                work.eval_real(a)
+            }
+        }
+    };
+}
+impl_lapack_real!(f64);
+impl_lapack_real!(f32);
@@ -56,3 +56,56 @@ impl_operator_norm!(c64, lapack_sys::zlange_);
 impl_operator_norm!(c32, lapack_sys::clange_);
 impl_operator_norm!(f64, lapack_sys::dlange_);
 impl_operator_norm!(f32, lapack_sys::slange_);
+
+/// Compute operator norm of symmetric/Hermitian matrix
+///
+/// Only the triangular part specified by [UPLO] is referenced.
+/// Row-major matrix is handled in the same manner as [crate::cholesky::CholeskyImpl].
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | slansy | dlansy | clanhe | zlanhe |
+///
+pub trait OperatorNormHermitianImpl: Scalar {
+    fn opnorm_hermitian(t: NormType, l: MatrixLayout, uplo: UPLO, a: &[Self]) -> Self::Real;
+}
+
+macro_rules! impl_operator_norm_hermitian {
+    ($s:ty, $lanhe:path) => {
+        impl OperatorNormHermitianImpl for $s {
+            fn opnorm_hermitian(
+                t: NormType,
+                l: MatrixLayout,
+                mut uplo: UPLO,
+                a: &[Self],
+            ) -> Self::Real {
+                let (n, _) = l.size();
+                // Transpose of Hermitian matrix is its conjugate, which has the same norm
+                if matches!(l, MatrixLayout::C { .. }) {
+                    uplo = uplo.t();
+                }
+                let mut work: Vec<MaybeUninit<Self::Real>> = match t {
+                    NormType::One | NormType::Infinity => vec_uninit(n as usize),
+                    NormType::Frobenius => Vec::new(),
+                };
+                unsafe {
+                    $lanhe(
+                        t.as_ptr(),
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_ptr(a),
+                        &l.lda(),
+                        AsPtr::as_mut_ptr(&mut work),
+                    )
+                }
+            }
+        }
+    };
+}
+impl_operator_norm_hermitian!(c64, lapack_sys::zlanhe_);
+impl_operator_norm_hermitian!(c32, lapack_sys::clanhe_);
+impl_operator_norm_hermitian!(f64, lapack_sys::dlansy_);
+impl_operator_norm_hermitian!(f32, lapack_sys::slansy_);
@@ -0,0 +1,129 @@
+use crate::{error::*, layout::*, *};
+use cauchy::*;
+
+/// Compute Cholesky decomposition of positive definite matrix in packed storage
+///
+/// The triangular factor `U` or `L` overwrites `a` according to `a.uplo`.
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | spptrf | dpptrf | cpptrf | zpptrf |
+///
+pub trait CholeskyPackedImpl: Scalar {
+    fn cholesky_packed(a: &mut Packed<Self>) -> Result<()>;
+}
+
+macro_rules! impl_cholesky_packed {
+    ($s:ty, $trf:path) => {
+        impl CholeskyPackedImpl for $s {
+            fn cholesky_packed(a: &mut Packed<Self>) -> Result<()> {
+                let n = a.l.len();
+                let mut info = 0;
+                unsafe {
+                    $trf(a.uplo.as_ptr(), &n, AsPtr::as_mut_ptr(&mut a.ap), &mut info);
+                }
+                info.as_lapack_result()?;
+                Ok(())
+            }
+        }
+    };
+}
+impl_cholesky_packed!(c64, lapack_sys::zpptrf_);
+impl_cholesky_packed!(c32, lapack_sys::cpptrf_);
+impl_cholesky_packed!(f64, lapack_sys::dpptrf_);
+impl_cholesky_packed!(f32, lapack_sys::spptrf_);
+
+/// Compute inverse matrix using Cholesky factorization result in packed storage
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | spptri | dpptri | cpptri | zpptri |
+///
+pub trait InvCholeskyPackedImpl: Scalar {
+    fn inv_cholesky_packed(a: &mut Packed<Self>) -> Result<()>;
+}
+
+macro_rules! impl_inv_cholesky_packed {
+    ($s:ty, $tri:path) => {
+        impl InvCholeskyPackedImpl for $s {
+            fn inv_cholesky_packed(a: &mut Packed<Self>) -> Result<()> {
+                let n = a.l.len();
+                let mut info = 0;
+                unsafe {
+                    $tri(a.uplo.as_ptr(), &n, AsPtr::as_mut_ptr(&mut a.ap), &mut info);
+                }
+                info.as_lapack_result()?;
+                Ok(())
+            }
+        }
+    };
+}
+impl_inv_cholesky_packed!(c64, lapack_sys::zpptri_);
+impl_inv_cholesky_packed!(c32, lapack_sys::cpptri_);
+impl_inv_cholesky_packed!(f64, lapack_sys::dpptri_);
+impl_inv_cholesky_packed!(f32, lapack_sys::spptri_);
+
+/// Solve linear equation using Cholesky factorization result in packed storage
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | spptrs | dpptrs | cpptrs | zpptrs |
+///
+pub trait SolveCholeskyPackedImpl: Scalar {
+    fn solve_cholesky_packed(a: &Packed<Self>, bl: MatrixLayout, b: &mut [Self]) -> Result<()>;
+}
+
+macro_rules! impl_solve_cholesky_packed {
+    ($s:ty, $trs:path) => {
+        impl SolveCholeskyPackedImpl for $s {
+            fn solve_cholesky_packed(
+                a: &Packed<Self>,
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<()> {
+                let n = a.l.len();
+                // Transpose if b is C-continuous
+                let mut b_t = None;
+                let b_layout = match b_layout {
+                    MatrixLayout::C { .. } => {
+                        let (layout, t) = transpose(b_layout, b);
+                        b_t = Some(t);
+                        layout
+                    }
+                    MatrixLayout::F { .. } => b_layout,
+                };
+                let (ldb, nrhs) = b_layout.size();
+                let mut info = 0;
+                unsafe {
+                    $trs(
+                        a.uplo.as_ptr(),
+                        &n,
+                        &nrhs,
+                        AsPtr::as_ptr(&a.ap),
+                        AsPtr::as_mut_ptr(b_t.as_mut().map(|v| v.as_mut_slice()).unwrap_or(b)),
+                        &ldb,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                if let Some(b_t) = b_t {
+                    transpose_over(b_layout, &b_t, b);
+                }
+                Ok(())
+            }
+        }
+    };
+}
+impl_solve_cholesky_packed!(c64, lapack_sys::zpptrs_);
+impl_solve_cholesky_packed!(c32, lapack_sys::cpptrs_);
+impl_solve_cholesky_packed!(f64, lapack_sys::dpptrs_);
+impl_solve_cholesky_packed!(f32, lapack_sys::spptrs_);
@@ -0,0 +1,93 @@
+use crate::{layout::*, UPLO};
+use cauchy::*;
+use std::ops::{Index, IndexMut};
+
+/// Represents a symmetric/Hermitian matrix in LAPACK packed storage.
+///
+/// Only the triangular part specified by `uplo` is stored column by column
+/// in a vector of `n (n + 1) / 2` elements, i.e.
+/// `ap[i + j (j + 1) / 2] = a[i, j]` for `i <= j` with [UPLO::Upper],
+/// and `ap[i + j (2n - j - 1) / 2] = a[i, j]` for `j <= i` with [UPLO::Lower].
+/// For example, when `n = 3`,
+///
+/// ```text
+/// UPLO::Upper: a00, a01, a11, a02, a12, a22
+/// UPLO::Lower: a00, a10, a20, a11, a21, a22
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Packed<A: Scalar> {
+    /// layout of raw matrix
+    pub l: MatrixLayout,
+    /// which triangular part is stored
+    pub uplo: UPLO,
+    /// `n (n + 1) / 2` elements of packed storage
+    pub ap: Vec<A>,
+}
+
+impl<A: Scalar> Packed<A> {
+    /// Number of elements of packed storage for `n x n` matrix, i.e. `n (n + 1) / 2`
+    pub fn packed_len(n: i32) -> usize {
+        let n = n as usize;
+        n * (n + 1) / 2
+    }
+
+    fn position(&self, row: i32, col: i32) -> usize {
+        let n = self.l.len();
+        assert!(
+            std::cmp::max(row, col) < n,
+            "ndarray: index {:?} is out of bounds for array of shape {}",
+            [row, col],
+            n
+        );
+        let (row, col, n) = (row as usize, col as usize, n as usize);
+        match self.uplo {
+            UPLO::Upper => {
+                assert!(
+                    row <= col,
+                    "ndarray-linalg::packed: index {:?} is not in upper triangle",
+                    [row, col]
+                );
+                row + col * (col + 1) / 2
+            }
+            UPLO::Lower => {
+                assert!(
+                    col <= row,
+                    "ndarray-linalg::packed: index {:?} is not in lower triangle",
+                    [row, col]
+                );
+                row + col * (2 * n - col - 1) / 2
+            }
+        }
+    }
+}
+
+impl<A: Scalar> Index<(i32, i32)> for Packed<A> {
+    type Output = A;
+    #[inline]
+    fn index(&self, (row, col): (i32, i32)) -> &A {
+        &self.ap[self.position(row, col)]
+    }
+}
+
+impl<A: Scalar> Index<[i32; 2]> for Packed<A> {
+    type Output = A;
+    #[inline]
+    fn index(&self, [row, col]: [i32; 2]) -> &A {
+        &self[(row, col)]
+    }
+}
+
+impl<A: Scalar> IndexMut<(i32, i32)> for Packed<A> {
+    #[inline]
+    fn index_mut(&mut self, (row, col): (i32, i32)) -> &mut A {
+        let pos = self.position(row, col);
+        &mut self.ap[pos]
+    }
+}
+
+impl<A: Scalar> IndexMut<[i32; 2]> for Packed<A> {
+    #[inline]
+    fn index_mut(&mut self, [row, col]: [i32; 2]) -> &mut A {
+        &mut self[(row, col)]
+    }
+}
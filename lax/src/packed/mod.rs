@@ -0,0 +1,10 @@
+//! Implement linear solver using Cholesky and Bunch-Kaufman decomposition
+//! for symmetric/Hermitian matrix in packed storage
+
+mod cholesky;
+mod matrix;
+mod solveh;
+
+pub use cholesky::*;
+pub use matrix::*;
+pub use solveh::*;
@@ -0,0 +1,110 @@
+use crate::{error::*, layout::*, *};
+use cauchy::*;
+
+/// Factorize symmetric/Hermitian matrix in packed storage using Bunch-Kaufman diagonal pivoting method
+///
+/// The block diagonal matrix `D` and the multipliers overwrite `a`.
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | ssptrf | dsptrf | chptrf | zhptrf |
+///
+pub trait BkPackedImpl: Scalar {
+    fn bk_packed(a: &mut Packed<Self>) -> Result<Pivot>;
+}
+
+macro_rules! impl_bk_packed {
+    ($s:ty, $trf:path) => {
+        impl BkPackedImpl for $s {
+            fn bk_packed(a: &mut Packed<Self>) -> Result<Pivot> {
+                let n = a.l.len();
+                let mut ipiv = vec_uninit(n as usize);
+                let mut info = 0;
+                unsafe {
+                    $trf(
+                        a.uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut a.ap),
+                        AsPtr::as_mut_ptr(&mut ipiv),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(unsafe { ipiv.assume_init() })
+            }
+        }
+    };
+}
+impl_bk_packed!(c64, lapack_sys::zhptrf_);
+impl_bk_packed!(c32, lapack_sys::chptrf_);
+impl_bk_packed!(f64, lapack_sys::dsptrf_);
+impl_bk_packed!(f32, lapack_sys::ssptrf_);
+
+/// Solve symmetric/Hermitian linear equation using Bunch-Kaufman factorization result in packed storage
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | ssptrs | dsptrs | chptrs | zhptrs |
+///
+pub trait SolvehPackedImpl: Scalar {
+    fn solveh_packed(
+        a: &Packed<Self>,
+        ipiv: &Pivot,
+        bl: MatrixLayout,
+        b: &mut [Self],
+    ) -> Result<()>;
+}
+
+macro_rules! impl_solveh_packed {
+    ($s:ty, $trs:path) => {
+        impl SolvehPackedImpl for $s {
+            fn solveh_packed(
+                a: &Packed<Self>,
+                ipiv: &Pivot,
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<()> {
+                let n = a.l.len();
+                // Transpose if b is C-continuous
+                let mut b_t = None;
+                let b_layout = match b_layout {
+                    MatrixLayout::C { .. } => {
+                        let (layout, t) = transpose(b_layout, b);
+                        b_t = Some(t);
+                        layout
+                    }
+                    MatrixLayout::F { .. } => b_layout,
+                };
+                let (ldb, nrhs) = b_layout.size();
+                let mut info = 0;
+                unsafe {
+                    $trs(
+                        a.uplo.as_ptr(),
+                        &n,
+                        &nrhs,
+                        AsPtr::as_ptr(&a.ap),
+                        ipiv.as_ptr(),
+                        AsPtr::as_mut_ptr(b_t.as_mut().map(|v| v.as_mut_slice()).unwrap_or(b)),
+                        &ldb,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                if let Some(b_t) = b_t {
+                    transpose_over(b_layout, &b_t, b);
+                }
+                Ok(())
+            }
+        }
+    };
+}
+impl_solveh_packed!(c64, lapack_sys::zhptrs_);
+impl_solveh_packed!(c32, lapack_sys::chptrs_);
+impl_solveh_packed!(f64, lapack_sys::dsptrs_);
+impl_solveh_packed!(f32, lapack_sys::ssptrs_);
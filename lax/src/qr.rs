@@ -214,3 +214,205 @@ impl_q_work!(c64, lapack_sys::zungqr_, lapack_sys::zunglq_);
 impl_q_work!(c32, lapack_sys::cungqr_, lapack_sys::cunglq_);
 impl_q_work!(f64, lapack_sys::dorgqr_, lapack_sys::dorglq_);
 impl_q_work!(f32, lapack_sys::sorgqr_, lapack_sys::sorglq_);
+
+pub struct HouseholderPivotedWork<T: Scalar> {
+    pub m: i32,
+    pub n: i32,
+    pub layout: MatrixLayout,
+    pub jpvt: Vec<i32>,
+    pub tau: Vec<MaybeUninit<T>>,
+    pub work: Vec<MaybeUninit<T>>,
+    pub rwork: Option<Vec<MaybeUninit<T::Real>>>,
+}
+
+/// Householder reflection with column pivoting
+///
+/// There is no LQ counterpart with row pivoting,
+/// and thus C-continuous array is factorized after transposed into F-continuous one.
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | sgeqp3 | dgeqp3 | cgeqp3 | zgeqp3 |
+///
+pub trait HouseholderPivotedWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(l: MatrixLayout) -> Result<Self>;
+    fn calc(&mut self, a: &mut [Self::Elem]) -> Result<(&[Self::Elem], &[i32])>;
+    fn eval(self, a: &mut [Self::Elem]) -> Result<(Vec<Self::Elem>, Pivot)>;
+}
+
+macro_rules! impl_householder_pivoted_work_c {
+    ($c:ty, $qp3:path) => {
+        impl HouseholderPivotedWorkImpl for HouseholderPivotedWork<$c> {
+            type Elem = $c;
+
+            fn new(layout: MatrixLayout) -> Result<Self> {
+                let (m, n) = layout.size();
+                let k = m.min(n);
+                let mut jpvt = vec![0; n as usize];
+                let mut tau = vec_uninit(k as usize);
+                let mut rwork = vec_uninit(2 * n as usize);
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                unsafe {
+                    $qp3(
+                        &m,
+                        &n,
+                        std::ptr::null_mut(),
+                        &std::cmp::max(1, m),
+                        jpvt.as_mut_ptr(),
+                        AsPtr::as_mut_ptr(&mut tau),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                let lwork = work_size[0].to_usize().unwrap();
+                let work = vec_uninit(lwork);
+                Ok(HouseholderPivotedWork {
+                    m,
+                    n,
+                    layout,
+                    jpvt,
+                    tau,
+                    work,
+                    rwork: Some(rwork),
+                })
+            }
+
+            fn calc(&mut self, a: &mut [Self::Elem]) -> Result<(&[Self::Elem], &[i32])> {
+                let lwork = self.work.len().to_i32().unwrap();
+                // All columns are free to be pivoted
+                self.jpvt.iter_mut().for_each(|p| *p = 0);
+                let mut a_t = match self.layout {
+                    MatrixLayout::C { .. } => Some(transpose(self.layout, a).1),
+                    MatrixLayout::F { .. } => None,
+                };
+                let mut info = 0;
+                unsafe {
+                    $qp3(
+                        &self.m,
+                        &self.n,
+                        AsPtr::as_mut_ptr(a_t.as_deref_mut().unwrap_or(a)),
+                        &std::cmp::max(1, self.m),
+                        self.jpvt.as_mut_ptr(),
+                        AsPtr::as_mut_ptr(&mut self.tau),
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(self.rwork.as_mut().unwrap()),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                if let Some(a_t) = a_t {
+                    transpose_over(
+                        MatrixLayout::F {
+                            col: self.n,
+                            lda: self.m,
+                        },
+                        &a_t,
+                        a,
+                    );
+                }
+                Ok((unsafe { self.tau.slice_assume_init_ref() }, &self.jpvt))
+            }
+
+            fn eval(mut self, a: &mut [Self::Elem]) -> Result<(Vec<Self::Elem>, Pivot)> {
+                let _qr = self.calc(a)?;
+                Ok((unsafe { self.tau.assume_init() }, self.jpvt))
+            }
+        }
+    };
+}
+impl_householder_pivoted_work_c!(c64, lapack_sys::zgeqp3_);
+impl_householder_pivoted_work_c!(c32, lapack_sys::cgeqp3_);
+
+macro_rules! impl_householder_pivoted_work_r {
+    ($f:ty, $qp3:path) => {
+        impl HouseholderPivotedWorkImpl for HouseholderPivotedWork<$f> {
+            type Elem = $f;
+
+            fn new(layout: MatrixLayout) -> Result<Self> {
+                let (m, n) = layout.size();
+                let k = m.min(n);
+                let mut jpvt = vec![0; n as usize];
+                let mut tau = vec_uninit(k as usize);
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                unsafe {
+                    $qp3(
+                        &m,
+                        &n,
+                        std::ptr::null_mut(),
+                        &std::cmp::max(1, m),
+                        jpvt.as_mut_ptr(),
+                        AsPtr::as_mut_ptr(&mut tau),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                let lwork = work_size[0].to_usize().unwrap();
+                let work = vec_uninit(lwork);
+                Ok(HouseholderPivotedWork {
+                    m,
+                    n,
+                    layout,
+                    jpvt,
+                    tau,
+                    work,
+                    rwork: None,
+                })
+            }
+
+            fn calc(&mut self, a: &mut [Self::Elem]) -> Result<(&[Self::Elem], &[i32])> {
+                let lwork = self.work.len().to_i32().unwrap();
+                // All columns are free to be pivoted
+                self.jpvt.iter_mut().for_each(|p| *p = 0);
+                let mut a_t = match self.layout {
+                    MatrixLayout::C { .. } => Some(transpose(self.layout, a).1),
+                    MatrixLayout::F { .. } => None,
+                };
+                let mut info = 0;
+                unsafe {
+                    $qp3(
+                        &self.m,
+                        &self.n,
+                        AsPtr::as_mut_ptr(a_t.as_deref_mut().unwrap_or(a)),
+                        &std::cmp::max(1, self.m),
+                        self.jpvt.as_mut_ptr(),
+                        AsPtr::as_mut_ptr(&mut self.tau),
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                if let Some(a_t) = a_t {
+                    transpose_over(
+                        MatrixLayout::F {
+                            col: self.n,
+                            lda: self.m,
+                        },
+                        &a_t,
+                        a,
+                    );
+                }
+                Ok((unsafe { self.tau.slice_assume_init_ref() }, &self.jpvt))
+            }
+
+            fn eval(mut self, a: &mut [Self::Elem]) -> Result<(Vec<Self::Elem>, Pivot)> {
+                let _qr = self.calc(a)?;
+                Ok((unsafe { self.tau.assume_init() }, self.jpvt))
+            }
+        }
+    };
+}
+impl_householder_pivoted_work_r!(f64, lapack_sys::dgeqp3_);
+impl_householder_pivoted_work_r!(f32, lapack_sys::sgeqp3_);
@@ -122,3 +122,198 @@ macro_rules! impl_rcond_work_r {
 }
 impl_rcond_work_r!(f64, lapack_sys::dgecon_);
 impl_rcond_work_r!(f32, lapack_sys::sgecon_);
+
+/// Estimate the reciprocal condition number in 1-norm using Cholesky factorization result
+///
+/// `anorm` should be the 1-norm of the original matrix.
+/// Row-major matrix is handled in the same manner as [crate::cholesky::SolveCholeskyImpl].
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | spocon | dpocon | cpocon | zpocon |
+///
+pub trait RcondCholeskyImpl: Scalar {
+    fn rcond_cholesky(
+        l: MatrixLayout,
+        uplo: UPLO,
+        a: &[Self],
+        anorm: Self::Real,
+    ) -> Result<Self::Real>;
+}
+
+macro_rules! impl_rcond_cholesky_c {
+    ($c:ty, $pocon:path) => {
+        impl RcondCholeskyImpl for $c {
+            fn rcond_cholesky(
+                l: MatrixLayout,
+                mut uplo: UPLO,
+                a: &[Self],
+                anorm: Self::Real,
+            ) -> Result<Self::Real> {
+                let (n, _) = l.size();
+                let mut rcond = Self::Real::zero();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(2 * n as usize);
+                let mut rwork: Vec<MaybeUninit<Self::Real>> = vec_uninit(n as usize);
+                if matches!(l, MatrixLayout::C { .. }) {
+                    uplo = uplo.t();
+                }
+                let mut info = 0;
+                unsafe {
+                    $pocon(
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_ptr(a),
+                        &l.lda(),
+                        &anorm,
+                        &mut rcond,
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                Ok(rcond)
+            }
+        }
+    };
+}
+impl_rcond_cholesky_c!(c64, lapack_sys::zpocon_);
+impl_rcond_cholesky_c!(c32, lapack_sys::cpocon_);
+
+macro_rules! impl_rcond_cholesky_r {
+    ($r:ty, $pocon:path) => {
+        impl RcondCholeskyImpl for $r {
+            fn rcond_cholesky(
+                l: MatrixLayout,
+                mut uplo: UPLO,
+                a: &[Self],
+                anorm: Self::Real,
+            ) -> Result<Self::Real> {
+                let (n, _) = l.size();
+                let mut rcond = Self::Real::zero();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(3 * n as usize);
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(n as usize);
+                if matches!(l, MatrixLayout::C { .. }) {
+                    uplo = uplo.t();
+                }
+                let mut info = 0;
+                unsafe {
+                    $pocon(
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_ptr(a),
+                        &l.lda(),
+                        &anorm,
+                        &mut rcond,
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                Ok(rcond)
+            }
+        }
+    };
+}
+impl_rcond_cholesky_r!(f64, lapack_sys::dpocon_);
+impl_rcond_cholesky_r!(f32, lapack_sys::spocon_);
+
+/// Estimate the reciprocal condition number in 1-norm using Bunch-Kaufman factorization result
+///
+/// `anorm` should be the 1-norm of the original matrix.
+/// `uplo` and `ipiv` are those of [crate::solveh::BkWorkImpl], i.e. for the column-major view of `a`.
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | ssycon | dsycon | checon | zhecon |
+///
+pub trait RcondHermitianImpl: Scalar {
+    fn rcond_hermitian(
+        l: MatrixLayout,
+        uplo: UPLO,
+        a: &[Self],
+        ipiv: &Pivot,
+        anorm: Self::Real,
+    ) -> Result<Self::Real>;
+}
+
+macro_rules! impl_rcond_hermitian_c {
+    ($c:ty, $hecon:path) => {
+        impl RcondHermitianImpl for $c {
+            fn rcond_hermitian(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &[Self],
+                ipiv: &Pivot,
+                anorm: Self::Real,
+            ) -> Result<Self::Real> {
+                let (n, _) = l.size();
+                let mut rcond = Self::Real::zero();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(2 * n as usize);
+                let mut info = 0;
+                unsafe {
+                    $hecon(
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_ptr(a),
+                        &l.lda(),
+                        ipiv.as_ptr(),
+                        &anorm,
+                        &mut rcond,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                Ok(rcond)
+            }
+        }
+    };
+}
+impl_rcond_hermitian_c!(c64, lapack_sys::zhecon_);
+impl_rcond_hermitian_c!(c32, lapack_sys::checon_);
+
+macro_rules! impl_rcond_hermitian_r {
+    ($r:ty, $sycon:path) => {
+        impl RcondHermitianImpl for $r {
+            fn rcond_hermitian(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &[Self],
+                ipiv: &Pivot,
+                anorm: Self::Real,
+            ) -> Result<Self::Real> {
+                let (n, _) = l.size();
+                let mut rcond = Self::Real::zero();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(2 * n as usize);
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(n as usize);
+                let mut info = 0;
+                unsafe {
+                    $sycon(
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_ptr(a),
+                        &l.lda(),
+                        ipiv.as_ptr(),
+                        &anorm,
+                        &mut rcond,
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                Ok(rcond)
+            }
+        }
+    };
+}
+impl_rcond_hermitian_r!(f64, lapack_sys::dsycon_);
+impl_rcond_hermitian_r!(f32, lapack_sys::ssycon_);
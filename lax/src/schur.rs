@@ -0,0 +1,494 @@
+//! Schur decomposition for general matricies
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | sgees  | dgees  | cgees  | zgees  |
+//! | strsen | dtrsen | ctrsen | ztrsen |
+//!
+
+use crate::{error::*, layout::MatrixLayout, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Schur decomposition for general matrix
+///
+/// For a square matrix $A$, this computes
+/// $$
+/// A = Z T Z^\dagger
+/// $$
+/// where $Z$ is unitary (orthogonal for real matrices), and $T$ is
+/// upper triangular for complex matrices.
+/// For real matrices, $T$ is quasi upper triangular, i.e. block upper triangular
+/// with 1-by-1 and 2-by-2 diagonal blocks,
+/// and each 2-by-2 block corresponds to a complex conjugate pair of eigenvalues.
+///
+/// The input matrix is overwritten by $T$ keeping its layout,
+/// and $Z$ is returned in the same layout as the input.
+///
+#[non_exhaustive]
+pub struct SchurWork<T: Scalar> {
+    /// Problem size
+    pub n: i32,
+    /// Layout of input and output matrices
+    pub layout: MatrixLayout,
+    /// Compute Schur vectors or not
+    pub jobvs: JobEv,
+
+    /// Eigenvalues
+    pub eigs: Vec<MaybeUninit<T::Complex>>,
+    /// Real part of eigenvalues used in real routines
+    pub eigs_re: Option<Vec<MaybeUninit<T::Real>>>,
+    /// Imaginary part of eigenvalues used in real routines
+    pub eigs_im: Option<Vec<MaybeUninit<T::Real>>>,
+
+    /// Schur vectors
+    pub vs: Option<Vec<MaybeUninit<T>>>,
+
+    /// Working memory
+    pub work: Vec<MaybeUninit<T>>,
+    /// Working memory with `T::Real`
+    pub rwork: Option<Vec<MaybeUninit<T::Real>>>,
+}
+
+impl<T> SchurWork<T>
+where
+    T: Scalar,
+    SchurWork<T>: SchurWorkImpl<Elem = T>,
+{
+    /// Create new working memory for Schur decomposition.
+    pub fn new(calc_v: bool, l: MatrixLayout) -> Result<Self> {
+        SchurWorkImpl::new(calc_v, l)
+    }
+
+    /// Compute Schur decomposition on this working memory.
+    pub fn calc(&mut self, a: &mut [T]) -> Result<SchurRef<'_, T>> {
+        SchurWorkImpl::calc(self, a)
+    }
+
+    /// Compute Schur decomposition by consuming this working memory.
+    pub fn eval(self, a: &mut [T]) -> Result<SchurOwned<T>> {
+        SchurWorkImpl::eval(self, a)
+    }
+}
+
+/// Owned result of Schur decomposition by [SchurWork::eval]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchurOwned<T: Scalar> {
+    /// Eigenvalues in the order of the diagonal of $T$
+    pub eigs: Vec<T::Complex>,
+    /// Schur vectors $Z$
+    pub vs: Option<Vec<T>>,
+}
+
+/// Reference result of Schur decomposition by [SchurWork::calc]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchurRef<'work, T: Scalar> {
+    /// Eigenvalues in the order of the diagonal of $T$
+    pub eigs: &'work [T::Complex],
+    /// Schur vectors $Z$
+    pub vs: Option<&'work [T]>,
+}
+
+/// Helper trait for implementing [SchurWork] methods
+pub trait SchurWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(calc_v: bool, l: MatrixLayout) -> Result<Self>;
+    fn calc<'work>(&'work mut self, a: &mut [Self::Elem]) -> Result<SchurRef<'work, Self::Elem>>;
+    fn eval(self, a: &mut [Self::Elem]) -> Result<SchurOwned<Self::Elem>>;
+}
+
+macro_rules! impl_schur_work_c {
+    ($c:ty, $gees:path) => {
+        impl SchurWorkImpl for SchurWork<$c> {
+            type Elem = $c;
+
+            fn new(calc_v: bool, l: MatrixLayout) -> Result<Self> {
+                let (n, _) = l.size();
+                let jobvs = if calc_v { JobEv::All } else { JobEv::None };
+                let mut eigs = vec_uninit(n as usize);
+                let mut rwork = vec_uninit(n as usize);
+                let mut vs = jobvs.then(|| vec_uninit((n * n) as usize));
+
+                // calc work size
+                let mut info = 0;
+                let mut sdim = 0;
+                let mut work_size = [<$c>::zero()];
+                unsafe {
+                    $gees(
+                        jobvs.as_ptr(),
+                        JobEv::None.as_ptr(), // eigenvalues are not sorted
+                        None,
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(vs.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        std::ptr::null_mut(),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                let lwork = work_size[0].to_usize().unwrap();
+                let work = vec_uninit(lwork);
+                Ok(Self {
+                    n,
+                    layout: l,
+                    jobvs,
+                    eigs,
+                    eigs_re: None,
+                    eigs_im: None,
+                    vs,
+                    work,
+                    rwork: Some(rwork),
+                })
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                a: &mut [Self::Elem],
+            ) -> Result<SchurRef<'work, Self::Elem>> {
+                // LAPACK only accepts column-major matrices
+                if let MatrixLayout::C { .. } = self.layout {
+                    square_transpose(self.layout, a);
+                }
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut info = 0;
+                let mut sdim = 0;
+                unsafe {
+                    $gees(
+                        self.jobvs.as_ptr(),
+                        JobEv::None.as_ptr(), // eigenvalues are not sorted
+                        None,
+                        &self.n,
+                        AsPtr::as_mut_ptr(a),
+                        &self.n,
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut self.eigs),
+                        AsPtr::as_mut_ptr(self.vs.as_deref_mut().unwrap_or(&mut [])),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(self.rwork.as_mut().unwrap()),
+                        std::ptr::null_mut(),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                let vs = self
+                    .vs
+                    .as_mut()
+                    .map(|v| unsafe { v.slice_assume_init_mut() });
+                if let MatrixLayout::C { .. } = self.layout {
+                    square_transpose(self.layout, a);
+                    if let Some(vs) = vs {
+                        square_transpose(self.layout, vs);
+                    }
+                }
+                Ok(SchurRef {
+                    eigs: unsafe { self.eigs.slice_assume_init_ref() },
+                    vs: self
+                        .vs
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() }),
+                })
+            }
+
+            fn eval(mut self, a: &mut [Self::Elem]) -> Result<SchurOwned<Self::Elem>> {
+                let _schur_ref = self.calc(a)?;
+                Ok(SchurOwned {
+                    eigs: unsafe { self.eigs.assume_init() },
+                    vs: self.vs.map(|v| unsafe { v.assume_init() }),
+                })
+            }
+        }
+    };
+}
+
+impl_schur_work_c!(c32, lapack_sys::cgees_);
+impl_schur_work_c!(c64, lapack_sys::zgees_);
+
+macro_rules! impl_schur_work_r {
+    ($f:ty, $gees:path) => {
+        impl SchurWorkImpl for SchurWork<$f> {
+            type Elem = $f;
+
+            fn new(calc_v: bool, l: MatrixLayout) -> Result<Self> {
+                let (n, _) = l.size();
+                let jobvs = if calc_v { JobEv::All } else { JobEv::None };
+                let mut eigs_re = vec_uninit(n as usize);
+                let mut eigs_im = vec_uninit(n as usize);
+                let mut vs = jobvs.then(|| vec_uninit((n * n) as usize));
+
+                // calc work size
+                let mut info = 0;
+                let mut sdim = 0;
+                let mut work_size: [$f; 1] = [0.0];
+                unsafe {
+                    $gees(
+                        jobvs.as_ptr(),
+                        JobEv::None.as_ptr(), // eigenvalues are not sorted
+                        None,
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut eigs_re),
+                        AsPtr::as_mut_ptr(&mut eigs_im),
+                        AsPtr::as_mut_ptr(vs.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        std::ptr::null_mut(),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                let lwork = work_size[0].to_usize().unwrap();
+                let work = vec_uninit(lwork);
+                Ok(Self {
+                    n,
+                    layout: l,
+                    jobvs,
+                    eigs: vec_uninit(n as usize),
+                    eigs_re: Some(eigs_re),
+                    eigs_im: Some(eigs_im),
+                    vs,
+                    work,
+                    rwork: None,
+                })
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                a: &mut [Self::Elem],
+            ) -> Result<SchurRef<'work, Self::Elem>> {
+                // LAPACK only accepts column-major matrices
+                if let MatrixLayout::C { .. } = self.layout {
+                    square_transpose(self.layout, a);
+                }
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut info = 0;
+                let mut sdim = 0;
+                unsafe {
+                    $gees(
+                        self.jobvs.as_ptr(),
+                        JobEv::None.as_ptr(), // eigenvalues are not sorted
+                        None,
+                        &self.n,
+                        AsPtr::as_mut_ptr(a),
+                        &self.n,
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(self.eigs_re.as_mut().unwrap()),
+                        AsPtr::as_mut_ptr(self.eigs_im.as_mut().unwrap()),
+                        AsPtr::as_mut_ptr(self.vs.as_deref_mut().unwrap_or(&mut [])),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        std::ptr::null_mut(),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                let eigs_re = self
+                    .eigs_re
+                    .as_ref()
+                    .map(|e| unsafe { e.slice_assume_init_ref() })
+                    .unwrap();
+                let eigs_im = self
+                    .eigs_im
+                    .as_ref()
+                    .map(|e| unsafe { e.slice_assume_init_ref() })
+                    .unwrap();
+                eig::reconstruct_eigs(eigs_re, eigs_im, &mut self.eigs);
+
+                let vs = self
+                    .vs
+                    .as_mut()
+                    .map(|v| unsafe { v.slice_assume_init_mut() });
+                if let MatrixLayout::C { .. } = self.layout {
+                    square_transpose(self.layout, a);
+                    if let Some(vs) = vs {
+                        square_transpose(self.layout, vs);
+                    }
+                }
+                Ok(SchurRef {
+                    eigs: unsafe { self.eigs.slice_assume_init_ref() },
+                    vs: self
+                        .vs
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() }),
+                })
+            }
+
+            fn eval(mut self, a: &mut [Self::Elem]) -> Result<SchurOwned<Self::Elem>> {
+                let _schur_ref = self.calc(a)?;
+                Ok(SchurOwned {
+                    eigs: unsafe { self.eigs.assume_init() },
+                    vs: self.vs.map(|v| unsafe { v.assume_init() }),
+                })
+            }
+        }
+    };
+}
+
+impl_schur_work_r!(f32, lapack_sys::sgees_);
+impl_schur_work_r!(f64, lapack_sys::dgees_);
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Reorder Schur decomposition
+///
+/// For a Schur decomposition $A = Z T Z^\dagger$ computed by [SchurWork],
+/// this reorders $T$ and $Z$ so that the selected eigenvalues
+/// appear in the leading diagonal block of $T$.
+/// The leading columns of the updated $Z$ then span
+/// the invariant subspace corresponding to the selected eigenvalues.
+///
+/// For real matrices, a complex conjugate pair of eigenvalues
+/// is moved together when either of them is selected.
+///
+/// Returns the reordered eigenvalues and the dimension of the selected invariant subspace.
+///
+pub trait SchurReorderImpl: Scalar {
+    fn schur_reorder(
+        l: MatrixLayout,
+        select: &[bool],
+        t: &mut [Self],
+        z: &mut [Self],
+    ) -> Result<(Vec<Self::Complex>, i32)>;
+}
+
+macro_rules! impl_schur_reorder_c {
+    ($c:ty, $trsen:path) => {
+        impl SchurReorderImpl for $c {
+            fn schur_reorder(
+                l: MatrixLayout,
+                select: &[bool],
+                t: &mut [Self],
+                z: &mut [Self],
+            ) -> Result<(Vec<Self::Complex>, i32)> {
+                let (n, _) = l.size();
+                if select.len() != n as usize {
+                    return Err(Error::InvalidShape);
+                }
+                let select: Vec<i32> = select.iter().map(|&s| s as i32).collect();
+                let is_c = matches!(l, MatrixLayout::C { .. });
+                if is_c {
+                    square_transpose(l, t);
+                    square_transpose(l, z);
+                }
+                let mut eigs: Vec<MaybeUninit<$c>> = vec_uninit(n as usize);
+                let mut m = 0;
+                let mut work: Vec<MaybeUninit<$c>> = vec_uninit(1);
+                let mut info = 0;
+                unsafe {
+                    $trsen(
+                        JobEv::None.as_ptr(), // condition numbers are not computed
+                        JobEv::All.as_ptr(),
+                        select.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(t),
+                        &n,
+                        AsPtr::as_mut_ptr(z),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        &mut m,
+                        std::ptr::null_mut(),
+                        std::ptr::null_mut(),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &1,
+                        &mut info,
+                    )
+                };
+                if is_c {
+                    square_transpose(l, t);
+                    square_transpose(l, z);
+                }
+                info.as_lapack_result()?;
+                Ok((unsafe { eigs.assume_init() }, m))
+            }
+        }
+    };
+}
+
+impl_schur_reorder_c!(c32, lapack_sys::ctrsen_);
+impl_schur_reorder_c!(c64, lapack_sys::ztrsen_);
+
+macro_rules! impl_schur_reorder_r {
+    ($f:ty, $trsen:path) => {
+        impl SchurReorderImpl for $f {
+            fn schur_reorder(
+                l: MatrixLayout,
+                select: &[bool],
+                t: &mut [Self],
+                z: &mut [Self],
+            ) -> Result<(Vec<Self::Complex>, i32)> {
+                let (n, _) = l.size();
+                if select.len() != n as usize {
+                    return Err(Error::InvalidShape);
+                }
+                let select: Vec<i32> = select.iter().map(|&s| s as i32).collect();
+                let is_c = matches!(l, MatrixLayout::C { .. });
+                if is_c {
+                    square_transpose(l, t);
+                    square_transpose(l, z);
+                }
+                let mut eigs_re: Vec<MaybeUninit<$f>> = vec_uninit(n as usize);
+                let mut eigs_im: Vec<MaybeUninit<$f>> = vec_uninit(n as usize);
+                let mut m = 0;
+                let lwork = std::cmp::max(1, n);
+                let mut work: Vec<MaybeUninit<$f>> = vec_uninit(lwork as usize);
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(1);
+                let mut info = 0;
+                unsafe {
+                    $trsen(
+                        JobEv::None.as_ptr(), // condition numbers are not computed
+                        JobEv::All.as_ptr(),
+                        select.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(t),
+                        &n,
+                        AsPtr::as_mut_ptr(z),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut eigs_re),
+                        AsPtr::as_mut_ptr(&mut eigs_im),
+                        &mut m,
+                        std::ptr::null_mut(),
+                        std::ptr::null_mut(),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &1,
+                        &mut info,
+                    )
+                };
+                if is_c {
+                    square_transpose(l, t);
+                    square_transpose(l, z);
+                }
+                info.as_lapack_result()?;
+
+                let eigs_re = unsafe { eigs_re.assume_init() };
+                let eigs_im = unsafe { eigs_im.assume_init() };
+                let mut eigs = vec_uninit(n as usize);
+                eig::reconstruct_eigs(&eigs_re, &eigs_im, &mut eigs);
+                Ok((unsafe { eigs.assume_init() }, m))
+            }
+        }
+    };
+}
+
+impl_schur_reorder_r!(f32, lapack_sys::strsen_);
+impl_schur_reorder_r!(f64, lapack_sys::dtrsen_);
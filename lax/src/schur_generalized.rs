@@ -0,0 +1,616 @@
+//! Generalized Schur decomposition (QZ decomposition) for general matricies
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32   | f64   | c32   | c64   |
+//! |:------|:------|:------|:------|
+//! | sgges | dgges | cgges | zgges |
+//!
+
+use crate::{error::*, layout::MatrixLayout, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Generalized Schur decomposition for a pair of general matrices
+///
+/// For a pair of square matrices $(A, B)$, this computes
+/// $$
+/// A = Q S Z^\dagger, \quad B = Q T Z^\dagger
+/// $$
+/// where $Q$ and $Z$ are unitary (orthogonal for real matrices),
+/// $T$ is upper triangular, and $S$ is upper triangular for complex matrices
+/// or quasi upper triangular for real matrices.
+/// The generalized eigenvalues are $\lambda_i = \alpha_i / \beta_i$.
+///
+/// $A$ and $B$ are overwritten by $S$ and $T$ keeping their layout,
+/// and $Q$ and $Z$ are returned in the same layout.
+/// Both matrices must be in the same layout.
+///
+#[non_exhaustive]
+pub struct SchurGeneralizedWork<T: Scalar> {
+    /// Problem size
+    pub n: i32,
+    /// Layout of input and output matrices
+    pub layout: MatrixLayout,
+    /// Compute Schur vectors or not
+    pub jobvs: JobEv,
+
+    /// Numerator of eigenvalues
+    pub alpha: Vec<MaybeUninit<T::Complex>>,
+    /// Real part of numerator of eigenvalues used in real routines
+    pub alpha_re: Option<Vec<MaybeUninit<T::Real>>>,
+    /// Imaginary part of numerator of eigenvalues used in real routines
+    pub alpha_im: Option<Vec<MaybeUninit<T::Real>>>,
+    /// Denominator of eigenvalues
+    pub beta: Vec<MaybeUninit<T>>,
+
+    /// Left Schur vectors $Q$
+    pub vsl: Option<Vec<MaybeUninit<T>>>,
+    /// Right Schur vectors $Z$
+    pub vsr: Option<Vec<MaybeUninit<T>>>,
+
+    /// Working memory
+    pub work: Vec<MaybeUninit<T>>,
+    /// Working memory with `T::Real`
+    pub rwork: Option<Vec<MaybeUninit<T::Real>>>,
+}
+
+impl<T> SchurGeneralizedWork<T>
+where
+    T: Scalar,
+    SchurGeneralizedWork<T>: SchurGeneralizedWorkImpl<Elem = T>,
+{
+    /// Create new working memory for generalized Schur decomposition.
+    pub fn new(calc_v: bool, l: MatrixLayout) -> Result<Self> {
+        SchurGeneralizedWorkImpl::new(calc_v, l)
+    }
+
+    /// Compute generalized Schur decomposition on this working memory.
+    pub fn calc(&mut self, a: &mut [T], b: &mut [T]) -> Result<SchurGeneralizedRef<'_, T>> {
+        SchurGeneralizedWorkImpl::calc(self, a, b)
+    }
+
+    /// Compute generalized Schur decomposition by consuming this working memory.
+    pub fn eval(self, a: &mut [T], b: &mut [T]) -> Result<SchurGeneralizedOwned<T>> {
+        SchurGeneralizedWorkImpl::eval(self, a, b)
+    }
+}
+
+/// Owned result of generalized Schur decomposition by [SchurGeneralizedWork::eval]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchurGeneralizedOwned<T: Scalar> {
+    /// Numerator of eigenvalues
+    pub alpha: Vec<T::Complex>,
+    /// Denominator of eigenvalues
+    pub beta: Vec<T>,
+    /// Left Schur vectors $Q$
+    pub vsl: Option<Vec<T>>,
+    /// Right Schur vectors $Z$
+    pub vsr: Option<Vec<T>>,
+}
+
+/// Reference result of generalized Schur decomposition by [SchurGeneralizedWork::calc]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchurGeneralizedRef<'work, T: Scalar> {
+    /// Numerator of eigenvalues
+    pub alpha: &'work [T::Complex],
+    /// Denominator of eigenvalues
+    pub beta: &'work [T],
+    /// Left Schur vectors $Q$
+    pub vsl: Option<&'work [T]>,
+    /// Right Schur vectors $Z$
+    pub vsr: Option<&'work [T]>,
+}
+
+/// Helper trait for implementing [SchurGeneralizedWork] methods
+pub trait SchurGeneralizedWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(calc_v: bool, l: MatrixLayout) -> Result<Self>;
+    fn calc<'work>(
+        &'work mut self,
+        a: &mut [Self::Elem],
+        b: &mut [Self::Elem],
+    ) -> Result<SchurGeneralizedRef<'work, Self::Elem>>;
+    fn eval(
+        self,
+        a: &mut [Self::Elem],
+        b: &mut [Self::Elem],
+    ) -> Result<SchurGeneralizedOwned<Self::Elem>>;
+}
+
+macro_rules! impl_schur_generalized_work_c {
+    ($c:ty, $gges:path) => {
+        impl SchurGeneralizedWorkImpl for SchurGeneralizedWork<$c> {
+            type Elem = $c;
+
+            fn new(calc_v: bool, l: MatrixLayout) -> Result<Self> {
+                let (n, _) = l.size();
+                let jobvs = if calc_v { JobEv::All } else { JobEv::None };
+                let mut alpha = vec_uninit(n as usize);
+                let mut beta = vec_uninit(n as usize);
+                let mut rwork = vec_uninit(8 * n as usize);
+                let mut vsl = jobvs.then(|| vec_uninit((n * n) as usize));
+                let mut vsr = jobvs.then(|| vec_uninit((n * n) as usize));
+
+                // calc work size
+                let mut info = 0;
+                let mut sdim = 0;
+                let mut work_size = [<$c>::zero()];
+                unsafe {
+                    $gges(
+                        jobvs.as_ptr(),
+                        jobvs.as_ptr(),
+                        JobEv::None.as_ptr(), // eigenvalues are not sorted
+                        None,
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut alpha),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(vsl.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        AsPtr::as_mut_ptr(vsr.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        std::ptr::null_mut(),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                let lwork = work_size[0].to_usize().unwrap();
+                let work = vec_uninit(lwork);
+                Ok(Self {
+                    n,
+                    layout: l,
+                    jobvs,
+                    alpha,
+                    alpha_re: None,
+                    alpha_im: None,
+                    beta,
+                    vsl,
+                    vsr,
+                    work,
+                    rwork: Some(rwork),
+                })
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+            ) -> Result<SchurGeneralizedRef<'work, Self::Elem>> {
+                // LAPACK only accepts column-major matrices
+                if let MatrixLayout::C { .. } = self.layout {
+                    square_transpose(self.layout, a);
+                    square_transpose(self.layout, b);
+                }
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut info = 0;
+                let mut sdim = 0;
+                unsafe {
+                    $gges(
+                        self.jobvs.as_ptr(),
+                        self.jobvs.as_ptr(),
+                        JobEv::None.as_ptr(), // eigenvalues are not sorted
+                        None,
+                        &self.n,
+                        AsPtr::as_mut_ptr(a),
+                        &self.n,
+                        AsPtr::as_mut_ptr(b),
+                        &self.n,
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut self.alpha),
+                        AsPtr::as_mut_ptr(&mut self.beta),
+                        AsPtr::as_mut_ptr(self.vsl.as_deref_mut().unwrap_or(&mut [])),
+                        &self.n,
+                        AsPtr::as_mut_ptr(self.vsr.as_deref_mut().unwrap_or(&mut [])),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(self.rwork.as_mut().unwrap()),
+                        std::ptr::null_mut(),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                if let MatrixLayout::C { .. } = self.layout {
+                    square_transpose(self.layout, a);
+                    square_transpose(self.layout, b);
+                    if let Some(vsl) = self.vsl.as_mut() {
+                        square_transpose(self.layout, unsafe { vsl.slice_assume_init_mut() });
+                    }
+                    if let Some(vsr) = self.vsr.as_mut() {
+                        square_transpose(self.layout, unsafe { vsr.slice_assume_init_mut() });
+                    }
+                }
+                Ok(SchurGeneralizedRef {
+                    alpha: unsafe { self.alpha.slice_assume_init_ref() },
+                    beta: unsafe { self.beta.slice_assume_init_ref() },
+                    vsl: self
+                        .vsl
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() }),
+                    vsr: self
+                        .vsr
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() }),
+                })
+            }
+
+            fn eval(
+                mut self,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+            ) -> Result<SchurGeneralizedOwned<Self::Elem>> {
+                let _schur_ref = self.calc(a, b)?;
+                Ok(SchurGeneralizedOwned {
+                    alpha: unsafe { self.alpha.assume_init() },
+                    beta: unsafe { self.beta.assume_init() },
+                    vsl: self.vsl.map(|v| unsafe { v.assume_init() }),
+                    vsr: self.vsr.map(|v| unsafe { v.assume_init() }),
+                })
+            }
+        }
+    };
+}
+
+impl_schur_generalized_work_c!(c32, lapack_sys::cgges_);
+impl_schur_generalized_work_c!(c64, lapack_sys::zgges_);
+
+macro_rules! impl_schur_generalized_work_r {
+    ($f:ty, $gges:path) => {
+        impl SchurGeneralizedWorkImpl for SchurGeneralizedWork<$f> {
+            type Elem = $f;
+
+            fn new(calc_v: bool, l: MatrixLayout) -> Result<Self> {
+                let (n, _) = l.size();
+                let jobvs = if calc_v { JobEv::All } else { JobEv::None };
+                let mut alpha_re = vec_uninit(n as usize);
+                let mut alpha_im = vec_uninit(n as usize);
+                let mut beta = vec_uninit(n as usize);
+                let mut vsl = jobvs.then(|| vec_uninit((n * n) as usize));
+                let mut vsr = jobvs.then(|| vec_uninit((n * n) as usize));
+
+                // calc work size
+                let mut info = 0;
+                let mut sdim = 0;
+                let mut work_size: [$f; 1] = [0.0];
+                unsafe {
+                    $gges(
+                        jobvs.as_ptr(),
+                        jobvs.as_ptr(),
+                        JobEv::None.as_ptr(), // eigenvalues are not sorted
+                        None,
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut alpha_re),
+                        AsPtr::as_mut_ptr(&mut alpha_im),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(vsl.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        AsPtr::as_mut_ptr(vsr.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        std::ptr::null_mut(),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                let lwork = work_size[0].to_usize().unwrap();
+                let work = vec_uninit(lwork);
+                Ok(Self {
+                    n,
+                    layout: l,
+                    jobvs,
+                    alpha: vec_uninit(n as usize),
+                    alpha_re: Some(alpha_re),
+                    alpha_im: Some(alpha_im),
+                    beta,
+                    vsl,
+                    vsr,
+                    work,
+                    rwork: None,
+                })
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+            ) -> Result<SchurGeneralizedRef<'work, Self::Elem>> {
+                // LAPACK only accepts column-major matrices
+                if let MatrixLayout::C { .. } = self.layout {
+                    square_transpose(self.layout, a);
+                    square_transpose(self.layout, b);
+                }
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut info = 0;
+                let mut sdim = 0;
+                unsafe {
+                    $gges(
+                        self.jobvs.as_ptr(),
+                        self.jobvs.as_ptr(),
+                        JobEv::None.as_ptr(), // eigenvalues are not sorted
+                        None,
+                        &self.n,
+                        AsPtr::as_mut_ptr(a),
+                        &self.n,
+                        AsPtr::as_mut_ptr(b),
+                        &self.n,
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(self.alpha_re.as_mut().unwrap()),
+                        AsPtr::as_mut_ptr(self.alpha_im.as_mut().unwrap()),
+                        AsPtr::as_mut_ptr(&mut self.beta),
+                        AsPtr::as_mut_ptr(self.vsl.as_deref_mut().unwrap_or(&mut [])),
+                        &self.n,
+                        AsPtr::as_mut_ptr(self.vsr.as_deref_mut().unwrap_or(&mut [])),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        std::ptr::null_mut(),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                let alpha_re = self
+                    .alpha_re
+                    .as_ref()
+                    .map(|e| unsafe { e.slice_assume_init_ref() })
+                    .unwrap();
+                let alpha_im = self
+                    .alpha_im
+                    .as_ref()
+                    .map(|e| unsafe { e.slice_assume_init_ref() })
+                    .unwrap();
+                eig::reconstruct_eigs(alpha_re, alpha_im, &mut self.alpha);
+
+                if let MatrixLayout::C { .. } = self.layout {
+                    square_transpose(self.layout, a);
+                    square_transpose(self.layout, b);
+                    if let Some(vsl) = self.vsl.as_mut() {
+                        square_transpose(self.layout, unsafe { vsl.slice_assume_init_mut() });
+                    }
+                    if let Some(vsr) = self.vsr.as_mut() {
+                        square_transpose(self.layout, unsafe { vsr.slice_assume_init_mut() });
+                    }
+                }
+                Ok(SchurGeneralizedRef {
+                    alpha: unsafe { self.alpha.slice_assume_init_ref() },
+                    beta: unsafe { self.beta.slice_assume_init_ref() },
+                    vsl: self
+                        .vsl
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() }),
+                    vsr: self
+                        .vsr
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() }),
+                })
+            }
+
+            fn eval(
+                mut self,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+            ) -> Result<SchurGeneralizedOwned<Self::Elem>> {
+                let _schur_ref = self.calc(a, b)?;
+                Ok(SchurGeneralizedOwned {
+                    alpha: unsafe { self.alpha.assume_init() },
+                    beta: unsafe { self.beta.assume_init() },
+                    vsl: self.vsl.map(|v| unsafe { v.assume_init() }),
+                    vsr: self.vsr.map(|v| unsafe { v.assume_init() }),
+                })
+            }
+        }
+    };
+}
+
+impl_schur_generalized_work_r!(f32, lapack_sys::sgges_);
+impl_schur_generalized_work_r!(f64, lapack_sys::dgges_);
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Reorder generalized Schur decomposition
+///
+/// For a generalized Schur decomposition $A = Q S Z^\dagger$, $B = Q T Z^\dagger$
+/// computed by [SchurGeneralizedWork], this reorders $S$, $T$, $Q$, and $Z$
+/// so that the selected generalized eigenvalues appear in the leading diagonal blocks of $S$ and $T$.
+/// The leading columns of the updated $Z$ then span
+/// the deflating subspace corresponding to the selected eigenvalues.
+///
+/// For real matrices, a complex conjugate pair of eigenvalues
+/// is moved together when either of them is selected.
+///
+/// Returns the reordered numerators $\alpha$ and denominators $\beta$ of the eigenvalues,
+/// and the dimension of the selected deflating subspace.
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | stgsen | dtgsen | ctgsen | ztgsen |
+///
+pub trait SchurGeneralizedReorderImpl: Scalar {
+    fn schur_generalized_reorder(
+        l: MatrixLayout,
+        select: &[bool],
+        s: &mut [Self],
+        t: &mut [Self],
+        q: &mut [Self],
+        z: &mut [Self],
+    ) -> Result<(Vec<Self::Complex>, Vec<Self>, i32)>;
+}
+
+macro_rules! impl_schur_generalized_reorder_c {
+    ($c:ty, $tgsen:path) => {
+        impl SchurGeneralizedReorderImpl for $c {
+            fn schur_generalized_reorder(
+                l: MatrixLayout,
+                select: &[bool],
+                s: &mut [Self],
+                t: &mut [Self],
+                q: &mut [Self],
+                z: &mut [Self],
+            ) -> Result<(Vec<Self::Complex>, Vec<Self>, i32)> {
+                let (n, _) = l.size();
+                if select.len() != n as usize {
+                    return Err(Error::InvalidShape);
+                }
+                let select: Vec<i32> = select.iter().map(|&s| s as i32).collect();
+                let is_c = matches!(l, MatrixLayout::C { .. });
+                if is_c {
+                    for a in [&mut *s, &mut *t, &mut *q, &mut *z] {
+                        square_transpose(l, a);
+                    }
+                }
+                let mut alpha: Vec<MaybeUninit<$c>> = vec_uninit(n as usize);
+                let mut beta: Vec<MaybeUninit<$c>> = vec_uninit(n as usize);
+                let mut m = 0;
+                let mut work: Vec<MaybeUninit<$c>> = vec_uninit(1);
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(1);
+                let mut info = 0;
+                unsafe {
+                    $tgsen(
+                        &0, // only reorder, condition numbers are not computed
+                        &1,
+                        &1,
+                        select.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(s),
+                        &n,
+                        AsPtr::as_mut_ptr(t),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut alpha),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(q),
+                        &n,
+                        AsPtr::as_mut_ptr(z),
+                        &n,
+                        &mut m,
+                        std::ptr::null_mut(),
+                        std::ptr::null_mut(),
+                        std::ptr::null_mut(),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &1,
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &1,
+                        &mut info,
+                    )
+                };
+                if is_c {
+                    for a in [s, t, q, z] {
+                        square_transpose(l, a);
+                    }
+                }
+                info.as_lapack_result()?;
+                Ok((
+                    unsafe { alpha.assume_init() },
+                    unsafe { beta.assume_init() },
+                    m,
+                ))
+            }
+        }
+    };
+}
+
+impl_schur_generalized_reorder_c!(c32, lapack_sys::ctgsen_);
+impl_schur_generalized_reorder_c!(c64, lapack_sys::ztgsen_);
+
+macro_rules! impl_schur_generalized_reorder_r {
+    ($f:ty, $tgsen:path) => {
+        impl SchurGeneralizedReorderImpl for $f {
+            fn schur_generalized_reorder(
+                l: MatrixLayout,
+                select: &[bool],
+                s: &mut [Self],
+                t: &mut [Self],
+                q: &mut [Self],
+                z: &mut [Self],
+            ) -> Result<(Vec<Self::Complex>, Vec<Self>, i32)> {
+                let (n, _) = l.size();
+                if select.len() != n as usize {
+                    return Err(Error::InvalidShape);
+                }
+                let select: Vec<i32> = select.iter().map(|&s| s as i32).collect();
+                let is_c = matches!(l, MatrixLayout::C { .. });
+                if is_c {
+                    for a in [&mut *s, &mut *t, &mut *q, &mut *z] {
+                        square_transpose(l, a);
+                    }
+                }
+                let mut alpha_re: Vec<MaybeUninit<$f>> = vec_uninit(n as usize);
+                let mut alpha_im: Vec<MaybeUninit<$f>> = vec_uninit(n as usize);
+                let mut beta: Vec<MaybeUninit<$f>> = vec_uninit(n as usize);
+                let mut m = 0;
+                let lwork = 4 * n + 16;
+                let mut work: Vec<MaybeUninit<$f>> = vec_uninit(lwork as usize);
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(1);
+                let mut info = 0;
+                unsafe {
+                    $tgsen(
+                        &0, // only reorder, condition numbers are not computed
+                        &1,
+                        &1,
+                        select.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(s),
+                        &n,
+                        AsPtr::as_mut_ptr(t),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut alpha_re),
+                        AsPtr::as_mut_ptr(&mut alpha_im),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(q),
+                        &n,
+                        AsPtr::as_mut_ptr(z),
+                        &n,
+                        &mut m,
+                        std::ptr::null_mut(),
+                        std::ptr::null_mut(),
+                        std::ptr::null_mut(),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &1,
+                        &mut info,
+                    )
+                };
+                if is_c {
+                    for a in [s, t, q, z] {
+                        square_transpose(l, a);
+                    }
+                }
+                info.as_lapack_result()?;
+
+                let alpha_re = unsafe { alpha_re.assume_init() };
+                let alpha_im = unsafe { alpha_im.assume_init() };
+                let mut alpha = vec_uninit(n as usize);
+                eig::reconstruct_eigs(&alpha_re, &alpha_im, &mut alpha);
+                Ok((
+                    unsafe { alpha.assume_init() },
+                    unsafe { beta.assume_init() },
+                    m,
+                ))
+            }
+        }
+    };
+}
+
+impl_schur_generalized_reorder_r!(f32, lapack_sys::stgsen_);
+impl_schur_generalized_reorder_r!(f64, lapack_sys::dtgsen_);
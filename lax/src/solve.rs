@@ -222,3 +222,214 @@ impl_inv_work!(c64, lapack_sys::zgetri_);
 impl_inv_work!(c32, lapack_sys::cgetri_);
 impl_inv_work!(f64, lapack_sys::dgetri_);
 impl_inv_work!(f32, lapack_sys::sgetri_);
+
+/// Result of the expert drivers for linear equations, e.g. [Lapack::solve_expert]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolveExpertOwned<T: Scalar> {
+    /// Estimate of the reciprocal condition number of the (equilibrated) matrix
+    pub rcond: T::Real,
+    /// Estimated forward error bound of the solution `x`, i.e. an upper bound of
+    /// `max|x - x_true| / max|x|`
+    pub ferr: T::Real,
+    /// Componentwise relative backward error of the solution `x`
+    pub berr: T::Real,
+    /// Equilibration applied to the matrix before factorization
+    pub equed: Equilibration,
+}
+
+/// Helper trait to abstract `*gesvx` LAPACK routines for implementing [Lapack::solve_expert]
+///
+/// Row-major matrix is handled as the transposed matrix in the same manner as [SolveImpl].
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | sgesvx | dgesvx | cgesvx | zgesvx |
+///
+pub trait SolveExpertImpl: Scalar {
+    fn solve_expert(
+        l: MatrixLayout,
+        t: Transpose,
+        a: &mut [Self],
+        lu: Option<(&[Self], &Pivot)>,
+        b: &mut [Self],
+    ) -> Result<SolveExpertOwned<Self>>;
+}
+
+macro_rules! impl_solve_expert_c {
+    ($c:ty, $gesvx:path) => {
+        impl SolveExpertImpl for $c {
+            fn solve_expert(
+                l: MatrixLayout,
+                t: Transpose,
+                a: &mut [Self],
+                lu: Option<(&[Self], &Pivot)>,
+                b: &mut [Self],
+            ) -> Result<SolveExpertOwned<Self>> {
+                let (t, conj) = match l {
+                    MatrixLayout::C { .. } => match t {
+                        Transpose::No => (Transpose::Transpose, false),
+                        Transpose::Transpose => (Transpose::No, false),
+                        Transpose::Hermite => (Transpose::No, true),
+                    },
+                    MatrixLayout::F { .. } => (t, false),
+                };
+                let (n, _) = l.size();
+                // Use the given factorization, or equilibrate and factorize `a`
+                let (fact, mut af, mut ipiv) = match lu {
+                    Some((af, ipiv)) => (b'F', af.to_vec(), ipiv.clone()),
+                    None => (b'E', vec![Self::zero(); a.len()], vec![0; n as usize]),
+                };
+                let mut equed = b'N';
+                let mut r: Vec<MaybeUninit<<$c as Scalar>::Real>> = vec_uninit(n as usize);
+                let mut c: Vec<MaybeUninit<<$c as Scalar>::Real>> = vec_uninit(n as usize);
+                let mut x: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut rcond = 0.0;
+                let mut ferr = 0.0;
+                let mut berr = 0.0;
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(std::cmp::max(1, 2 * n) as usize);
+                let mut rwork: Vec<MaybeUninit<<$c as Scalar>::Real>> =
+                    vec_uninit(std::cmp::max(1, 2 * n) as usize);
+                if conj {
+                    for b_elem in &mut *b {
+                        *b_elem = b_elem.conj();
+                    }
+                }
+                let mut info = 0;
+                unsafe {
+                    $gesvx(
+                        &fact as *const u8 as *const i8,
+                        t.as_ptr(),
+                        &n,
+                        &1,
+                        AsPtr::as_mut_ptr(a),
+                        &l.lda(),
+                        AsPtr::as_mut_ptr(&mut af),
+                        &l.lda(),
+                        ipiv.as_mut_ptr(),
+                        &mut equed as *mut u8 as *mut i8,
+                        AsPtr::as_mut_ptr(&mut r),
+                        AsPtr::as_mut_ptr(&mut c),
+                        AsPtr::as_mut_ptr(b),
+                        &l.lda(),
+                        AsPtr::as_mut_ptr(&mut x),
+                        &l.lda(),
+                        &mut rcond,
+                        &mut ferr,
+                        &mut berr,
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        &mut info,
+                    )
+                };
+                // `info = n + 1` means that the matrix is singular to working precision,
+                // but the solution and error bounds are computed.
+                if info != n + 1 {
+                    info.as_lapack_result()?;
+                }
+                b.copy_from_slice(unsafe { x.slice_assume_init_ref() });
+                if conj {
+                    for b_elem in &mut *b {
+                        *b_elem = b_elem.conj();
+                    }
+                }
+                let equed = Equilibration::from_equed(equed);
+                Ok(SolveExpertOwned {
+                    rcond,
+                    ferr,
+                    berr,
+                    equed: match l {
+                        MatrixLayout::C { .. } => equed.t(),
+                        MatrixLayout::F { .. } => equed,
+                    },
+                })
+            }
+        }
+    };
+}
+impl_solve_expert_c!(c64, lapack_sys::zgesvx_);
+impl_solve_expert_c!(c32, lapack_sys::cgesvx_);
+
+macro_rules! impl_solve_expert_r {
+    ($f:ty, $gesvx:path) => {
+        impl SolveExpertImpl for $f {
+            fn solve_expert(
+                l: MatrixLayout,
+                t: Transpose,
+                a: &mut [Self],
+                lu: Option<(&[Self], &Pivot)>,
+                b: &mut [Self],
+            ) -> Result<SolveExpertOwned<Self>> {
+                let t = match l {
+                    MatrixLayout::C { .. } => match t {
+                        Transpose::No => Transpose::Transpose,
+                        Transpose::Transpose | Transpose::Hermite => Transpose::No,
+                    },
+                    MatrixLayout::F { .. } => t,
+                };
+                let (n, _) = l.size();
+                // Use the given factorization, or equilibrate and factorize `a`
+                let (fact, mut af, mut ipiv) = match lu {
+                    Some((af, ipiv)) => (b'F', af.to_vec(), ipiv.clone()),
+                    None => (b'E', vec![Self::zero(); a.len()], vec![0; n as usize]),
+                };
+                let mut equed = b'N';
+                let mut r: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut c: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut x: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut rcond = 0.0;
+                let mut ferr = 0.0;
+                let mut berr = 0.0;
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(std::cmp::max(1, 4 * n) as usize);
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(std::cmp::max(1, n) as usize);
+                let mut info = 0;
+                unsafe {
+                    $gesvx(
+                        &fact as *const u8 as *const i8,
+                        t.as_ptr(),
+                        &n,
+                        &1,
+                        AsPtr::as_mut_ptr(a),
+                        &l.lda(),
+                        AsPtr::as_mut_ptr(&mut af),
+                        &l.lda(),
+                        ipiv.as_mut_ptr(),
+                        &mut equed as *mut u8 as *mut i8,
+                        AsPtr::as_mut_ptr(&mut r),
+                        AsPtr::as_mut_ptr(&mut c),
+                        AsPtr::as_mut_ptr(b),
+                        &l.lda(),
+                        AsPtr::as_mut_ptr(&mut x),
+                        &l.lda(),
+                        &mut rcond,
+                        &mut ferr,
+                        &mut berr,
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    )
+                };
+                // `info = n + 1` means that the matrix is singular to working precision,
+                // but the solution and error bounds are computed.
+                if info != n + 1 {
+                    info.as_lapack_result()?;
+                }
+                b.copy_from_slice(unsafe { x.slice_assume_init_ref() });
+                let equed = Equilibration::from_equed(equed);
+                Ok(SolveExpertOwned {
+                    rcond,
+                    ferr,
+                    berr,
+                    equed: match l {
+                        MatrixLayout::C { .. } => equed.t(),
+                        MatrixLayout::F { .. } => equed,
+                    },
+                })
+            }
+        }
+    };
+}
+impl_solve_expert_r!(f64, lapack_sys::dgesvx_);
+impl_solve_expert_r!(f32, lapack_sys::sgesvx_);
@@ -0,0 +1,244 @@
+//! Solve linear equations using mixed-precision iterative refinement
+//!
+//! The matrix is factorized in single precision, and the solution is refined
+//! in double precision. When the refinement does not converge,
+//! LAPACK solves the system again using the factorization in double precision.
+
+use crate::{error::*, layout::*, *};
+use cauchy::*;
+
+/// Solve linear equations using LU-decomposition in single precision
+/// with iterative refinement in double precision
+///
+/// Since these routines do not have a transpose option,
+/// a row-major matrix is copied into column-major storage before calling LAPACK.
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f64    | c64    |
+/// |:-------|:-------|
+/// | dsgesv | zcgesv |
+///
+pub trait SolveMixedImpl: Scalar {
+    fn solve_mixed(l: MatrixLayout, a: &mut [Self], b: &mut [Self]) -> Result<Refinement>;
+}
+
+macro_rules! impl_solve_mixed_c {
+    ($c:ty, $single:ty, $sgesv:path) => {
+        impl SolveMixedImpl for $c {
+            fn solve_mixed(l: MatrixLayout, a: &mut [Self], b: &mut [Self]) -> Result<Refinement> {
+                let (n, _) = l.size();
+                let nrhs = 1;
+                let mut a_t = None;
+                let a = match l {
+                    MatrixLayout::C { .. } => {
+                        let (_, t) = transpose(l, a);
+                        a_t.insert(t).as_mut_slice()
+                    }
+                    MatrixLayout::F { .. } => a,
+                };
+                let mut ipiv: Vec<MaybeUninit<i32>> = vec_uninit(n as usize);
+                let mut x: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit((n * nrhs) as usize);
+                let mut swork: Vec<MaybeUninit<$single>> = vec_uninit((n * (n + nrhs)) as usize);
+                let mut rwork: Vec<MaybeUninit<Self::Real>> = vec_uninit(n as usize);
+                let mut iter = 0;
+                let mut info = 0;
+                unsafe {
+                    $sgesv(
+                        &n,
+                        &nrhs,
+                        AsPtr::as_mut_ptr(a),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut ipiv),
+                        AsPtr::as_ptr(b),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut x),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut swork),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        &mut iter,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let x = unsafe { x.assume_init() };
+                b.copy_from_slice(&x);
+                Ok(Refinement::from_iter(iter))
+            }
+        }
+    };
+}
+impl_solve_mixed_c!(c64, c32, lapack_sys::zcgesv_);
+
+macro_rules! impl_solve_mixed_r {
+    ($r:ty, $single:ty, $sgesv:path) => {
+        impl SolveMixedImpl for $r {
+            fn solve_mixed(l: MatrixLayout, a: &mut [Self], b: &mut [Self]) -> Result<Refinement> {
+                let (n, _) = l.size();
+                let nrhs = 1;
+                let mut a_t = None;
+                let a = match l {
+                    MatrixLayout::C { .. } => {
+                        let (_, t) = transpose(l, a);
+                        a_t.insert(t).as_mut_slice()
+                    }
+                    MatrixLayout::F { .. } => a,
+                };
+                let mut ipiv: Vec<MaybeUninit<i32>> = vec_uninit(n as usize);
+                let mut x: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit((n * nrhs) as usize);
+                let mut swork: Vec<MaybeUninit<$single>> = vec_uninit((n * (n + nrhs)) as usize);
+                let mut iter = 0;
+                let mut info = 0;
+                unsafe {
+                    $sgesv(
+                        &n,
+                        &nrhs,
+                        AsPtr::as_mut_ptr(a),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut ipiv),
+                        AsPtr::as_ptr(b),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut x),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut swork),
+                        &mut iter,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let x = unsafe { x.assume_init() };
+                b.copy_from_slice(&x);
+                Ok(Refinement::from_iter(iter))
+            }
+        }
+    };
+}
+impl_solve_mixed_r!(f64, f32, lapack_sys::dsgesv_);
+
+/// Solve symmetric/Hermitian positive definite linear equations
+/// using Cholesky decomposition in single precision with iterative refinement in double precision
+///
+/// Row-major matrix is handled in the same manner as [crate::cholesky::SolveCholeskyImpl].
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f64    | c64    |
+/// |:-------|:-------|
+/// | dsposv | zcposv |
+///
+pub trait SolveCholeskyMixedImpl: Scalar {
+    fn solve_cholesky_mixed(
+        l: MatrixLayout,
+        uplo: UPLO,
+        a: &mut [Self],
+        b: &mut [Self],
+    ) -> Result<Refinement>;
+}
+
+macro_rules! impl_solve_cholesky_mixed_c {
+    ($c:ty, $single:ty, $sposv:path) => {
+        impl SolveCholeskyMixedImpl for $c {
+            fn solve_cholesky_mixed(
+                l: MatrixLayout,
+                mut uplo: UPLO,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<Refinement> {
+                let (n, _) = l.size();
+                let nrhs = 1;
+                if matches!(l, MatrixLayout::C { .. }) {
+                    uplo = uplo.t();
+                    for val in b.iter_mut() {
+                        *val = val.conj();
+                    }
+                }
+                let mut x: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit((n * nrhs) as usize);
+                let mut swork: Vec<MaybeUninit<$single>> = vec_uninit((n * (n + nrhs)) as usize);
+                let mut rwork: Vec<MaybeUninit<Self::Real>> = vec_uninit(n as usize);
+                let mut iter = 0;
+                let mut info = 0;
+                unsafe {
+                    $sposv(
+                        uplo.as_ptr(),
+                        &n,
+                        &nrhs,
+                        AsPtr::as_mut_ptr(a),
+                        &l.lda(),
+                        AsPtr::as_ptr(b),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut x),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut swork),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        &mut iter,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let x = unsafe { x.assume_init() };
+                b.copy_from_slice(&x);
+                if matches!(l, MatrixLayout::C { .. }) {
+                    for val in b.iter_mut() {
+                        *val = val.conj();
+                    }
+                }
+                Ok(Refinement::from_iter(iter))
+            }
+        }
+    };
+}
+impl_solve_cholesky_mixed_c!(c64, c32, lapack_sys::zcposv_);
+
+macro_rules! impl_solve_cholesky_mixed_r {
+    ($r:ty, $single:ty, $sposv:path) => {
+        impl SolveCholeskyMixedImpl for $r {
+            fn solve_cholesky_mixed(
+                l: MatrixLayout,
+                mut uplo: UPLO,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<Refinement> {
+                let (n, _) = l.size();
+                let nrhs = 1;
+                if matches!(l, MatrixLayout::C { .. }) {
+                    uplo = uplo.t();
+                }
+                let mut x: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit((n * nrhs) as usize);
+                let mut swork: Vec<MaybeUninit<$single>> = vec_uninit((n * (n + nrhs)) as usize);
+                let mut iter = 0;
+                let mut info = 0;
+                unsafe {
+                    $sposv(
+                        uplo.as_ptr(),
+                        &n,
+                        &nrhs,
+                        AsPtr::as_mut_ptr(a),
+                        &l.lda(),
+                        AsPtr::as_ptr(b),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut x),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut swork),
+                        &mut iter,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let x = unsafe { x.assume_init() };
+                b.copy_from_slice(&x);
+                Ok(Refinement::from_iter(iter))
+            }
+        }
+    };
+}
+impl_solve_cholesky_mixed_r!(f64, f32, lapack_sys::dsposv_);
@@ -1,6 +1,9 @@
 //! Factorize symmetric/Hermitian matrix using [Bunch-Kaufman diagonal pivoting method][BK]
 //!
+//! The rook pivoting variant and [Aasen's algorithm][Aasen] are also available via [PivotStrategy].
+//!
 //! [BK]: https://doi.org/10.2307/2005787
+//! [Aasen]: https://doi.org/10.1007/BF01931877
 //!
 
 use crate::{error::*, layout::MatrixLayout, *};
@@ -9,38 +12,54 @@ use num_traits::{ToPrimitive, Zero};
 
 pub struct BkWork<T: Scalar> {
     pub layout: MatrixLayout,
+    pub strategy: PivotStrategy,
     pub work: Vec<MaybeUninit<T>>,
     pub ipiv: Vec<MaybeUninit<i32>>,
 }
 
 /// Factorize symmetric/Hermitian matrix using Bunch-Kaufman diagonal pivoting method
 ///
+/// [BkWorkImpl::new] uses Bunch-Kaufman pivoting,
+/// and other strategies are selected by [BkWorkImpl::new_with_strategy].
+///
 /// LAPACK correspondance
 /// ----------------------
 ///
-/// | f32    | f64    | c32    | c64    |
-/// |:-------|:-------|:-------|:-------|
-/// | ssytrf | dsytrf | chetrf | zhetrf |
+/// | strategy                     | f32         | f64         | c32         | c64         |
+/// |:-----------------------------|:------------|:------------|:------------|:------------|
+/// | [PivotStrategy::BunchKaufman] | ssytrf      | dsytrf      | chetrf      | zhetrf      |
+/// | [PivotStrategy::Rook]         | ssytrf_rook | dsytrf_rook | chetrf_rook | zhetrf_rook |
+/// | [PivotStrategy::Aasen]        | ssytrf_aa   | dsytrf_aa   | chetrf_aa   | zhetrf_aa   |
 ///
 pub trait BkWorkImpl: Sized {
     type Elem: Scalar;
     fn new(l: MatrixLayout) -> Result<Self>;
+    fn new_with_strategy(l: MatrixLayout, strategy: PivotStrategy) -> Result<Self>;
     fn calc(&mut self, uplo: UPLO, a: &mut [Self::Elem]) -> Result<&[i32]>;
     fn eval(self, uplo: UPLO, a: &mut [Self::Elem]) -> Result<Pivot>;
 }
 
 macro_rules! impl_bk_work {
-    ($s:ty, $trf:path) => {
+    ($s:ty, $trf:path, $trf_rook:path, $trf_aa:path) => {
         impl BkWorkImpl for BkWork<$s> {
             type Elem = $s;
 
             fn new(layout: MatrixLayout) -> Result<Self> {
+                Self::new_with_strategy(layout, PivotStrategy::BunchKaufman)
+            }
+
+            fn new_with_strategy(layout: MatrixLayout, strategy: PivotStrategy) -> Result<Self> {
                 let (n, _) = layout.size();
                 let ipiv = vec_uninit(n as usize);
                 let mut info = 0;
                 let mut work_size = [Self::Elem::zero()];
+                let trf = match strategy {
+                    PivotStrategy::BunchKaufman => $trf,
+                    PivotStrategy::Rook => $trf_rook,
+                    PivotStrategy::Aasen => $trf_aa,
+                };
                 unsafe {
-                    $trf(
+                    trf(
                         UPLO::Upper.as_ptr(),
                         &n,
                         std::ptr::null_mut(),
@@ -54,7 +73,12 @@ macro_rules! impl_bk_work {
                 info.as_lapack_result()?;
                 let lwork = work_size[0].to_usize().unwrap();
                 let work = vec_uninit(lwork);
-                Ok(BkWork { layout, work, ipiv })
+                Ok(BkWork {
+                    layout,
+                    strategy,
+                    work,
+                    ipiv,
+                })
             }
 
             fn calc(&mut self, uplo: UPLO, a: &mut [Self::Elem]) -> Result<&[i32]> {
@@ -63,9 +87,14 @@ macro_rules! impl_bk_work {
                 if lwork == 0 {
                     return Ok(&[]);
                 }
+                let trf = match self.strategy {
+                    PivotStrategy::BunchKaufman => $trf,
+                    PivotStrategy::Rook => $trf_rook,
+                    PivotStrategy::Aasen => $trf_aa,
+                };
                 let mut info = 0;
                 unsafe {
-                    $trf(
+                    trf(
                         uplo.as_ptr(),
                         &n,
                         AsPtr::as_mut_ptr(a),
@@ -87,10 +116,30 @@ macro_rules! impl_bk_work {
         }
     };
 }
-impl_bk_work!(c64, lapack_sys::zhetrf_);
-impl_bk_work!(c32, lapack_sys::chetrf_);
-impl_bk_work!(f64, lapack_sys::dsytrf_);
-impl_bk_work!(f32, lapack_sys::ssytrf_);
+impl_bk_work!(
+    c64,
+    lapack_sys::zhetrf_,
+    lapack_sys::zhetrf_rook_,
+    lapack_sys::zhetrf_aa_
+);
+impl_bk_work!(
+    c32,
+    lapack_sys::chetrf_,
+    lapack_sys::chetrf_rook_,
+    lapack_sys::chetrf_aa_
+);
+impl_bk_work!(
+    f64,
+    lapack_sys::dsytrf_,
+    lapack_sys::dsytrf_rook_,
+    lapack_sys::dsytrf_aa_
+);
+impl_bk_work!(
+    f32,
+    lapack_sys::ssytrf_,
+    lapack_sys::ssytrf_rook_,
+    lapack_sys::ssytrf_aa_
+);
 
 pub struct InvhWork<T: Scalar> {
     pub layout: MatrixLayout,
@@ -148,8 +197,20 @@ impl_invh_work!(c32, lapack_sys::chetri_);
 impl_invh_work!(f64, lapack_sys::dsytri_);
 impl_invh_work!(f32, lapack_sys::ssytri_);
 
+/// Conjugate `b` in place if the matrix is row-major
+fn conjugate_row_major<T: Scalar>(l: MatrixLayout, b: &mut [T]) {
+    if let MatrixLayout::C { .. } = l {
+        for val in b.iter_mut() {
+            *val = val.conj();
+        }
+    }
+}
+
 /// Solve symmetric/Hermitian linear equation
 ///
+/// A row-major Hermitian matrix is regarded as its conjugate in column-major,
+/// and thus `b` is conjugated before and after solving.
+///
 /// LAPACK correspondance
 /// ----------------------
 ///
@@ -172,6 +233,7 @@ macro_rules! impl_solveh_ {
                 b: &mut [Self],
             ) -> Result<()> {
                 let (n, _) = l.size();
+                conjugate_row_major(l, b);
                 let mut info = 0;
                 unsafe {
                     $trs(
@@ -187,6 +249,7 @@ macro_rules! impl_solveh_ {
                     )
                 };
                 info.as_lapack_result()?;
+                conjugate_row_major(l, b);
                 Ok(())
             }
         }
@@ -197,3 +260,320 @@ impl_solveh_!(c64, lapack_sys::zhetrs_);
 impl_solveh_!(c32, lapack_sys::chetrs_);
 impl_solveh_!(f64, lapack_sys::dsytrs_);
 impl_solveh_!(f32, lapack_sys::ssytrs_);
+
+/// Solve symmetric/Hermitian linear equation using the factorization
+/// computed with the given [PivotStrategy]
+///
+/// As in [SolvehImpl], a row-major Hermitian matrix is regarded as its conjugate
+/// in column-major, and `b` is conjugated before and after solving.
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | strategy                     | f32         | f64         | c32         | c64         |
+/// |:-----------------------------|:------------|:------------|:------------|:------------|
+/// | [PivotStrategy::BunchKaufman] | ssytrs      | dsytrs      | chetrs      | zhetrs      |
+/// | [PivotStrategy::Rook]         | ssytrs_rook | dsytrs_rook | chetrs_rook | zhetrs_rook |
+/// | [PivotStrategy::Aasen]        | ssytrs_aa   | dsytrs_aa   | chetrs_aa   | zhetrs_aa   |
+///
+pub trait SolveLdlImpl: Scalar {
+    fn solve_ldl(
+        l: MatrixLayout,
+        uplo: UPLO,
+        strategy: PivotStrategy,
+        a: &[Self],
+        ipiv: &Pivot,
+        b: &mut [Self],
+    ) -> Result<()>;
+}
+
+macro_rules! impl_solve_ldl {
+    ($s:ty, $trs_rook:path, $trs_aa:path) => {
+        impl SolveLdlImpl for $s {
+            fn solve_ldl(
+                l: MatrixLayout,
+                uplo: UPLO,
+                strategy: PivotStrategy,
+                a: &[Self],
+                ipiv: &Pivot,
+                b: &mut [Self],
+            ) -> Result<()> {
+                let (n, _) = l.size();
+                match strategy {
+                    PivotStrategy::BunchKaufman => SolvehImpl::solveh(l, uplo, a, ipiv, b),
+                    PivotStrategy::Rook => {
+                        conjugate_row_major(l, b);
+                        let mut info = 0;
+                        unsafe {
+                            $trs_rook(
+                                uplo.as_ptr(),
+                                &n,
+                                &1,
+                                AsPtr::as_ptr(a),
+                                &l.lda(),
+                                ipiv.as_ptr(),
+                                AsPtr::as_mut_ptr(b),
+                                &n,
+                                &mut info,
+                            )
+                        };
+                        info.as_lapack_result()?;
+                        conjugate_row_major(l, b);
+                        Ok(())
+                    }
+                    PivotStrategy::Aasen => {
+                        conjugate_row_major(l, b);
+                        let lwork = std::cmp::max(1, 3 * n - 2);
+                        let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork as usize);
+                        let mut info = 0;
+                        unsafe {
+                            $trs_aa(
+                                uplo.as_ptr(),
+                                &n,
+                                &1,
+                                AsPtr::as_ptr(a),
+                                &l.lda(),
+                                ipiv.as_ptr(),
+                                AsPtr::as_mut_ptr(b),
+                                &n,
+                                AsPtr::as_mut_ptr(&mut work),
+                                &lwork,
+                                &mut info,
+                            )
+                        };
+                        info.as_lapack_result()?;
+                        conjugate_row_major(l, b);
+                        Ok(())
+                    }
+                }
+            }
+        }
+    };
+}
+impl_solve_ldl!(c64, lapack_sys::zhetrs_rook_, lapack_sys::zhetrs_aa_);
+impl_solve_ldl!(c32, lapack_sys::chetrs_rook_, lapack_sys::chetrs_aa_);
+impl_solve_ldl!(f64, lapack_sys::dsytrs_rook_, lapack_sys::dsytrs_aa_);
+impl_solve_ldl!(f32, lapack_sys::ssytrs_rook_, lapack_sys::ssytrs_aa_);
+
+/// Solve symmetric/Hermitian linear equation with iterative refinement and error bounds
+/// using Bunch-Kaufman diagonal pivoting method
+///
+/// Equilibration is not supported by these routines,
+/// and thus [SolveExpertOwned::equed] is always [Equilibration::None].
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | ssysvx | dsysvx | chesvx | zhesvx |
+///
+pub trait SolvehExpertImpl: Scalar {
+    fn solveh_expert(
+        l: MatrixLayout,
+        uplo: UPLO,
+        a: &[Self],
+        bk: Option<(&[Self], &Pivot)>,
+        b: &mut [Self],
+    ) -> Result<SolveExpertOwned<Self>>;
+}
+
+macro_rules! impl_solveh_expert_c {
+    ($c:ty, $hesvx:path) => {
+        impl SolvehExpertImpl for $c {
+            fn solveh_expert(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &[Self],
+                bk: Option<(&[Self], &Pivot)>,
+                b: &mut [Self],
+            ) -> Result<SolveExpertOwned<Self>> {
+                let (n, _) = l.size();
+                // Use the given factorization, or factorize `a`
+                let (fact, mut af, mut ipiv) = match bk {
+                    Some((af, ipiv)) => (b'F', af.to_vec(), ipiv.clone()),
+                    None => (b'N', vec![Self::zero(); a.len()], vec![0; n as usize]),
+                };
+                let mut x: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut rcond = 0.0;
+                let mut ferr = 0.0;
+                let mut berr = 0.0;
+                let mut rwork: Vec<MaybeUninit<<$c as Scalar>::Real>> =
+                    vec_uninit(std::cmp::max(1, n) as usize);
+                // Row-major Hermitian matrix is regarded as its conjugate in column-major
+                if matches!(l, MatrixLayout::C { .. }) {
+                    for val in b.iter_mut() {
+                        *val = val.conj();
+                    }
+                }
+
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $hesvx(
+                        &fact as *const u8 as *const i8,
+                        uplo.as_ptr(),
+                        &n,
+                        &1,
+                        AsPtr::as_ptr(a),
+                        &l.lda(),
+                        AsPtr::as_mut_ptr(&mut af),
+                        &l.lda(),
+                        ipiv.as_mut_ptr(),
+                        AsPtr::as_ptr(b),
+                        &l.lda(),
+                        AsPtr::as_mut_ptr(&mut x),
+                        &l.lda(),
+                        &mut rcond,
+                        &mut ferr,
+                        &mut berr,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $hesvx(
+                        &fact as *const u8 as *const i8,
+                        uplo.as_ptr(),
+                        &n,
+                        &1,
+                        AsPtr::as_ptr(a),
+                        &l.lda(),
+                        AsPtr::as_mut_ptr(&mut af),
+                        &l.lda(),
+                        ipiv.as_mut_ptr(),
+                        AsPtr::as_ptr(b),
+                        &l.lda(),
+                        AsPtr::as_mut_ptr(&mut x),
+                        &l.lda(),
+                        &mut rcond,
+                        &mut ferr,
+                        &mut berr,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        &mut info,
+                    )
+                };
+                // `info = n + 1` means that the matrix is singular to working precision,
+                // but the solution and error bounds are computed.
+                if info != n + 1 {
+                    info.as_lapack_result()?;
+                }
+                b.copy_from_slice(unsafe { x.slice_assume_init_ref() });
+                if matches!(l, MatrixLayout::C { .. }) {
+                    for val in b.iter_mut() {
+                        *val = val.conj();
+                    }
+                }
+                Ok(SolveExpertOwned {
+                    rcond,
+                    ferr,
+                    berr,
+                    equed: Equilibration::None,
+                })
+            }
+        }
+    };
+}
+impl_solveh_expert_c!(c64, lapack_sys::zhesvx_);
+impl_solveh_expert_c!(c32, lapack_sys::chesvx_);
+
+macro_rules! impl_solveh_expert_r {
+    ($f:ty, $sysvx:path) => {
+        impl SolvehExpertImpl for $f {
+            fn solveh_expert(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &[Self],
+                bk: Option<(&[Self], &Pivot)>,
+                b: &mut [Self],
+            ) -> Result<SolveExpertOwned<Self>> {
+                let (n, _) = l.size();
+                // Use the given factorization, or factorize `a`
+                let (fact, mut af, mut ipiv) = match bk {
+                    Some((af, ipiv)) => (b'F', af.to_vec(), ipiv.clone()),
+                    None => (b'N', vec![Self::zero(); a.len()], vec![0; n as usize]),
+                };
+                let mut x: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut rcond = 0.0;
+                let mut ferr = 0.0;
+                let mut berr = 0.0;
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(std::cmp::max(1, n) as usize);
+
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $sysvx(
+                        &fact as *const u8 as *const i8,
+                        uplo.as_ptr(),
+                        &n,
+                        &1,
+                        AsPtr::as_ptr(a),
+                        &l.lda(),
+                        AsPtr::as_mut_ptr(&mut af),
+                        &l.lda(),
+                        ipiv.as_mut_ptr(),
+                        AsPtr::as_ptr(b),
+                        &l.lda(),
+                        AsPtr::as_mut_ptr(&mut x),
+                        &l.lda(),
+                        &mut rcond,
+                        &mut ferr,
+                        &mut berr,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $sysvx(
+                        &fact as *const u8 as *const i8,
+                        uplo.as_ptr(),
+                        &n,
+                        &1,
+                        AsPtr::as_ptr(a),
+                        &l.lda(),
+                        AsPtr::as_mut_ptr(&mut af),
+                        &l.lda(),
+                        ipiv.as_mut_ptr(),
+                        AsPtr::as_ptr(b),
+                        &l.lda(),
+                        AsPtr::as_mut_ptr(&mut x),
+                        &l.lda(),
+                        &mut rcond,
+                        &mut ferr,
+                        &mut berr,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    )
+                };
+                // `info = n + 1` means that the matrix is singular to working precision,
+                // but the solution and error bounds are computed.
+                if info != n + 1 {
+                    info.as_lapack_result()?;
+                }
+                b.copy_from_slice(unsafe { x.slice_assume_init_ref() });
+                Ok(SolveExpertOwned {
+                    rcond,
+                    ferr,
+                    berr,
+                    equed: Equilibration::None,
+                })
+            }
+        }
+    };
+}
+impl_solveh_expert_r!(f64, lapack_sys::dsysvx_);
+impl_solveh_expert_r!(f32, lapack_sys::ssysvx_);
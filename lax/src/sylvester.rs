@@ -0,0 +1,109 @@
+//! Sylvester equation for (quasi) upper triangular matrices
+
+use crate::{error::*, layout::*, *};
+use cauchy::*;
+use num_traits::Zero;
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Solve Sylvester equation for (quasi) upper triangular matrices
+///
+/// This solves
+/// $$
+/// \mathrm{op}(A) X + s X \mathrm{op}(B) = \alpha C
+/// $$
+/// where $\mathrm{op}$ is specified by [Transpose], $s = \pm 1$ is specified by `isgn`,
+/// and $A$ and $B$ are in the Schur canonical form computed by [SchurWork](crate::schur::SchurWork),
+/// i.e. upper triangular for complex matrices and quasi upper triangular for real matrices.
+/// $C$ is overwritten by $X$, and the scaling factor $\alpha \le 1$
+/// chosen to avoid overflow is returned.
+///
+/// `l` is the layout of $C$ of size $m \times n$,
+/// and $A$ of size $m \times m$ and $B$ of size $n \times n$ must be in the same order.
+/// Row-major matrices are copied into column-major order since LAPACK only accepts the latter.
+///
+/// This returns [Error::LapackComputationalFailure] with `return_code = 1`
+/// if the spectra of $\mathrm{op}(A)$ and $-s\mathrm{op}(B)$ are (nearly) overlapping,
+/// i.e. the equation is (nearly) singular.
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | strsyl | dtrsyl | ctrsyl | ztrsyl |
+///
+pub trait SylvesterImpl: Scalar {
+    fn sylvester(
+        l: MatrixLayout,
+        trana: Transpose,
+        tranb: Transpose,
+        isgn: i32,
+        a: &[Self],
+        b: &[Self],
+        c: &mut [Self],
+    ) -> Result<Self::Real>;
+}
+
+macro_rules! impl_sylvester {
+    ($s:ty, $trsyl:path) => {
+        impl SylvesterImpl for $s {
+            fn sylvester(
+                l: MatrixLayout,
+                trana: Transpose,
+                tranb: Transpose,
+                isgn: i32,
+                a: &[Self],
+                b: &[Self],
+                c: &mut [Self],
+            ) -> Result<Self::Real> {
+                let (m, n) = l.size();
+                // LAPACK only accepts column-major matrices,
+                // and the (quasi) upper triangular structure must be kept
+                let is_c = matches!(l, MatrixLayout::C { .. });
+                let (a_t, b_t, mut c_t) = if is_c {
+                    (
+                        transpose(MatrixLayout::C { row: m, lda: m }, a).1,
+                        transpose(MatrixLayout::C { row: n, lda: n }, b).1,
+                        transpose(l, c).1,
+                    )
+                } else {
+                    (Vec::new(), Vec::new(), Vec::new())
+                };
+                let (a, b, c_f): (&[Self], &[Self], &mut [Self]) = if is_c {
+                    (&a_t, &b_t, &mut c_t)
+                } else {
+                    (a, b, &mut *c)
+                };
+                let mut scale = Self::Real::zero();
+                let mut info = 0;
+                unsafe {
+                    $trsyl(
+                        trana.as_ptr(),
+                        tranb.as_ptr(),
+                        &isgn,
+                        &m,
+                        &n,
+                        AsPtr::as_ptr(a),
+                        &m.max(1),
+                        AsPtr::as_ptr(b),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(c_f),
+                        &m.max(1),
+                        &mut scale,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                if is_c {
+                    transpose_over(MatrixLayout::F { col: n, lda: m }, &c_t, c);
+                }
+                Ok(scale)
+            }
+        }
+    };
+}
+
+impl_sylvester!(f64, lapack_sys::dtrsyl_);
+impl_sylvester!(f32, lapack_sys::strsyl_);
+impl_sylvester!(c64, lapack_sys::ztrsyl_);
+impl_sylvester!(c32, lapack_sys::ctrsyl_);
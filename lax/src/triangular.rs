@@ -2,6 +2,7 @@
 
 use crate::{error::*, layout::*, *};
 use cauchy::*;
+use num_traits::{One, Zero};
 
 /// Solve linear problem for triangular matrices
 ///
@@ -91,3 +92,279 @@ impl_triangular!(f64, lapack_sys::dtrtrs_);
 impl_triangular!(f32, lapack_sys::strtrs_);
 impl_triangular!(c64, lapack_sys::ztrtrs_);
 impl_triangular!(c32, lapack_sys::ctrtrs_);
+
+/// Compute inverse matrix of triangular matrix
+///
+/// Only the triangular part specified by [UPLO] is referenced and overwritten,
+/// and the diagonal elements are not referenced if [Diag::Unit].
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | strtri | dtrtri | ctrtri | ztrtri |
+///
+pub trait InvTriangularImpl: Scalar {
+    fn inv_triangular(l: MatrixLayout, uplo: UPLO, diag: Diag, a: &mut [Self]) -> Result<()>;
+}
+
+macro_rules! impl_inv_triangular {
+    ($scalar:ty, $trtri:path) => {
+        impl InvTriangularImpl for $scalar {
+            fn inv_triangular(
+                l: MatrixLayout,
+                mut uplo: UPLO,
+                diag: Diag,
+                a: &mut [Self],
+            ) -> Result<()> {
+                let (n, _) = l.size();
+                // Row-major matrix is regarded as its transpose in column-major,
+                // whose inverse is the transpose of the inverse
+                if matches!(l, MatrixLayout::C { .. }) {
+                    uplo = uplo.t();
+                }
+                let mut info = 0;
+                unsafe {
+                    $trtri(
+                        uplo.as_ptr(),
+                        diag.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &l.lda(),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_inv_triangular!(f64, lapack_sys::dtrtri_);
+impl_inv_triangular!(f32, lapack_sys::strtri_);
+impl_inv_triangular!(c64, lapack_sys::ztrtri_);
+impl_inv_triangular!(c32, lapack_sys::ctrtri_);
+
+/// Estimate the reciprocal condition number of triangular matrix in 1-norm
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | strcon | dtrcon | ctrcon | ztrcon |
+///
+pub trait RcondTriangularImpl: Scalar {
+    fn rcond_triangular(l: MatrixLayout, uplo: UPLO, diag: Diag, a: &[Self]) -> Result<Self::Real>;
+}
+
+macro_rules! impl_rcond_triangular_c {
+    ($c:ty, $trcon:path) => {
+        impl RcondTriangularImpl for $c {
+            fn rcond_triangular(
+                l: MatrixLayout,
+                uplo: UPLO,
+                diag: Diag,
+                a: &[Self],
+            ) -> Result<Self::Real> {
+                let (n, _) = l.size();
+                let mut rcond = Self::Real::zero();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(2 * n as usize);
+                let mut rwork: Vec<MaybeUninit<Self::Real>> = vec_uninit(n as usize);
+                // 1-norm of row-major matrix is the infinity-norm of its transpose in column-major
+                let (norm_type, uplo) = match l {
+                    MatrixLayout::C { .. } => (NormType::Infinity, uplo.t()),
+                    MatrixLayout::F { .. } => (NormType::One, uplo),
+                };
+                let mut info = 0;
+                unsafe {
+                    $trcon(
+                        norm_type.as_ptr(),
+                        uplo.as_ptr(),
+                        diag.as_ptr(),
+                        &n,
+                        AsPtr::as_ptr(a),
+                        &l.lda(),
+                        &mut rcond,
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(rcond)
+            }
+        }
+    };
+}
+
+impl_rcond_triangular_c!(c64, lapack_sys::ztrcon_);
+impl_rcond_triangular_c!(c32, lapack_sys::ctrcon_);
+
+macro_rules! impl_rcond_triangular_r {
+    ($r:ty, $trcon:path) => {
+        impl RcondTriangularImpl for $r {
+            fn rcond_triangular(
+                l: MatrixLayout,
+                uplo: UPLO,
+                diag: Diag,
+                a: &[Self],
+            ) -> Result<Self::Real> {
+                let (n, _) = l.size();
+                let mut rcond = Self::Real::zero();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(3 * n as usize);
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(n as usize);
+                // 1-norm of row-major matrix is the infinity-norm of its transpose in column-major
+                let (norm_type, uplo) = match l {
+                    MatrixLayout::C { .. } => (NormType::Infinity, uplo.t()),
+                    MatrixLayout::F { .. } => (NormType::One, uplo),
+                };
+                let mut info = 0;
+                unsafe {
+                    $trcon(
+                        norm_type.as_ptr(),
+                        uplo.as_ptr(),
+                        diag.as_ptr(),
+                        &n,
+                        AsPtr::as_ptr(a),
+                        &l.lda(),
+                        &mut rcond,
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(rcond)
+            }
+        }
+    };
+}
+
+impl_rcond_triangular_r!(f64, lapack_sys::dtrcon_);
+impl_rcond_triangular_r!(f32, lapack_sys::strcon_);
+
+/// Multiply triangular matrix `A` to general matrix `B`,
+/// i.e. `B = A * B` for [Side::Left] or `B = B * A` for [Side::Right]
+///
+/// Only the triangular part of `A` specified by [UPLO] is referenced.
+/// `A` and `B` may have different layouts.
+///
+/// BLAS correspondance
+/// --------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | strmm  | dtrmm  | ctrmm  | ztrmm  |
+///
+pub trait MultiplyTriangularImpl: Scalar {
+    fn multiply_triangular(
+        al: MatrixLayout,
+        bl: MatrixLayout,
+        side: Side,
+        uplo: UPLO,
+        diag: Diag,
+        a: &[Self],
+        b: &mut [Self],
+    ) -> Result<()>;
+}
+
+/// BLAS `?trmm` provided by the BLAS library linked with LAPACK, which `lapack-sys` does not bind
+mod blas {
+    use lapack_sys::__BindgenComplex;
+    use std::os::raw::{c_char, c_int};
+
+    macro_rules! declare_trmm {
+        ($trmm:ident, $elem:ty) => {
+            extern "C" {
+                pub fn $trmm(
+                    side: *const c_char,
+                    uplo: *const c_char,
+                    transa: *const c_char,
+                    diag: *const c_char,
+                    m: *const c_int,
+                    n: *const c_int,
+                    alpha: *const $elem,
+                    a: *const $elem,
+                    lda: *const c_int,
+                    b: *mut $elem,
+                    ldb: *const c_int,
+                );
+            }
+        };
+    }
+    declare_trmm!(strmm_, f32);
+    declare_trmm!(dtrmm_, f64);
+    declare_trmm!(ctrmm_, __BindgenComplex<f32>);
+    declare_trmm!(ztrmm_, __BindgenComplex<f64>);
+}
+
+macro_rules! impl_multiply_triangular {
+    ($s:ty, $trmm:path) => {
+        impl MultiplyTriangularImpl for $s {
+            fn multiply_triangular(
+                al: MatrixLayout,
+                bl: MatrixLayout,
+                side: Side,
+                uplo: UPLO,
+                diag: Diag,
+                a: &[Self],
+                b: &mut [Self],
+            ) -> Result<()> {
+                let (n, n_) = al.size();
+                let (rows, cols) = bl.size();
+                let k = match side {
+                    Side::Left => rows,
+                    Side::Right => cols,
+                };
+                if n != n_ || n != k {
+                    return Err(Error::InvalidShape);
+                }
+                // Row-major `B` is regarded as its transpose in column-major,
+                // i.e. `B = A * B` is computed as `B^T = B^T * A^T`
+                let (side, m, n) = match bl {
+                    MatrixLayout::F { .. } => (side, rows, cols),
+                    MatrixLayout::C { .. } => match side {
+                        Side::Left => (Side::Right, cols, rows),
+                        Side::Right => (Side::Left, cols, rows),
+                    },
+                };
+                // `A` is transposed if it is in the other order than `B`
+                let trans = if al.same_order(&bl) {
+                    Transpose::No
+                } else {
+                    Transpose::Transpose
+                };
+                // The triangle referenced in column-major
+                let uplo = match al {
+                    MatrixLayout::F { .. } => uplo,
+                    MatrixLayout::C { .. } => uplo.t(),
+                };
+                let alpha = [Self::one()];
+                unsafe {
+                    $trmm(
+                        side.as_ptr(),
+                        uplo.as_ptr(),
+                        trans.as_ptr(),
+                        diag.as_ptr(),
+                        &m,
+                        &n,
+                        AsPtr::as_ptr(&alpha),
+                        AsPtr::as_ptr(a),
+                        &al.lda(),
+                        AsPtr::as_mut_ptr(b),
+                        &bl.lda(),
+                    );
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_multiply_triangular!(f64, blas::dtrmm_);
+impl_multiply_triangular!(f32, blas::strmm_);
+impl_multiply_triangular!(c64, blas::ztrmm_);
+impl_multiply_triangular!(c32, blas::ctrmm_);
@@ -0,0 +1,320 @@
+//! Eigenvalue problem for symmetric/Hermitian tridiagonal matrices
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    |
+//! |:-------|:-------|
+//! | sstev  | dstev  |
+//! | sstemr | dstemr |
+//! | sstebz | dstebz |
+//! | sstein | dstein |
+//!
+//! These routines are defined only for real symmetric tridiagonal matrices.
+//! A Hermitian tridiagonal matrix $T$ is reduced to the real symmetric one $S = D^H T D$
+//! by a diagonal unitary matrix $D$, and the eigenvectors of $T$ are recovered as $D w$
+//! from the eigenvectors $w$ of $S$.
+
+use crate::{eigh_range::range_args, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Helper trait to abstract `?stev`, `?stemr`, `?stebz` and `?stein` LAPACK routines
+/// for implementing [Lapack::eigh_tridiagonal] and [Lapack::eigh_tridiagonal_inverse_iteration]
+///
+/// Only the diagonal `d` and the sub-diagonal `dl` of [Tridiagonal] are referenced,
+/// i.e. the super-diagonal is assumed to be $\overline{dl}$.
+/// The imaginary part of `d` is ignored.
+pub trait EighTridiagonalImpl: Scalar {
+    fn eigh_tridiagonal(
+        calc_eigenvec: bool,
+        range: EigRange<Self::Real>,
+        a: &Tridiagonal<Self>,
+    ) -> Result<EighRangeOwned<Self>>;
+
+    fn eigh_tridiagonal_inverse_iteration(
+        range: EigRange<Self::Real>,
+        a: &Tridiagonal<Self>,
+    ) -> Result<EighRangeOwned<Self>>;
+}
+
+macro_rules! impl_eigh_tridiagonal_r {
+    ($r:ty, $stev:path, $stemr:path, $stebz:path, $stein:path) => {
+        impl EighTridiagonalImpl for $r {
+            fn eigh_tridiagonal(
+                calc_eigenvec: bool,
+                range: EigRange<Self::Real>,
+                a: &Tridiagonal<Self>,
+            ) -> Result<EighRangeOwned<Self>> {
+                let (n, _) = a.l.size();
+                let jobz = if calc_eigenvec {
+                    JobEv::All
+                } else {
+                    JobEv::None
+                };
+                let mut d = a.d.clone();
+                // `?stemr` uses the last element of `e` as workspace
+                let mut e = a.dl.clone();
+                e.push(Self::zero());
+                let mut info = 0;
+
+                if let EigRange::All = range {
+                    let mut z = jobz.then(|| vec_uninit((n * n) as usize));
+                    let mut work: Vec<MaybeUninit<Self>> =
+                        vec_uninit(std::cmp::max(1, 2 * n - 2) as usize);
+                    unsafe {
+                        $stev(
+                            jobz.as_ptr(),
+                            &n,
+                            AsPtr::as_mut_ptr(&mut d),
+                            AsPtr::as_mut_ptr(&mut e),
+                            AsPtr::as_mut_ptr(z.as_deref_mut().unwrap_or(&mut [])),
+                            &n,
+                            AsPtr::as_mut_ptr(&mut work),
+                            &mut info,
+                        );
+                    }
+                    info.as_lapack_result()?;
+                    let z = z.map(|z| unsafe { z.assume_init() });
+                    return Ok(EighRangeOwned { eigs: d, z });
+                }
+
+                let m_max = range.max_len(n as usize);
+                let nzc = m_max as i32;
+                let (vl, vu, il, iu) = range_args(range);
+                let mut eigs: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut z = jobz.then(|| vec_uninit(n as usize * m_max));
+                let mut isuppz: Vec<MaybeUninit<i32>> = vec_uninit(2 * std::cmp::max(1, m_max));
+                let mut tryrac = 1;
+                let mut m = 0;
+
+                // calc work size
+                let mut work_size = [Self::zero()];
+                let mut iwork_size = [0];
+                unsafe {
+                    $stemr(
+                        jobz.as_ptr(),
+                        range.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut d),
+                        AsPtr::as_mut_ptr(&mut e),
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &mut m,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(z.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        &nzc,
+                        AsPtr::as_mut_ptr(&mut isuppz),
+                        &mut tryrac,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut iwork_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let liwork = iwork_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(liwork);
+                unsafe {
+                    $stemr(
+                        jobz.as_ptr(),
+                        range.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut d),
+                        AsPtr::as_mut_ptr(&mut e),
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &mut m,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(z.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        &nzc,
+                        AsPtr::as_mut_ptr(&mut isuppz),
+                        &mut tryrac,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &(liwork as i32),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                let m = m as usize;
+                let mut eigs = unsafe { eigs.assume_init() };
+                eigs.truncate(m);
+                let z = z.map(|z| {
+                    let mut z = unsafe { z.assume_init() };
+                    z.truncate(n as usize * m);
+                    z
+                });
+                Ok(EighRangeOwned { eigs, z })
+            }
+
+            fn eigh_tridiagonal_inverse_iteration(
+                range: EigRange<Self::Real>,
+                a: &Tridiagonal<Self>,
+            ) -> Result<EighRangeOwned<Self>> {
+                let (n, _) = a.l.size();
+                let (vl, vu, il, iu) = range_args(range);
+                // Most accurate eigenvalues are required for inverse iteration
+                let abstol = 2.0 * <$r>::MIN_POSITIVE;
+                let mut m = 0;
+                let mut nsplit = 0;
+                let mut w: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut iblock: Vec<MaybeUninit<i32>> = vec_uninit(n as usize);
+                let mut isplit: Vec<MaybeUninit<i32>> = vec_uninit(n as usize);
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(5 * n as usize);
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(3 * n as usize);
+                let mut info = 0;
+                unsafe {
+                    $stebz(
+                        range.as_ptr(),
+                        &b'B' as *const u8 as *const i8, // grouped by split-off block as `?stein` expects
+                        &n,
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &abstol,
+                        AsPtr::as_ptr(&a.d),
+                        AsPtr::as_ptr(&a.dl),
+                        &mut m,
+                        &mut nsplit,
+                        AsPtr::as_mut_ptr(&mut w),
+                        AsPtr::as_mut_ptr(&mut iblock),
+                        AsPtr::as_mut_ptr(&mut isplit),
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                let mut z: Vec<MaybeUninit<Self>> = vec_uninit((n * m) as usize);
+                let mut ifail: Vec<MaybeUninit<i32>> = vec_uninit(m as usize);
+                unsafe {
+                    $stein(
+                        &n,
+                        AsPtr::as_ptr(&a.d),
+                        AsPtr::as_ptr(&a.dl),
+                        &m,
+                        AsPtr::as_ptr(&w),
+                        AsPtr::as_ptr(&iblock),
+                        AsPtr::as_ptr(&isplit),
+                        AsPtr::as_mut_ptr(&mut z),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut iwork[..n as usize]),
+                        AsPtr::as_mut_ptr(&mut ifail),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                let n = n as usize;
+                let m = m as usize;
+                let mut w = unsafe { w.assume_init() };
+                w.truncate(m);
+                let z = unsafe { z.assume_init() };
+                // Sort eigenpairs in ascending order across the split-off blocks
+                let mut order: Vec<usize> = (0..m).collect();
+                order.sort_by(|&i, &j| w[i].partial_cmp(&w[j]).unwrap());
+                let eigs = order.iter().map(|&i| w[i]).collect();
+                let z = order
+                    .iter()
+                    .flat_map(|&i| z[i * n..(i + 1) * n].iter().cloned())
+                    .collect();
+                Ok(EighRangeOwned { eigs, z: Some(z) })
+            }
+        }
+    };
+}
+impl_eigh_tridiagonal_r!(
+    f64,
+    lapack_sys::dstev_,
+    lapack_sys::dstemr_,
+    lapack_sys::dstebz_,
+    lapack_sys::dstein_
+);
+impl_eigh_tridiagonal_r!(
+    f32,
+    lapack_sys::sstev_,
+    lapack_sys::sstemr_,
+    lapack_sys::sstebz_,
+    lapack_sys::sstein_
+);
+
+macro_rules! impl_eigh_tridiagonal_c {
+    ($c:ty, $r:ty) => {
+        impl EighTridiagonalImpl for $c {
+            fn eigh_tridiagonal(
+                calc_eigenvec: bool,
+                range: EigRange<Self::Real>,
+                a: &Tridiagonal<Self>,
+            ) -> Result<EighRangeOwned<Self>> {
+                let (s, phase) = to_real_symmetric(a);
+                let EighRangeOwned { eigs, z } =
+                    <$r as EighTridiagonalImpl>::eigh_tridiagonal(calc_eigenvec, range, &s)?;
+                let z = z.map(|z| apply_phase(&phase, &z));
+                Ok(EighRangeOwned { eigs, z })
+            }
+
+            fn eigh_tridiagonal_inverse_iteration(
+                range: EigRange<Self::Real>,
+                a: &Tridiagonal<Self>,
+            ) -> Result<EighRangeOwned<Self>> {
+                let (s, phase) = to_real_symmetric(a);
+                let EighRangeOwned { eigs, z } =
+                    <$r as EighTridiagonalImpl>::eigh_tridiagonal_inverse_iteration(range, &s)?;
+                let z = z.map(|z| apply_phase(&phase, &z));
+                Ok(EighRangeOwned { eigs, z })
+            }
+        }
+    };
+}
+impl_eigh_tridiagonal_c!(c64, f64);
+impl_eigh_tridiagonal_c!(c32, f32);
+
+/// Reduce Hermitian tridiagonal matrix $T$ into real symmetric one $S = D^H T D$
+/// where $D = \mathrm{diag}(p)$ is returned as `p`
+fn to_real_symmetric<T: Scalar>(a: &Tridiagonal<T>) -> (Tridiagonal<T::Real>, Vec<T>) {
+    let mut phase = Vec::with_capacity(a.d.len());
+    let mut p = T::one();
+    phase.push(p);
+    for e in &a.dl {
+        let abs = e.abs();
+        if !abs.is_zero() {
+            p *= e.div_real(abs);
+        }
+        phase.push(p);
+    }
+    let dl: Vec<T::Real> = a.dl.iter().map(|e| e.abs()).collect();
+    let s = Tridiagonal {
+        l: a.l,
+        d: a.d.iter().map(|d| d.re()).collect(),
+        du: dl.clone(),
+        dl,
+    };
+    (s, phase)
+}
+
+/// Compute eigenvectors $D w$ from column-major eigenvectors $w$ of real symmetric matrix
+fn apply_phase<T: Scalar>(phase: &[T], w: &[T::Real]) -> Vec<T> {
+    let n = phase.len();
+    w.iter()
+        .enumerate()
+        .map(|(i, w)| phase[i % n].mul_real(*w))
+        .collect()
+}
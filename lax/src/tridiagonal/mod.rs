@@ -1,11 +1,13 @@
 //! Implement linear solver using LU decomposition
-//! for tridiagonal matrix
+//! and symmetric/Hermitian eigenvalue problem for tridiagonal matrix
 
+mod eigh;
 mod lu;
 mod matrix;
 mod rcond;
 mod solve;
 
+pub use eigh::*;
 pub use lu::*;
 pub use matrix::*;
 pub use rcond::*;
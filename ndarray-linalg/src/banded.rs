@@ -0,0 +1,704 @@
+//! Vectors as a banded matrix
+//! &
+//! Methods for banded matrices
+//!
+//! # Example
+//!
+//! Solve a linear equation with a pentadiagonal matrix:
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = array![
+//!     [4.0, 1.0, 1.0, 0.0, 0.0],
+//!     [1.0, 4.0, 1.0, 1.0, 0.0],
+//!     [1.0, 1.0, 4.0, 1.0, 1.0],
+//!     [0.0, 1.0, 1.0, 4.0, 1.0],
+//!     [0.0, 0.0, 1.0, 1.0, 4.0],
+//! ];
+//! let b = array![1.0, 2.0, 3.0, 4.0, 5.0];
+//! let banded = a.extract_banded(2, 2)?;
+//!
+//! // LU decomposition
+//! let x = banded.solve_banded(&b)?;
+//! assert_close_l2!(&a.dot(&x), &b, 1e-9);
+//!
+//! // Cholesky decomposition, since `a` is positive definite
+//! let f = banded.factorizec_banded(UPLO::Lower)?;
+//! let x = f.solve_banded(&b)?;
+//! assert_close_l2!(&a.dot(&x), &b, 1e-9);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! Eigenvalues of a symmetric banded matrix are computed without densifying it:
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! // 1D discrete Laplacian
+//! let n = 8;
+//! let mut a = Array2::<f64>::zeros((n, n)).extract_banded(1, 1)?;
+//! for i in 0..n as i32 {
+//!     a[(i, i)] = 2.0;
+//!     if i > 0 {
+//!         a[(i, i - 1)] = -1.0;
+//!         a[(i - 1, i)] = -1.0;
+//!     }
+//! }
+//! let (e, v) = a.eigh_banded(UPLO::Lower)?;
+//! assert_eq!(v.dim(), (n, n));
+//! let lowest = 2.0 - 2.0 * (std::f64::consts::PI / (n + 1) as f64).cos();
+//! assert!((e[0] - lowest).abs() < 1e-9);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use super::convert::*;
+use super::error::*;
+use super::layout::*;
+use cauchy::Scalar;
+use lax::*;
+use ndarray::*;
+
+pub use lax::{Banded, CholeskyFactorizedBanded, LUFactorizedBanded};
+
+/// An interface for making a Banded struct.
+pub trait ExtractBanded<A: Scalar> {
+    /// Extract banded elements with `kl` sub-diagonals and `ku` super-diagonals
+    /// and layout of the raw matrix.
+    ///
+    /// If the raw matrix has some elements out of the band,
+    /// they will be ignored.
+    fn extract_banded(&self, kl: usize, ku: usize) -> Result<Banded<A>>;
+}
+
+impl<A, S> ExtractBanded<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn extract_banded(&self, kl: usize, ku: usize) -> Result<Banded<A>> {
+        let l = self.square_layout()?;
+        let n = l.len() as usize;
+        let ldab = kl + ku + 1;
+        let mut ab = vec![A::zero(); ldab * n];
+        for j in 0..n {
+            for i in j.saturating_sub(ku)..std::cmp::min(n, j + kl + 1) {
+                ab[ku + i - j + j * ldab] = self[(i, j)];
+            }
+        }
+        Ok(Banded {
+            l,
+            kl: kl as i32,
+            ku: ku as i32,
+            ab,
+        })
+    }
+}
+
+pub trait SolveBanded<A: Scalar, D: Dimension> {
+    /// Solves a system of linear equations `A * x = b` with banded
+    /// matrix `A`, where `A` is `self`, `b` is the argument, and
+    /// `x` is the successful result.
+    fn solve_banded<S: Data<Elem = A>>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>>;
+    /// Solves a system of linear equations `A * x = b` with banded
+    /// matrix `A`, where `A` is `self`, `b` is the argument, and
+    /// `x` is the successful result.
+    fn solve_banded_into<S: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<S, D>,
+    ) -> Result<ArrayBase<S, D>>;
+    /// Solves a system of linear equations `A^T * x = b` with banded
+    /// matrix `A`, where `A` is `self`, `b` is the argument, and
+    /// `x` is the successful result.
+    fn solve_t_banded<S: Data<Elem = A>>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>>;
+    /// Solves a system of linear equations `A^T * x = b` with banded
+    /// matrix `A`, where `A` is `self`, `b` is the argument, and
+    /// `x` is the successful result.
+    fn solve_t_banded_into<S: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<S, D>,
+    ) -> Result<ArrayBase<S, D>>;
+    /// Solves a system of linear equations `A^H * x = b` with banded
+    /// matrix `A`, where `A` is `self`, `b` is the argument, and
+    /// `x` is the successful result.
+    fn solve_h_banded<S: Data<Elem = A>>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>>;
+    /// Solves a system of linear equations `A^H * x = b` with banded
+    /// matrix `A`, where `A` is `self`, `b` is the argument, and
+    /// `x` is the successful result.
+    fn solve_h_banded_into<S: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<S, D>,
+    ) -> Result<ArrayBase<S, D>>;
+}
+
+pub trait SolveBandedInplace<A: Scalar, D: Dimension> {
+    /// Solves a system of linear equations `A * x = b` banded
+    /// matrix `A`, where `A` is `self`, `b` is the argument, and
+    /// `x` is the successful result. The value of `x` is also assigned to the
+    /// argument.
+    fn solve_banded_inplace<'a, S: DataMut<Elem = A>>(
+        &self,
+        b: &'a mut ArrayBase<S, D>,
+    ) -> Result<&'a mut ArrayBase<S, D>>;
+    /// Solves a system of linear equations `A^T * x = b` banded
+    /// matrix `A`, where `A` is `self`, `b` is the argument, and
+    /// `x` is the successful result. The value of `x` is also assigned to the
+    /// argument.
+    fn solve_t_banded_inplace<'a, S: DataMut<Elem = A>>(
+        &self,
+        b: &'a mut ArrayBase<S, D>,
+    ) -> Result<&'a mut ArrayBase<S, D>>;
+    /// Solves a system of linear equations `A^H * x = b` banded
+    /// matrix `A`, where `A` is `self`, `b` is the argument, and
+    /// `x` is the successful result. The value of `x` is also assigned to the
+    /// argument.
+    fn solve_h_banded_inplace<'a, S: DataMut<Elem = A>>(
+        &self,
+        b: &'a mut ArrayBase<S, D>,
+    ) -> Result<&'a mut ArrayBase<S, D>>;
+}
+
+impl<A> SolveBanded<A, Ix2> for LUFactorizedBanded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solve_banded<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix2>) -> Result<Array<A, Ix2>> {
+        let mut b = replicate(b);
+        self.solve_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_banded_into<Sb: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<Sb, Ix2>,
+    ) -> Result<ArrayBase<Sb, Ix2>> {
+        self.solve_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_t_banded<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix2>) -> Result<Array<A, Ix2>> {
+        let mut b = replicate(b);
+        self.solve_t_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_t_banded_into<Sb: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<Sb, Ix2>,
+    ) -> Result<ArrayBase<Sb, Ix2>> {
+        self.solve_t_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_h_banded<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix2>) -> Result<Array<A, Ix2>> {
+        let mut b = replicate(b);
+        self.solve_h_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_h_banded_into<Sb: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<Sb, Ix2>,
+    ) -> Result<ArrayBase<Sb, Ix2>> {
+        self.solve_h_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+}
+
+impl<A> SolveBanded<A, Ix2> for Banded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solve_banded<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix2>) -> Result<Array<A, Ix2>> {
+        let mut b = replicate(b);
+        self.solve_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_banded_into<Sb: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<Sb, Ix2>,
+    ) -> Result<ArrayBase<Sb, Ix2>> {
+        self.solve_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_t_banded<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix2>) -> Result<Array<A, Ix2>> {
+        let mut b = replicate(b);
+        self.solve_t_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_t_banded_into<Sb: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<Sb, Ix2>,
+    ) -> Result<ArrayBase<Sb, Ix2>> {
+        self.solve_t_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_h_banded<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix2>) -> Result<Array<A, Ix2>> {
+        let mut b = replicate(b);
+        self.solve_h_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_h_banded_into<Sb: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<Sb, Ix2>,
+    ) -> Result<ArrayBase<Sb, Ix2>> {
+        self.solve_h_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+}
+
+impl<A> SolveBanded<A, Ix2> for CholeskyFactorizedBanded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solve_banded<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix2>) -> Result<Array<A, Ix2>> {
+        let mut b = replicate(b);
+        self.solve_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_banded_into<Sb: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<Sb, Ix2>,
+    ) -> Result<ArrayBase<Sb, Ix2>> {
+        self.solve_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_t_banded<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix2>) -> Result<Array<A, Ix2>> {
+        let mut b = replicate(b);
+        self.solve_t_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_t_banded_into<Sb: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<Sb, Ix2>,
+    ) -> Result<ArrayBase<Sb, Ix2>> {
+        self.solve_t_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_h_banded<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix2>) -> Result<Array<A, Ix2>> {
+        let mut b = replicate(b);
+        self.solve_h_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_h_banded_into<Sb: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<Sb, Ix2>,
+    ) -> Result<ArrayBase<Sb, Ix2>> {
+        self.solve_h_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+}
+
+impl<A> SolveBandedInplace<A, Ix2> for LUFactorizedBanded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solve_banded_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let l = rhs.layout()?;
+        A::solve_banded(self, l, Transpose::No, rhs.as_allocated_mut()?)?;
+        Ok(rhs)
+    }
+    fn solve_t_banded_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let l = rhs.layout()?;
+        A::solve_banded(self, l, Transpose::Transpose, rhs.as_allocated_mut()?)?;
+        Ok(rhs)
+    }
+    fn solve_h_banded_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let l = rhs.layout()?;
+        A::solve_banded(self, l, Transpose::Hermite, rhs.as_allocated_mut()?)?;
+        Ok(rhs)
+    }
+}
+
+impl<A> SolveBandedInplace<A, Ix2> for Banded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solve_banded_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let f = self.factorize_banded()?;
+        f.solve_banded_inplace(rhs)
+    }
+    fn solve_t_banded_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let f = self.factorize_banded()?;
+        f.solve_t_banded_inplace(rhs)
+    }
+    fn solve_h_banded_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let f = self.factorize_banded()?;
+        f.solve_h_banded_inplace(rhs)
+    }
+}
+
+impl<A> SolveBandedInplace<A, Ix2> for CholeskyFactorizedBanded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solve_banded_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let l = rhs.layout()?;
+        A::solve_cholesky_banded(self, l, rhs.as_allocated_mut()?)?;
+        Ok(rhs)
+    }
+    fn solve_t_banded_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        // `A^T = conj(A)` since `A` is Hermitian,
+        // and thus `A^T x = b` is equivalent to `A conj(x) = conj(b)`
+        rhs.mapv_inplace(|x| x.conj());
+        self.solve_banded_inplace(rhs)?;
+        rhs.mapv_inplace(|x| x.conj());
+        Ok(rhs)
+    }
+    fn solve_h_banded_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        // `A^H = A` since `A` is Hermitian
+        self.solve_banded_inplace(rhs)
+    }
+}
+
+impl<A> SolveBanded<A, Ix1> for LUFactorizedBanded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solve_banded<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<Array<A, Ix1>> {
+        let b = b.to_owned();
+        self.solve_banded_into(b)
+    }
+    fn solve_banded_into<Sb: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<Sb, Ix1>,
+    ) -> Result<ArrayBase<Sb, Ix1>> {
+        let b = into_col(b);
+        let b = self.solve_banded_into(b)?;
+        Ok(flatten(b))
+    }
+    fn solve_t_banded<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<Array<A, Ix1>> {
+        let b = b.to_owned();
+        self.solve_t_banded_into(b)
+    }
+    fn solve_t_banded_into<Sb: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<Sb, Ix1>,
+    ) -> Result<ArrayBase<Sb, Ix1>> {
+        let b = into_col(b);
+        let b = self.solve_t_banded_into(b)?;
+        Ok(flatten(b))
+    }
+    fn solve_h_banded<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<Array<A, Ix1>> {
+        let b = b.to_owned();
+        self.solve_h_banded_into(b)
+    }
+    fn solve_h_banded_into<Sb: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<Sb, Ix1>,
+    ) -> Result<ArrayBase<Sb, Ix1>> {
+        let b = into_col(b);
+        let b = self.solve_h_banded_into(b)?;
+        Ok(flatten(b))
+    }
+}
+
+impl<A> SolveBanded<A, Ix1> for Banded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solve_banded<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<Array<A, Ix1>> {
+        let b = b.to_owned();
+        self.solve_banded_into(b)
+    }
+    fn solve_banded_into<Sb: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<Sb, Ix1>,
+    ) -> Result<ArrayBase<Sb, Ix1>> {
+        let b = into_col(b);
+        let b = self.solve_banded_into(b)?;
+        Ok(flatten(b))
+    }
+    fn solve_t_banded<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<Array<A, Ix1>> {
+        let b = b.to_owned();
+        self.solve_t_banded_into(b)
+    }
+    fn solve_t_banded_into<Sb: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<Sb, Ix1>,
+    ) -> Result<ArrayBase<Sb, Ix1>> {
+        let b = into_col(b);
+        let b = self.solve_t_banded_into(b)?;
+        Ok(flatten(b))
+    }
+    fn solve_h_banded<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<Array<A, Ix1>> {
+        let b = b.to_owned();
+        self.solve_h_banded_into(b)
+    }
+    fn solve_h_banded_into<Sb: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<Sb, Ix1>,
+    ) -> Result<ArrayBase<Sb, Ix1>> {
+        let b = into_col(b);
+        let b = self.solve_h_banded_into(b)?;
+        Ok(flatten(b))
+    }
+}
+
+impl<A> SolveBanded<A, Ix1> for CholeskyFactorizedBanded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solve_banded<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<Array<A, Ix1>> {
+        let b = b.to_owned();
+        self.solve_banded_into(b)
+    }
+    fn solve_banded_into<Sb: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<Sb, Ix1>,
+    ) -> Result<ArrayBase<Sb, Ix1>> {
+        let b = into_col(b);
+        let b = self.solve_banded_into(b)?;
+        Ok(flatten(b))
+    }
+    fn solve_t_banded<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<Array<A, Ix1>> {
+        let b = b.to_owned();
+        self.solve_t_banded_into(b)
+    }
+    fn solve_t_banded_into<Sb: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<Sb, Ix1>,
+    ) -> Result<ArrayBase<Sb, Ix1>> {
+        let b = into_col(b);
+        let b = self.solve_t_banded_into(b)?;
+        Ok(flatten(b))
+    }
+    fn solve_h_banded<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<Array<A, Ix1>> {
+        let b = b.to_owned();
+        self.solve_h_banded_into(b)
+    }
+    fn solve_h_banded_into<Sb: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<Sb, Ix1>,
+    ) -> Result<ArrayBase<Sb, Ix1>> {
+        let b = into_col(b);
+        let b = self.solve_h_banded_into(b)?;
+        Ok(flatten(b))
+    }
+}
+
+/// An interface for computing LU factorizations of banded matrix refs.
+pub trait FactorizeBanded<A: Scalar> {
+    /// Computes the LU factorization `A = P*L*U`, where `P` is a permutation
+    /// matrix.
+    fn factorize_banded(&self) -> Result<LUFactorizedBanded<A>>;
+}
+
+/// An interface for computing LU factorizations of banded matrices.
+pub trait FactorizeBandedInto<A: Scalar> {
+    /// Computes the LU factorization `A = P*L*U`, where `P` is a permutation
+    /// matrix.
+    fn factorize_banded_into(self) -> Result<LUFactorizedBanded<A>>;
+}
+
+impl<A> FactorizeBandedInto<A> for Banded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn factorize_banded_into(self) -> Result<LUFactorizedBanded<A>> {
+        Ok(A::lu_banded(self)?)
+    }
+}
+
+impl<A> FactorizeBanded<A> for Banded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn factorize_banded(&self) -> Result<LUFactorizedBanded<A>> {
+        let a = self.clone();
+        Ok(A::lu_banded(a)?)
+    }
+}
+
+/// An interface for computing Cholesky factorizations of positive definite banded matrix refs.
+pub trait FactorizeCBanded<A: Scalar> {
+    /// Computes the Cholesky factorization `A = U^H * U` or `A = L * L^H`
+    /// of Hermitian (or real symmetric) positive definite matrix.
+    ///
+    /// Only the super-diagonals (for `UPLO::Upper`) or the sub-diagonals (for `UPLO::Lower`)
+    /// are referenced.
+    fn factorizec_banded(&self, uplo: UPLO) -> Result<CholeskyFactorizedBanded<A>>;
+}
+
+impl<A> FactorizeCBanded<A> for Banded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn factorizec_banded(&self, uplo: UPLO) -> Result<CholeskyFactorizedBanded<A>> {
+        Ok(A::cholesky_banded(uplo, self)?)
+    }
+}
+
+/// An interface for *estimating* the reciprocal condition number of banded matrix refs.
+pub trait ReciprocalConditionNumBanded<A: Scalar> {
+    /// *Estimates* the reciprocal of the condition number of the banded matrix in
+    /// 1-norm.
+    ///
+    /// This method uses the LAPACK `*gbcon` routines, which *estimate*
+    /// `self.inv().opnorm_one()` and then compute `rcond = 1. /
+    /// (self.opnorm_one() * self.inv().opnorm_one())`.
+    ///
+    /// * If `rcond` is near `0.`, the matrix is badly conditioned.
+    /// * If `rcond` is near `1.`, the matrix is well conditioned.
+    fn rcond_banded(&self) -> Result<A::Real>;
+}
+
+impl<A> ReciprocalConditionNumBanded<A> for LUFactorizedBanded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn rcond_banded(&self) -> Result<A::Real> {
+        Ok(A::rcond_banded(self)?)
+    }
+}
+
+impl<A> ReciprocalConditionNumBanded<A> for Banded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn rcond_banded(&self) -> Result<A::Real> {
+        self.factorize_banded()?.rcond_banded()
+    }
+}
+
+/// Eigenvalue decomposition of symmetric/Hermitian banded matrix
+pub trait EighBanded {
+    type Elem: Scalar;
+    type EigVal;
+    type EigVec;
+
+    /// Compute all eigenvalues in ascending order and the corresponding eigenvectors
+    /// using divide-and-conquer algorithm.
+    ///
+    /// Only the super-diagonals (for `UPLO::Upper`) or the sub-diagonals (for `UPLO::Lower`)
+    /// are referenced.
+    fn eigh_banded(&self, uplo: UPLO) -> Result<(Self::EigVal, Self::EigVec)>;
+
+    /// Compute the eigenvalues specified by `range` and the corresponding eigenvectors.
+    ///
+    /// Eigenvalues are returned in ascending order,
+    /// and the eigenvectors are stored as columns of `(n, m)` matrix
+    /// where `m` is the number of found eigenvalues.
+    fn eigh_banded_range(
+        &self,
+        uplo: UPLO,
+        range: EigRange<<Self::Elem as Scalar>::Real>,
+    ) -> Result<(Self::EigVal, Self::EigVec)>;
+}
+
+impl<A> EighBanded for Banded<A>
+where
+    A: Scalar + Lapack,
+{
+    type Elem = A;
+    type EigVal = Array1<A::Real>;
+    type EigVec = Array2<A>;
+
+    fn eigh_banded(&self, uplo: UPLO) -> Result<(Self::EigVal, Self::EigVec)> {
+        let lax::EighRangeOwned { eigs, z } = A::eigh_banded(true, uplo, self)?;
+        let n = self.l.len() as usize;
+        Ok((
+            ArrayBase::from(eigs),
+            Array2::from_shape_vec((n, n).f(), z.unwrap()).unwrap(),
+        ))
+    }
+
+    fn eigh_banded_range(
+        &self,
+        uplo: UPLO,
+        range: EigRange<A::Real>,
+    ) -> Result<(Self::EigVal, Self::EigVec)> {
+        let lax::EighRangeOwned { eigs, z } = A::eigh_banded_range(true, uplo, range, self)?;
+        let n = self.l.len() as usize;
+        let m = eigs.len();
+        Ok((
+            ArrayBase::from(eigs),
+            Array2::from_shape_vec((n, m).f(), z.unwrap()).unwrap(),
+        ))
+    }
+}
+
+/// Calculate eigenvalues of symmetric/Hermitian banded matrix without eigenvectors
+pub trait EigValshBanded {
+    type Elem: Scalar;
+    type EigVal;
+
+    /// Compute all eigenvalues in ascending order. See [EighBanded::eigh_banded]
+    fn eigvalsh_banded(&self, uplo: UPLO) -> Result<Self::EigVal>;
+
+    /// Compute the eigenvalues specified by `range`. See [EighBanded::eigh_banded_range]
+    fn eigvalsh_banded_range(
+        &self,
+        uplo: UPLO,
+        range: EigRange<<Self::Elem as Scalar>::Real>,
+    ) -> Result<Self::EigVal>;
+}
+
+impl<A> EigValshBanded for Banded<A>
+where
+    A: Scalar + Lapack,
+{
+    type Elem = A;
+    type EigVal = Array1<A::Real>;
+
+    fn eigvalsh_banded(&self, uplo: UPLO) -> Result<Self::EigVal> {
+        let lax::EighRangeOwned { eigs, .. } = A::eigh_banded(false, uplo, self)?;
+        Ok(ArrayBase::from(eigs))
+    }
+
+    fn eigvalsh_banded_range(&self, uplo: UPLO, range: EigRange<A::Real>) -> Result<Self::EigVal> {
+        let lax::EighRangeOwned { eigs, .. } = A::eigh_banded_range(false, uplo, range, self)?;
+        Ok(ArrayBase::from(eigs))
+    }
+}
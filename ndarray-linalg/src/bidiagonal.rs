@@ -0,0 +1,165 @@
+//! Bidiagonal matrix, Golub-Kahan bidiagonalization, and SVD of bidiagonal matrices
+//!
+//! A general `(m, n)` matrix `A` is reduced into `A = Q B P^H` by [Bidiagonalize],
+//! where `B` is a real bidiagonal matrix of size `k = min(m, n)`,
+//! and `Q` of shape `(m, k)` and `P^H` of shape `(k, n)` have orthonormal columns and rows.
+//! `B` is upper bidiagonal if `m >= n`, and lower bidiagonal otherwise.
+//!
+//! The SVD of [Bidiagonal] matrix is computed by [SVDBidiagonal] without densifying it,
+//! which is useful e.g. after the Lanczos bidiagonalization.
+//!
+//! # Example
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = array![
+//!     [1.0, 2.0, 3.0],
+//!     [4.0, 5.0, 6.0],
+//!     [7.0, 8.0, 10.0],
+//!     [1.0, 0.0, 1.0],
+//! ];
+//! let (q, b, pt) = a.bidiagonalize()?;
+//! assert_eq!(b.uplo, UPLO::Upper);
+//! assert_close_l2!(&q.dot(&b.to_dense()).dot(&pt), &a, 1e-7);
+//!
+//! // Singular values of A are those of B
+//! let (_, s, _) = b.svd_bidiagonal(false)?;
+//! let (_, s_dense, _) = a.svd(false, false)?;
+//! assert_close_l2!(&s, &s_dense, 1e-7);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use ndarray::*;
+
+use crate::convert::*;
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+
+pub use lax::Bidiagonal;
+use lax::UPLO;
+
+/// Convert a [Bidiagonal] matrix into a dense matrix
+pub trait BidiagonalToDense<A: Scalar> {
+    /// Create a dense `(n, n)` matrix with the bidiagonal elements
+    fn to_dense(&self) -> Array2<A>;
+}
+
+impl<A: Scalar> BidiagonalToDense<A> for Bidiagonal<A> {
+    fn to_dense(&self) -> Array2<A> {
+        let n = self.len();
+        let mut a = Array2::zeros((n, n));
+        for (i, d) in self.d.iter().enumerate() {
+            a[(i, i)] = A::from_real(*d);
+        }
+        for (i, e) in self.e.iter().enumerate() {
+            match self.uplo {
+                UPLO::Upper => a[(i, i + 1)] = A::from_real(*e),
+                UPLO::Lower => a[(i + 1, i)] = A::from_real(*e),
+            }
+        }
+        a
+    }
+}
+
+/// Golub-Kahan bidiagonalization of general matrix
+pub trait Bidiagonalize {
+    type Elem: Scalar;
+
+    /// Compute bidiagonalization `A = Q B P^H` and returns `(Q, B, P^H)`
+    fn bidiagonalize(
+        &self,
+    ) -> Result<(
+        Array2<Self::Elem>,
+        Bidiagonal<Self::Elem>,
+        Array2<Self::Elem>,
+    )>;
+}
+
+impl<A, S> Bidiagonalize for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Elem = A;
+
+    fn bidiagonalize(&self) -> Result<(Array2<A>, Bidiagonal<A>, Array2<A>)> {
+        let mut a: Array2<A> = replicate(self);
+        let l = a.layout()?;
+        let (m, n) = a.dim();
+        let k = std::cmp::min(m, n);
+        let lax::BidiagonalOwned { b, tauq, taup } = A::bidiagonal(l, a.as_allocated_mut()?)?;
+        let q = A::bidiagonal_q(l, a.as_allocated()?, &tauq)?;
+        let pt = A::bidiagonal_pt(l, a.as_allocated()?, &taup)?;
+        Ok((
+            Array2::from_shape_vec((m, k).f(), q).unwrap(),
+            b,
+            Array2::from_shape_vec((k, n).f(), pt).unwrap(),
+        ))
+    }
+}
+
+/// Singular value decomposition of bidiagonal matrix
+pub trait SVDBidiagonal {
+    type U;
+    type VT;
+    type Sigma;
+
+    /// Compute singular value decomposition `B = U Σ V^T`
+    /// using implicit zero-shift QR algorithm (`?bdsqr`)
+    ///
+    /// The singular values are returned in descending order.
+    fn svd_bidiagonal(
+        &self,
+        calc_uvt: bool,
+    ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)>;
+
+    /// Compute singular value decomposition `B = U Σ V^T`
+    /// using divide-and-conquer algorithm (`?bdsdc`)
+    ///
+    /// The singular values are returned in descending order.
+    fn svddc_bidiagonal(
+        &self,
+        calc_uvt: bool,
+    ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)>;
+}
+
+impl<A> SVDBidiagonal for Bidiagonal<A>
+where
+    A: Scalar + Lapack,
+{
+    type U = Array2<A>;
+    type VT = Array2<A>;
+    type Sigma = Array1<A::Real>;
+
+    fn svd_bidiagonal(
+        &self,
+        calc_uvt: bool,
+    ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)> {
+        let svd_res = A::svd_bidiagonal(calc_uvt, self)?;
+        Ok(into_svd_result(self.len(), svd_res))
+    }
+
+    fn svddc_bidiagonal(
+        &self,
+        calc_uvt: bool,
+    ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)> {
+        let svd_res = A::svddc_bidiagonal(calc_uvt, self)?;
+        Ok(into_svd_result(self.len(), svd_res))
+    }
+}
+
+fn into_svd_result<A: Scalar>(
+    n: usize,
+    svd_res: lax::SvdOwned<A>,
+) -> (Option<Array2<A>>, Array1<A::Real>, Option<Array2<A>>) {
+    let u = svd_res
+        .u
+        .map(|u| Array2::from_shape_vec((n, n).f(), u).unwrap());
+    let vt = svd_res
+        .vt
+        .map(|vt| Array2::from_shape_vec((n, n).f(), vt).unwrap());
+    (u, ArrayBase::from(svd_res.s), vt)
+}
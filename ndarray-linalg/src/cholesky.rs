@@ -4,6 +4,10 @@
 //! decomposition](https://en.wikipedia.org/wiki/Cholesky_decomposition) for
 //! more information.
 //!
+//! For positive semidefinite matrices, e.g. kernel or covariance matrices of low rank,
+//! use [CholeskyPivoted] which computes the decomposition with complete pivoting
+//! and detects the numerical rank instead of failing.
+//!
 //! # Example
 //!
 //! Using the Cholesky decomposition of `A` for various operations, where `A`
@@ -44,14 +48,16 @@
 //! ```
 
 use ndarray::*;
-use num_traits::Float;
+use num_traits::{Float, One};
 
 use crate::convert::*;
 use crate::error::*;
 use crate::layout::*;
+use crate::solve::{ReciprocalConditionNum, SolveExpertResult, SolveMixedResult};
 use crate::triangular::IntoTriangular;
 use crate::types::*;
 
+use lax::NormType;
 pub use lax::UPLO;
 
 /// Cholesky decomposition of Hermitian (or real symmetric) positive definite matrix
@@ -161,6 +167,32 @@ where
     }
 }
 
+impl<A, S, Sf> ReciprocalConditionNum<A> for (&ArrayBase<S, Ix2>, &CholeskyFactorized<Sf>)
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    Sf: Data<Elem = A>,
+{
+    fn rcond(&self) -> Result<A::Real> {
+        let (a, f) = *self;
+        assert_eq!(
+            a.shape(),
+            f.factor.shape(),
+            "The shapes of the matrix and its factorization must be identical.",
+        );
+        // The 1-norm of the original matrix is not kept in the factorization
+        let a: Array2<A> = replicate(a);
+        let anorm =
+            A::opnorm_hermitian(NormType::One, a.square_layout()?, f.uplo, a.as_allocated()?);
+        Ok(A::rcond_cholesky(
+            f.factor.square_layout()?,
+            f.uplo,
+            f.factor.as_allocated()?,
+            anorm,
+        )?)
+    }
+}
+
 impl<A, S> SolveC<A> for CholeskyFactorized<S>
 where
     A: Scalar + Lapack,
@@ -361,6 +393,309 @@ where
     }
 }
 
+/// Solve systems of linear equations with Hermitian (or real symmetric)
+/// positive definite coefficient matrices with equilibration, iterative refinement,
+/// and error bounds
+///
+/// This is implemented for a matrix `A`, and for a pair `(&A, &f)` of the matrix
+/// and its Cholesky factorization `f` computed by [FactorizeC]. In the latter case,
+/// the factorization is reused and equilibration is not performed.
+pub trait SolveCExpert<A: Scalar> {
+    /// Solves a system of linear equations `A * x = b` with Hermitian (or real
+    /// symmetric) positive definite matrix `A`, where `b` is the argument.
+    fn solvec_expert<S: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, Ix1>,
+    ) -> Result<SolveExpertResult<A>>;
+}
+
+impl<A, S> SolveCExpert<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn solvec_expert<Sb>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<SolveExpertResult<A>>
+    where
+        Sb: Data<Elem = A>,
+    {
+        assert_eq!(
+            b.len(),
+            self.len_of(Axis(1)),
+            "The length of `b` must be compatible with the shape of the matrix.",
+        );
+        let mut a: Array2<A> = replicate(self);
+        let mut x: Array1<A> = replicate(b);
+        let result = A::solve_cholesky_expert(
+            a.square_layout()?,
+            UPLO::Upper,
+            a.as_allocated_mut()?,
+            None,
+            x.as_slice_mut().unwrap(),
+        )?;
+        Ok(SolveExpertResult::new(x, result))
+    }
+}
+
+impl<A, S, Sf> SolveCExpert<A> for (&ArrayBase<S, Ix2>, &CholeskyFactorized<Sf>)
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    Sf: Data<Elem = A>,
+{
+    fn solvec_expert<Sb>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<SolveExpertResult<A>>
+    where
+        Sb: Data<Elem = A>,
+    {
+        let (a, f) = *self;
+        assert_eq!(
+            a.shape(),
+            f.factor.shape(),
+            "The shapes of the matrix and its factorization must be identical.",
+        );
+        assert_eq!(
+            b.len(),
+            a.len_of(Axis(1)),
+            "The length of `b` must be compatible with the shape of the matrix.",
+        );
+        // The original matrix must be in the same layout as the factorization
+        let l = f.factor.square_layout()?;
+        let mut a: Array2<A> = clone_with_layout(l, a);
+        let mut x: Array1<A> = replicate(b);
+        let result = A::solve_cholesky_expert(
+            l,
+            f.uplo,
+            a.as_allocated_mut()?,
+            Some(f.factor.as_allocated()?),
+            x.as_slice_mut().unwrap(),
+        )?;
+        Ok(SolveExpertResult::new(x, result))
+    }
+}
+
+/// Solve systems of linear equations with Hermitian (or real symmetric)
+/// positive definite coefficient matrices using Cholesky factorization
+/// in single precision with iterative refinement in double precision
+///
+/// This is implemented only for `f64` and `c64` matrices. If the refinement
+/// does not converge, the system is solved by Cholesky factorization
+/// in double precision instead.
+pub trait SolveCMixed<A: Scalar> {
+    /// Solves a system of linear equations `A * x = b` with Hermitian (or real
+    /// symmetric) positive definite matrix `A`, where `b` is the argument.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `b` is not the equal to the number of columns
+    /// of `A`.
+    fn solvec_mixed<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>)
+        -> Result<SolveMixedResult<A>>;
+}
+
+impl<A, S> SolveCMixed<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + LapackMixed,
+    S: Data<Elem = A>,
+{
+    fn solvec_mixed<Sb>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<SolveMixedResult<A>>
+    where
+        Sb: Data<Elem = A>,
+    {
+        assert_eq!(
+            b.len(),
+            self.len_of(Axis(1)),
+            "The length of `b` must be compatible with the shape of the matrix.",
+        );
+        let mut a: Array2<A> = replicate(self);
+        let mut x: Array1<A> = replicate(b);
+        let refinement = A::solve_cholesky_mixed(
+            a.square_layout()?,
+            UPLO::Upper,
+            a.as_allocated_mut()?,
+            x.as_slice_mut().unwrap(),
+        )?;
+        Ok(SolveMixedResult { x, refinement })
+    }
+}
+
+/// Pivoted Cholesky decomposition of Hermitian (or real symmetric) positive semidefinite matrix
+///
+/// This represents `P^T A P = U^H * U` or `P^T A P = L * L^H` where `P` is a permutation matrix.
+/// Only the leading `rank` rows of `U` (or columns of `L`) are non-zero,
+/// and thus `A` is approximated by the low-rank factor (see [Self::low_rank_factor]).
+pub struct CholeskyPivotedFactorized<S: Data> {
+    /// `L` from the decomposition `P^T A P = L * L^H` or `U` from the decomposition
+    /// `P^T A P = U^H * U`, where the entries beyond the rank are zero.
+    pub factor: ArrayBase<S, Ix2>,
+    /// If this is `UPLO::Lower`, then `self.factor` is `L`. If this is
+    /// `UPLO::Upper`, then `self.factor` is `U`.
+    pub uplo: UPLO,
+    /// Permutation as indices, i.e. the `j`-th column of `A P` is the `perm[j]`-th column of `A`.
+    pub perm: Vec<usize>,
+    /// Rank detected by the tolerance
+    pub rank: usize,
+}
+
+impl<A, S> CholeskyPivotedFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    /// Returns the `(n, rank)` matrix `F` satisfying `A = F * F^H`
+    /// up to the tolerance, i.e. `F = P * L[:, ..rank]`.
+    pub fn low_rank_factor(&self) -> Array2<A> {
+        let r = self.rank;
+        let l = match self.uplo {
+            UPLO::Lower => self.factor.slice(s![.., ..r]).to_owned(),
+            UPLO::Upper => self.factor.slice(s![..r, ..]).t().mapv(|elem| elem.conj()),
+        };
+        let mut f = Array2::zeros(l.dim());
+        for (k, &p) in self.perm.iter().enumerate() {
+            f.row_mut(p).assign(&l.row(k));
+        }
+        f
+    }
+
+    /// Computes the natural log of the pseudo-determinant, i.e. the product
+    /// of the non-zero eigenvalues, of the positive semidefinite matrix.
+    ///
+    /// The non-zero eigenvalues of `A = F * F^H` are those of `F^H * F`
+    /// for the low-rank factor `F` (see [Self::low_rank_factor]),
+    /// and thus this computes `ln(det(F^H * F))`.
+    /// The eigenvalues cut off by the tolerance are regarded as zero.
+    ///
+    /// ```
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// let a: Array2<f64> = array![[1., 1.], [1., 1.]];
+    /// let f = a.cholesky_pivoted(UPLO::Lower, None)?;
+    /// assert_eq!(f.rank, 1);
+    /// assert!((f.ln_pseudo_detc()? - 2f64.ln()).abs() < 1e-12);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn ln_pseudo_detc(&self) -> Result<A::Real> {
+        if self.rank == self.perm.len() {
+            return Ok(self.ln_detc());
+        }
+        if self.rank == 0 {
+            return Ok(num_traits::Zero::zero());
+        }
+        let f = self.low_rank_factor();
+        let ff = f.t().mapv(|elem| elem.conj()).dot(&f);
+        ff.ln_detc()
+    }
+}
+
+impl<A, S> DeterminantC for CholeskyPivotedFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = <A as Scalar>::Real;
+
+    fn detc(&self) -> Self::Output {
+        Float::exp(self.ln_detc())
+    }
+
+    /// Returns negative infinity if the matrix is rank deficient.
+    fn ln_detc(&self) -> Self::Output {
+        if self.rank < self.perm.len() {
+            return Float::neg_infinity();
+        }
+        self.factor
+            .diag()
+            .iter()
+            .map(|elem| Float::ln(elem.square()))
+            .sum()
+    }
+}
+
+impl<A, S> SolveC<A> for CholeskyPivotedFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    /// Computes the basic solution `x = P [y_1; 0]`, where `y_1` is the solution
+    /// of the leading `(rank, rank)` system.
+    /// This is the exact solution if `b` is in the range of `A`.
+    fn solvec_inplace<'a, Sb>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let r = self.rank;
+        let mut y: Array1<A> = self.perm.iter().map(|&p| b[p]).collect();
+        if r > 0 {
+            let f11: Array2<A> = replicate(&self.factor.slice(s![..r, ..r]));
+            let mut y1: Array1<A> = replicate(&y.slice(s![..r]));
+            A::solve_cholesky(
+                f11.square_layout()?,
+                self.uplo,
+                f11.as_allocated()?,
+                y1.as_slice_mut().unwrap(),
+            )?;
+            y.slice_mut(s![..r]).assign(&y1);
+        }
+        y.slice_mut(s![r..]).fill(A::zero());
+        for (k, &p) in self.perm.iter().enumerate() {
+            b[p] = y[k];
+        }
+        Ok(b)
+    }
+}
+
+/// Pivoted Cholesky decomposition of Hermitian (or real symmetric) positive semidefinite matrix
+pub trait CholeskyPivoted<A: Scalar> {
+    /// Computes the Cholesky decomposition with complete pivoting `P^T A P = U^H * U`
+    /// (for `UPLO::Upper`) or `P^T A P = L * L^H` (for `UPLO::Lower`).
+    ///
+    /// The factorization stops when the largest remaining diagonal element
+    /// is not larger than `tol`, which determines the rank.
+    /// If `tol` is `None`, `n * eps * max_k |A_kk|` is used.
+    /// Unlike [Cholesky], this does not fail for a singular matrix.
+    fn cholesky_pivoted(
+        &self,
+        uplo: UPLO,
+        tol: Option<A::Real>,
+    ) -> Result<CholeskyPivotedFactorized<OwnedRepr<A>>>;
+}
+
+impl<A, S> CholeskyPivoted<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn cholesky_pivoted(
+        &self,
+        uplo: UPLO,
+        tol: Option<A::Real>,
+    ) -> Result<CholeskyPivotedFactorized<OwnedRepr<A>>> {
+        let mut a: Array2<A> = replicate(self);
+        let tol = tol.unwrap_or_else(|| -A::Real::one());
+        let (piv, rank) =
+            A::cholesky_pivoted(a.square_layout()?, uplo, a.as_allocated_mut()?, tol)?;
+        let rank = rank as usize;
+        // LAPACK leaves the trailing part beyond the rank partially updated
+        for ((i, j), val) in a.indexed_iter_mut() {
+            let beyond_rank = match uplo {
+                UPLO::Upper => i >= rank,
+                UPLO::Lower => j >= rank,
+            };
+            if beyond_rank {
+                *val = A::zero();
+            }
+        }
+        Ok(CholeskyPivotedFactorized {
+            factor: a.into_triangular(uplo),
+            uplo,
+            perm: piv.into_iter().map(|p| p as usize - 1).collect(),
+            rank,
+        })
+    }
+}
+
 /// Inverse of Hermitian (or real symmetric) positive definite matrix ref
 pub trait InverseC {
     type Output;
@@ -61,7 +61,10 @@ where
     }
 }
 
-fn clone_with_layout<A, Si, So>(l: MatrixLayout, a: &ArrayBase<Si, Ix2>) -> ArrayBase<So, Ix2>
+pub(crate) fn clone_with_layout<A, Si, So>(
+    l: MatrixLayout,
+    a: &ArrayBase<Si, Ix2>,
+) -> ArrayBase<So, Ix2>
 where
     A: Copy,
     Si: Data<Elem = A>,
@@ -4,6 +4,9 @@ use crate::error::*;
 use crate::layout::*;
 use crate::types::*;
 use ndarray::*;
+use num_traits::Float;
+
+pub use lax::Balance;
 
 #[cfg_attr(doc, katexit::katexit)]
 /// Eigenvalue decomposition of general matrix reference
@@ -77,3 +80,368 @@ where
         Ok(ArrayBase::from(s))
     }
 }
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Eigenvalue decomposition of general matrix with both left and right eigenvectors
+pub trait EigLeftRight {
+    type EigVal;
+    type EigVec;
+    /// Calculate eigenvalues with the left and right eigenvectors
+    ///
+    /// $$ A v_i = \lambda_i v_i, \quad u_i^H A = \lambda_i u_i^H $$
+    ///
+    /// Returns $(\lambda, U, V)$ where the columns of $U$ and $V$ are the left and right eigenvectors.
+    /// The right eigenvectors are normalized to have unit 2-norm,
+    /// and the left eigenvectors are scaled so that $u_i^H v_i = 1$.
+    /// Since left and right eigenvectors of distinct eigenvalues are orthogonal,
+    /// $U^H V = I$ holds if all eigenvalues are distinct.
+    /// The left eigenvectors become large for nearly defective eigenvalues,
+    /// where $|u_i^H v_i|$ of unit vectors is small.
+    /// For a defective eigenvalue, e.g. of a Jordan block, $u_i^H v_i$ vanishes
+    /// up to the rounding errors, and the left eigenvectors are meaningless.
+    /// [LinalgError::DefectiveEigenvalue] is returned if $|u_i^H v_i| \le n \epsilon$,
+    /// i.e. the eigenvalue is defective up to the rounding errors.
+    ///
+    /// ```
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// let a: Array2<f64> = array![
+    ///     [1.0, 2.0, 0.0],
+    ///     [0.0, 3.0, 1.0],
+    ///     [1.0, 0.0, 2.0],
+    /// ];
+    /// let (_eigs, vl, vr) = a.eig_left_right()?;
+    /// let vlh = vl.t().mapv(|x| x.conj());
+    /// assert_close_l2!(&vlh.dot(&vr), &Array2::eye(3), 1e-7);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn eig_left_right(&self) -> Result<(Self::EigVal, Self::EigVec, Self::EigVec)>;
+}
+
+impl<A, S> EigLeftRight for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type EigVal = Array1<A::Complex>;
+    type EigVec = Array2<A::Complex>;
+
+    fn eig_left_right(&self) -> Result<(Self::EigVal, Self::EigVec, Self::EigVec)> {
+        let mut a = self.to_owned();
+        let layout = a.square_layout()?;
+        let result = A::eig_left_right(layout, a.as_allocated_mut()?)?;
+        let n = layout.len() as usize;
+        let tol = A::real(n) * A::Real::epsilon();
+        let mut vl = Array2::from_shape_vec((n, n).f(), result.vl.unwrap()).unwrap();
+        let vr = Array2::from_shape_vec((n, n).f(), result.vr.unwrap()).unwrap();
+        for (mut u, v) in vl.axis_iter_mut(Axis(1)).zip(vr.axis_iter(Axis(1))) {
+            // u / conj(u^H v) satisfies u^H v = 1
+            let uv: A::Complex = u.iter().zip(v.iter()).map(|(x, y)| x.conj() * *y).sum();
+            if uv.abs() <= tol {
+                return Err(LinalgError::DefectiveEigenvalue);
+            }
+            let c = uv.conj();
+            u.mapv_inplace(|x| x / c);
+        }
+        Ok((ArrayBase::from(result.eigs), vl, vr))
+    }
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Eigenvalue decomposition of real general matrix in the real representation computed by [EigReal]
+///
+/// A complex conjugate pair of eigenvalues $a \pm ib$ with $b > 0$ appears consecutively,
+/// and the eigenvector $x + iy$ of $a + ib$ is stored as the two real columns $x$ and $y$.
+#[derive(Debug, Clone)]
+pub struct EigRealResult<A> {
+    /// Real part of eigenvalues
+    pub eigvals_re: Array1<A>,
+    /// Imaginary part of eigenvalues
+    pub eigvals_im: Array1<A>,
+    /// Right eigenvectors as real columns, where a conjugate pair is stored as $(\mathrm{Re}\,v, \mathrm{Im}\,v)$
+    pub eigvecs: Array2<A>,
+}
+
+impl<A: Scalar + PartialOrd> EigRealResult<A> {
+    /// Eigenvalues as complex numbers
+    pub fn eigvals(&self) -> Array1<A::Complex> {
+        Zip::from(&self.eigvals_re)
+            .and(&self.eigvals_im)
+            .map_collect(|&re, &im| A::complex(re, im))
+    }
+
+    /// Real block-diagonal matrix $\Lambda$ satisfying $A V = V \Lambda$ for [EigRealResult::eigvecs] $V$
+    ///
+    /// A conjugate pair $a \pm ib$ corresponds to the $2 \times 2$ block
+    /// $\begin{pmatrix} a & b \\\\ -b & a \end{pmatrix}$.
+    pub fn block_diagonal(&self) -> Array2<A> {
+        let n = self.eigvals_re.len();
+        let mut lambda = Array2::zeros((n, n));
+        for j in 0..n {
+            let im = self.eigvals_im[j];
+            lambda[(j, j)] = self.eigvals_re[j];
+            if im > A::zero() {
+                lambda[(j, j + 1)] = im;
+            } else if im < A::zero() {
+                lambda[(j, j - 1)] = im;
+            }
+        }
+        lambda
+    }
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Eigenvalue decomposition of real general matrix keeping the results real
+///
+/// This is implemented only for `f32` and `f64` matrices.
+/// Use [Eig] to get the complex eigenvalues and eigenvectors.
+pub trait EigReal<A> {
+    /// Calculate eigenvalues with the right eigenvectors in the real representation
+    ///
+    /// $$ A V = V \Lambda $$
+    ///
+    /// where $\Lambda$ is real block-diagonal, see [EigRealResult::block_diagonal].
+    ///
+    /// ```
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// // Rotation by 90 degrees has eigenvalues ±i
+    /// let a: Array2<f64> = array![
+    ///     [0.0, -1.0],
+    ///     [1.0,  0.0],
+    /// ];
+    /// let result = a.eig_real()?;
+    /// assert!(result.eigvals_re.iter().all(|re| re.abs() < 1e-7));
+    /// assert_close_l2!(&result.eigvals_im, &array![1.0, -1.0], 1e-7);
+    ///
+    /// let v = &result.eigvecs;
+    /// assert_close_l2!(&a.dot(v), &v.dot(&result.block_diagonal()), 1e-7);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn eig_real(&self) -> Result<EigRealResult<A>>;
+}
+
+impl<A, S> EigReal<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + LapackReal,
+    S: Data<Elem = A>,
+{
+    fn eig_real(&self) -> Result<EigRealResult<A>> {
+        let mut a = self.to_owned();
+        let layout = a.square_layout()?;
+        let result = A::eig_real(true, layout, a.as_allocated_mut()?)?;
+        let n = layout.len() as usize;
+        Ok(EigRealResult {
+            eigvals_re: ArrayBase::from(result.eigs_re),
+            eigvals_im: ArrayBase::from(result.eigs_im),
+            eigvecs: Array2::from_shape_vec((n, n).f(), result.vr.unwrap()).unwrap(),
+        })
+    }
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Eigenvalue decomposition with the sensitivity of each eigenvalue and eigenvector
+/// computed by [EigExpert]
+#[derive(Debug, Clone)]
+pub struct EigExpertResult<A: Scalar> {
+    /// Eigenvalues $\lambda_i$
+    pub eigvals: Array1<A::Complex>,
+    /// Right eigenvectors $v_i$ satisfying $A v_i = \lambda_i v_i$ as columns, normalized to have unit 2-norm
+    pub right_eigvecs: Array2<A::Complex>,
+    /// Left eigenvectors $u_i$ satisfying $u_i^H A = \lambda_i u_i^H$ as columns, normalized to have unit 2-norm
+    pub left_eigvecs: Array2<A::Complex>,
+    /// Reciprocal condition numbers of the eigenvalues, i.e. $|u_i^H v_i|$
+    pub eigval_rcond: Array1<A::Real>,
+    /// Reciprocal condition numbers of the right eigenvectors
+    pub eigvec_rcond: Array1<A::Real>,
+    /// 1-norm of the balanced matrix
+    pub balanced_norm: A::Real,
+}
+
+impl<A: Scalar> EigExpertResult<A> {
+    /// Approximate error bounds of the computed eigenvalues, i.e. `|λ_i - λ_i_true|`
+    pub fn eigval_error_bounds(&self) -> Array1<A::Real> {
+        let eps_norm = A::Real::epsilon() * self.balanced_norm;
+        self.eigval_rcond.mapv(|r| eps_norm / r)
+    }
+
+    /// Approximate error bounds of the computed right eigenvectors
+    /// in the angle between the computed and true eigenvectors
+    pub fn eigvec_error_bounds(&self) -> Array1<A::Real> {
+        let eps_norm = A::Real::epsilon() * self.balanced_norm;
+        self.eigvec_rcond.mapv(|r| eps_norm / r)
+    }
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Eigenvalue decomposition of general matrix with balancing and condition numbers, using `?geevx`
+///
+/// The eigenvalues of a non-normal matrix may be very sensitive to perturbations,
+/// e.g. by rounding errors. The reciprocal condition numbers tell which computed eigenpairs are reliable.
+pub trait EigExpert<A: Scalar> {
+    /// Calculate eigenvalues, right and left eigenvectors, and their reciprocal condition numbers
+    /// after balancing the matrix as specified by [Balance]
+    ///
+    /// ```
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// // Highly non-normal matrix with ill-conditioned eigenvalues
+    /// let a: Array2<f64> = array![
+    ///     [1.0, 1e6],
+    ///     [0.0, 2.0],
+    /// ];
+    /// let result = a.eig_expert(Balance::None)?;
+    /// assert!(result.eigval_rcond.iter().all(|&r| r < 1e-5));
+    ///
+    /// // Normal matrix has perfectly conditioned eigenvalues
+    /// let a: Array2<f64> = array![
+    ///     [1.0, 0.0],
+    ///     [0.0, 2.0],
+    /// ];
+    /// let result = a.eig_expert(Balance::Both)?;
+    /// assert!(result.eigval_rcond.iter().all(|&r| (r - 1.0).abs() < 1e-12));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn eig_expert(&self, balance: Balance) -> Result<EigExpertResult<A>>;
+}
+
+impl<A, S> EigExpert<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn eig_expert(&self, balance: Balance) -> Result<EigExpertResult<A>> {
+        let mut a = self.to_owned();
+        let layout = a.square_layout()?;
+        let result = A::eig_expert(balance, layout, a.as_allocated_mut()?)?;
+        let n = layout.len() as usize;
+        Ok(EigExpertResult {
+            eigvals: ArrayBase::from(result.eigs),
+            right_eigvecs: Array2::from_shape_vec((n, n).f(), result.vr).unwrap(),
+            left_eigvecs: Array2::from_shape_vec((n, n).f(), result.vl).unwrap(),
+            eigval_rcond: ArrayBase::from(result.rconde),
+            eigvec_rcond: ArrayBase::from(result.rcondv),
+            balanced_norm: result.abnrm,
+        })
+    }
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Generalized eigenvalue decomposition of a pair of general matrices
+pub trait EigGeneralized {
+    /// EigVal is the pair of numerators and denominators of eigenvalues
+    type EigVal;
+    /// EigVec is the right eivenvector
+    type EigVec;
+    /// Calculate generalized eigenvalues with the right eigenvector
+    ///
+    /// $$ A u_i = \lambda_i B u_i $$
+    ///
+    /// Eigenvalues are returned as a pair of arrays $(\alpha, \beta)$
+    /// where $\lambda_i = \alpha_i / \beta_i$.
+    /// $\beta_i = 0$ corresponds to an infinite eigenvalue, e.g. when $B$ is singular.
+    ///
+    /// ```
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// let a: Array2<f64> = array![
+    ///     [1.0, 2.0],
+    ///     [3.0, 4.0],
+    /// ];
+    /// let b: Array2<f64> = array![
+    ///     [1.0, 0.0],
+    ///     [0.0, 0.0],
+    /// ];
+    /// let ((alpha, beta), _vecs) = (a, b).eig_generalized().unwrap();
+    ///
+    /// // det(A - λB) = -2 - 4λ has only one root λ = -1/2,
+    /// // and the other eigenvalue is infinite since `b` is singular
+    /// let finite: Vec<_> = alpha
+    ///     .iter()
+    ///     .zip(beta.iter())
+    ///     .filter(|(_, b)| b.abs() > 1e-9)
+    ///     .map(|(&a, &b)| a / b)
+    ///     .collect();
+    /// assert_eq!(finite.len(), 1);
+    /// assert_aclose!(finite[0], c64::new(-0.5, 0.0), 1e-7);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shapes of the matrices are different.
+    fn eig_generalized(&self) -> Result<(Self::EigVal, Self::EigVec)>;
+}
+
+impl<A, S, S2> EigGeneralized for (ArrayBase<S, Ix2>, ArrayBase<S2, Ix2>)
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    type EigVal = (Array1<A::Complex>, Array1<A>);
+    type EigVec = Array2<A::Complex>;
+
+    fn eig_generalized(&self) -> Result<(Self::EigVal, Self::EigVec)> {
+        let (mut a, mut b) = to_fortran_pair(&self.0, &self.1);
+        let layout = a.square_layout()?;
+        let result =
+            A::eig_generalized(true, layout, a.as_allocated_mut()?, b.as_allocated_mut()?)?;
+        let n = layout.len() as usize;
+        Ok((
+            (ArrayBase::from(result.alpha), ArrayBase::from(result.beta)),
+            Array2::from_shape_vec((n, n).f(), result.vr.unwrap()).unwrap(),
+        ))
+    }
+}
+
+/// Calculate generalized eigenvalues without eigenvectors
+pub trait EigValsGeneralized {
+    type EigVal;
+    fn eigvals_generalized(&self) -> Result<Self::EigVal>;
+}
+
+impl<A, S, S2> EigValsGeneralized for (ArrayBase<S, Ix2>, ArrayBase<S2, Ix2>)
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    type EigVal = (Array1<A::Complex>, Array1<A>);
+
+    fn eigvals_generalized(&self) -> Result<Self::EigVal> {
+        let (mut a, mut b) = to_fortran_pair(&self.0, &self.1);
+        let result = A::eig_generalized(
+            false,
+            a.square_layout()?,
+            a.as_allocated_mut()?,
+            b.as_allocated_mut()?,
+        )?;
+        Ok((ArrayBase::from(result.alpha), ArrayBase::from(result.beta)))
+    }
+}
+
+/// Copy a pair of matrices into Fortran layout since LAPACK requires them in the same layout
+pub(crate) fn to_fortran_pair<A, S, S2>(
+    a: &ArrayBase<S, Ix2>,
+    b: &ArrayBase<S2, Ix2>,
+) -> (Array2<A>, Array2<A>)
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    assert_eq!(
+        a.shape(),
+        b.shape(),
+        "The shapes of the matrices must be identical.",
+    );
+    let mut a_f = Array2::zeros(a.dim().f());
+    a_f.assign(a);
+    let mut b_f = Array2::zeros(b.dim().f());
+    b_f.assign(b);
+    (a_f, b_f)
+}
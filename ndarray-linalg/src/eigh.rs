@@ -42,23 +42,65 @@ use crate::operator::LinearOperator;
 use crate::types::*;
 use crate::UPLO;
 
+pub use lax::{EigRange, EighDriver};
+
 /// Eigenvalue decomposition of Hermite matrix reference
 pub trait Eigh {
     type EigVal;
     type EigVec;
     fn eigh(&self, uplo: UPLO) -> Result<(Self::EigVal, Self::EigVec)>;
+
+    /// Same as [Eigh::eigh], but the algorithm is specified by [EighDriver].
+    /// [Eigh::eigh] uses [EighDriver::QR].
+    ///
+    /// The default implementation calls [Eigh::eigh],
+    /// so `driver` is ignored unless an implementor overrides this method.
+    /// All implementations in this crate override it.
+    fn eigh_with_driver(
+        &self,
+        uplo: UPLO,
+        _driver: EighDriver,
+    ) -> Result<(Self::EigVal, Self::EigVec)> {
+        self.eigh(uplo)
+    }
 }
 
 /// Eigenvalue decomposition of mutable reference of Hermite matrix
 pub trait EighInplace {
     type EigVal;
     fn eigh_inplace(&mut self, uplo: UPLO) -> Result<(Self::EigVal, &mut Self)>;
+
+    /// Same as [EighInplace::eigh_inplace], but the algorithm is specified by [EighDriver]
+    ///
+    /// The default implementation calls [EighInplace::eigh_inplace],
+    /// so `driver` is ignored unless an implementor overrides this method.
+    /// All implementations in this crate override it.
+    fn eigh_inplace_with_driver(
+        &mut self,
+        uplo: UPLO,
+        _driver: EighDriver,
+    ) -> Result<(Self::EigVal, &mut Self)> {
+        self.eigh_inplace(uplo)
+    }
 }
 
 /// Eigenvalue decomposition of Hermite matrix
 pub trait EighInto: Sized {
     type EigVal;
     fn eigh_into(self, uplo: UPLO) -> Result<(Self::EigVal, Self)>;
+
+    /// Same as [EighInto::eigh_into], but the algorithm is specified by [EighDriver]
+    ///
+    /// The default implementation calls [EighInto::eigh_into],
+    /// so `driver` is ignored unless an implementor overrides this method.
+    /// All implementations in this crate override it.
+    fn eigh_into_with_driver(
+        self,
+        uplo: UPLO,
+        _driver: EighDriver,
+    ) -> Result<(Self::EigVal, Self)> {
+        self.eigh_into(uplo)
+    }
 }
 
 impl<A, S> EighInto for ArrayBase<S, Ix2>
@@ -68,8 +110,16 @@ where
 {
     type EigVal = Array1<A::Real>;
 
-    fn eigh_into(mut self, uplo: UPLO) -> Result<(Self::EigVal, Self)> {
-        let (val, _) = self.eigh_inplace(uplo)?;
+    fn eigh_into(self, uplo: UPLO) -> Result<(Self::EigVal, Self)> {
+        self.eigh_into_with_driver(uplo, EighDriver::QR)
+    }
+
+    fn eigh_into_with_driver(
+        mut self,
+        uplo: UPLO,
+        driver: EighDriver,
+    ) -> Result<(Self::EigVal, Self)> {
+        let (val, _) = self.eigh_inplace_with_driver(uplo, driver)?;
         Ok((val, self))
     }
 }
@@ -82,8 +132,16 @@ where
 {
     type EigVal = Array1<A::Real>;
 
-    fn eigh_into(mut self, uplo: UPLO) -> Result<(Self::EigVal, Self)> {
-        let (val, _) = self.eigh_inplace(uplo)?;
+    fn eigh_into(self, uplo: UPLO) -> Result<(Self::EigVal, Self)> {
+        self.eigh_into_with_driver(uplo, EighDriver::QR)
+    }
+
+    fn eigh_into_with_driver(
+        mut self,
+        uplo: UPLO,
+        driver: EighDriver,
+    ) -> Result<(Self::EigVal, Self)> {
+        let (val, _) = self.eigh_inplace_with_driver(uplo, driver)?;
         Ok((val, self))
     }
 }
@@ -97,8 +155,16 @@ where
     type EigVec = Array2<A>;
 
     fn eigh(&self, uplo: UPLO) -> Result<(Self::EigVal, Self::EigVec)> {
+        self.eigh_with_driver(uplo, EighDriver::QR)
+    }
+
+    fn eigh_with_driver(
+        &self,
+        uplo: UPLO,
+        driver: EighDriver,
+    ) -> Result<(Self::EigVal, Self::EigVec)> {
         let a = self.to_owned();
-        a.eigh_into(uplo)
+        a.eigh_into_with_driver(uplo, driver)
     }
 }
 
@@ -112,8 +178,16 @@ where
     type EigVec = (Array2<A>, Array2<A>);
 
     fn eigh(&self, uplo: UPLO) -> Result<(Self::EigVal, Self::EigVec)> {
+        self.eigh_with_driver(uplo, EighDriver::QR)
+    }
+
+    fn eigh_with_driver(
+        &self,
+        uplo: UPLO,
+        driver: EighDriver,
+    ) -> Result<(Self::EigVal, Self::EigVec)> {
         let (a, b) = (self.0.to_owned(), self.1.to_owned());
-        (a, b).eigh_into(uplo)
+        (a, b).eigh_into_with_driver(uplo, driver)
     }
 }
 
@@ -125,13 +199,27 @@ where
     type EigVal = Array1<A::Real>;
 
     fn eigh_inplace(&mut self, uplo: UPLO) -> Result<(Self::EigVal, &mut Self)> {
+        self.eigh_inplace_with_driver(uplo, EighDriver::QR)
+    }
+
+    fn eigh_inplace_with_driver(
+        &mut self,
+        uplo: UPLO,
+        driver: EighDriver,
+    ) -> Result<(Self::EigVal, &mut Self)> {
         let layout = self.square_layout()?;
         // XXX Force layout to be Fortran (see #146)
         match layout {
             MatrixLayout::C { .. } => self.swap_axes(0, 1),
             MatrixLayout::F { .. } => {}
         }
-        let s = A::eigh(true, self.square_layout()?, uplo, self.as_allocated_mut()?)?;
+        let s = A::eigh_with_driver(
+            true,
+            self.square_layout()?,
+            uplo,
+            driver,
+            self.as_allocated_mut()?,
+        )?;
         Ok((ArrayBase::from(s), self))
     }
 }
@@ -150,6 +238,17 @@ where
     ///
     /// Panics if the shapes of the matrices are different.
     fn eigh_inplace(&mut self, uplo: UPLO) -> Result<(Self::EigVal, &mut Self)> {
+        self.eigh_inplace_with_driver(uplo, EighDriver::QR)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the shapes of the matrices are different.
+    fn eigh_inplace_with_driver(
+        &mut self,
+        uplo: UPLO,
+        driver: EighDriver,
+    ) -> Result<(Self::EigVal, &mut Self)> {
         assert_eq!(
             self.0.shape(),
             self.1.shape(),
@@ -168,10 +267,11 @@ where
             MatrixLayout::F { .. } => {}
         }
 
-        let s = A::eigh_generalized(
+        let s = A::eigh_generalized_with_driver(
             true,
             self.0.square_layout()?,
             uplo,
+            driver,
             self.0.as_allocated_mut()?,
             self.1.as_allocated_mut()?,
         )?;
@@ -236,6 +336,102 @@ where
     }
 }
 
+/// Eigenvalue decomposition of Hermite matrix reference computing only a subset of the spectrum
+pub trait EighRange {
+    type Elem: Scalar;
+    type EigVal;
+    type EigVec;
+
+    /// Compute the eigenvalues specified by `range` and the corresponding eigenvectors.
+    ///
+    /// Eigenvalues are returned in ascending order,
+    /// and the eigenvectors are stored as columns of `(n, m)` matrix
+    /// where `m` is the number of found eigenvalues.
+    ///
+    /// ```
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// let a: Array2<f64> = array![
+    ///     [2., 1., 0.],
+    ///     [1., 2., 1.],
+    ///     [0., 1., 2.],
+    /// ];
+    /// // Two lowest eigenpairs
+    /// let (e, v) = a.eigh_range(UPLO::Lower, EigRange::Index(0, 1)).unwrap();
+    /// assert_eq!(v.dim(), (3, 2));
+    /// assert_close_l2!(&a.dot(&v), &v.dot(&Array2::from_diag(&e)), 1e-7);
+    ///
+    /// // Eigenvalues in the interval (1, 3]
+    /// let (e, _v) = a.eigh_range(UPLO::Lower, EigRange::Value(1.0, 3.0)).unwrap();
+    /// assert_close_l2!(&e, &array![2.0], 1e-7);
+    /// ```
+    fn eigh_range(
+        &self,
+        uplo: UPLO,
+        range: EigRange<<Self::Elem as Scalar>::Real>,
+    ) -> Result<(Self::EigVal, Self::EigVec)>;
+}
+
+impl<A, S> EighRange for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Elem = A;
+    type EigVal = Array1<A::Real>;
+    type EigVec = Array2<A>;
+
+    fn eigh_range(
+        &self,
+        uplo: UPLO,
+        range: EigRange<A::Real>,
+    ) -> Result<(Self::EigVal, Self::EigVec)> {
+        let mut a = self.to_owned();
+        let layout = a.square_layout()?;
+        let lax::EighRangeOwned { eigs, z } =
+            A::eigh_range(true, layout, uplo, range, a.as_allocated_mut()?)?;
+        let n = layout.len() as usize;
+        let m = eigs.len();
+        Ok((
+            ArrayBase::from(eigs),
+            Array2::from_shape_vec((n, m).f(), z.unwrap()).unwrap(),
+        ))
+    }
+}
+
+/// Calculate a subset of eigenvalues of Hermite matrix without eigenvectors
+pub trait EigValshRange {
+    type Elem: Scalar;
+    type EigVal;
+    fn eigvalsh_range(
+        &self,
+        uplo: UPLO,
+        range: EigRange<<Self::Elem as Scalar>::Real>,
+    ) -> Result<Self::EigVal>;
+}
+
+impl<A, S> EigValshRange for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Elem = A;
+    type EigVal = Array1<A::Real>;
+
+    fn eigvalsh_range(&self, uplo: UPLO, range: EigRange<A::Real>) -> Result<Self::EigVal> {
+        let mut a = self.to_owned();
+        let lax::EighRangeOwned { eigs, .. } = A::eigh_range(
+            false,
+            a.square_layout()?,
+            uplo,
+            range,
+            a.as_allocated_mut()?,
+        )?;
+        Ok(ArrayBase::from(eigs))
+    }
+}
+
 /// Calculate symmetric square-root matrix using `eigh`
 pub trait SymmetricSqrt {
     type Output;
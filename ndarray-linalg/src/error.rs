@@ -35,4 +35,17 @@ pub enum LinalgError {
     /// Strides of the array is not supported
     #[error(transparent)]
     Shape(#[from] ShapeError),
+
+    /// Matrix equation does not have a unique solution
+    /// since the spectra of its coefficients (nearly) overlap
+    #[error("Matrix equation is (nearly) singular: the spectra of its coefficients overlap")]
+    SingularMatrixEquation,
+
+    /// Algebraic Riccati equation does not have a stabilizing solution
+    #[error("Algebraic Riccati equation does not have a stabilizing solution")]
+    NoStabilizingSolution,
+
+    /// Eigenvalue is defective, i.e. its left and right eigenvectors are orthogonal
+    #[error("Eigenvalue is defective: its left and right eigenvectors are orthogonal")]
+    DefectiveEigenvalue,
 }
@@ -0,0 +1,85 @@
+//! Hessenberg decomposition of general square matrices
+//!
+//! For a square matrix `A`, this computes `A = Q H Q^H`
+//! where `Q` is unitary (orthogonal for real matrices) and `H` is upper Hessenberg,
+//! i.e. `H_ij = 0` for `i > j + 1`.
+//! This is the first step of the QR algorithm for eigenvalue problem,
+//! and the dense counterpart of the Arnoldi iteration in [crate::krylov::arnoldi].
+//!
+//! # Example
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = array![
+//!     [1.0, 2.0, 3.0, 4.0],
+//!     [2.0, 1.0, 0.0, 1.0],
+//!     [3.0, 0.0, 2.0, 1.0],
+//!     [4.0, 1.0, 1.0, 3.0],
+//! ];
+//! let (q, h) = a.hessenberg()?;
+//! assert_eq!(h[(3, 0)], 0.0);
+//! assert_close_l2!(&q.dot(&h).dot(&q.t()), &a, 1e-7);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use ndarray::*;
+
+use crate::convert::*;
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+
+/// Hessenberg decomposition of general matrix reference
+pub trait Hessenberg {
+    type Q;
+    type H;
+
+    /// Compute Hessenberg decomposition `A = Q H Q^H` and returns `(Q, H)`
+    fn hessenberg(&self) -> Result<(Self::Q, Self::H)>;
+}
+
+/// Hessenberg decomposition of general matrix
+pub trait HessenbergInto: Sized {
+    type H;
+
+    /// Compute Hessenberg decomposition `A = Q H Q^H` and returns `(Q, H)`
+    /// where `Q` is stored in the memory of `A`
+    fn hessenberg_into(self) -> Result<(Self, Self::H)>;
+}
+
+impl<A, S> HessenbergInto for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type H = Array2<A>;
+
+    fn hessenberg_into(mut self) -> Result<(Self, Self::H)> {
+        let layout = self.square_layout()?;
+        let tau = A::hessenberg(layout, self.as_allocated_mut()?)?;
+        let mut h = self.to_owned();
+        for ((i, j), h) in h.indexed_iter_mut() {
+            if i > j + 1 {
+                *h = A::zero();
+            }
+        }
+        A::hessenberg_q(layout, self.as_allocated_mut()?, &tau)?;
+        Ok((self, h))
+    }
+}
+
+impl<A, S> Hessenberg for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Q = Array2<A>;
+    type H = Array2<A>;
+
+    fn hessenberg(&self) -> Result<(Self::Q, Self::H)> {
+        let a: Array2<A> = replicate(self);
+        a.hessenberg_into()
+    }
+}
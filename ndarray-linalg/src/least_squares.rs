@@ -18,8 +18,10 @@
 //! `LeastSquaresSvdInPlace` avoid an extra allocation for `A` and `b` which
 //! `LeastSquaresSvd` has do perform to preserve the values in `A` and `b`.
 //!
-//! All methods use the Lapacke family of methods `*gelsd` which solves the least
+//! By default, all methods use the Lapacke family of methods `*gelsd` which solves the least
 //! squares problem using the SVD with a divide-and-conquer strategy.
+//! Another algorithm and the rank cutoff can be specified by [LeastSquaresOptions]
+//! through the `*_with_options` methods, e.g. [LeastSquaresSvd::least_squares_with_options].
 //!
 //! The traits are implemented for value types `f32`, `f64`, `c32` and `c64`
 //! and vector or matrix right-hand-sides (`ArrayBase<S, Ix1>` or `ArrayBase<S, Ix2>`).
@@ -59,6 +61,26 @@
 //! let result_4 = a.least_squares_into(b).unwrap();
 //! // `a` and `b` have been moved, no longer valid
 //! ```
+//!
+//! Choosing the algorithm:
+//!
+//! ```rust
+//! use approx::AbsDiffEq;
+//! use ndarray::{array, Array1, Array2};
+//! use ndarray_linalg::{LeastSquaresDriver, LeastSquaresOptions, LeastSquaresSvd};
+//!
+//! // rank deficient matrix
+//! let a: Array2<f64> = array![[1., 2.], [2., 4.], [3., 6.]];
+//! let b: Array1<f64> = array![1., 2., 3.];
+//! let options = LeastSquaresOptions {
+//!     driver: LeastSquaresDriver::CompleteOrthogonal,
+//!     rcond: Some(1e-10),
+//! };
+//! let result = a.least_squares_with_options(&b, options).unwrap();
+//! assert_eq!(result.driver, LeastSquaresDriver::CompleteOrthogonal);
+//! assert_eq!(result.rank, 1);
+//! assert!(result.solution.abs_diff_eq(&array![0.2, 0.4], 1e-12));
+//! ```
 
 use lax::*;
 use ndarray::*;
@@ -67,6 +89,8 @@ use crate::error::*;
 use crate::layout::*;
 use crate::types::*;
 
+pub use lax::{LeastSquaresDriver, LeastSquaresOptions};
+
 /// Result of a LeastSquares computation
 ///
 /// Takes two type parameters, `E`, the element type of the matrix
@@ -78,13 +102,17 @@ use crate::types::*;
 /// the solution is a `m x k` matrix.
 #[derive(Debug, Clone)]
 pub struct LeastSquaresResult<E: Scalar, I: Dimension> {
-    /// The singular values of the matrix A in `Ax = b`
+    /// The singular values of the matrix A in `Ax = b`.
+    /// This is empty unless the driver is based on SVD.
     pub singular_values: Array1<E::Real>,
     /// The solution vector or matrix `x` which is the best
     /// solution to `Ax = b`, i.e. minimizing the 2-norm `||b - Ax||`
     pub solution: Array<E, I>,
-    /// The rank of the matrix A in `Ax = b`
+    /// The rank of the matrix A in `Ax = b`.
+    /// [LeastSquaresDriver::QR] assumes that A has full rank.
     pub rank: i32,
+    /// The driver used to solve the problem
+    pub driver: LeastSquaresDriver,
     /// If n < m and rank(A) == n, the sum of squares
     /// If b is a (m x 1) vector, this is a 0-dimensional array (single value)
     /// If b is a (m x k) matrix, this is a (k x 1) column vector
@@ -105,6 +133,15 @@ where
     /// be both either row- or column-major format, otherwise a
     /// `IncompatibleShape` error is raised.
     fn least_squares(&self, rhs: &ArrayBase<D, I>) -> Result<LeastSquaresResult<E, I>>;
+
+    /// Solve a least squares problem of the form `Ax = rhs`
+    /// using the driver and the rank cutoff specified by `options`.
+    /// [LeastSquaresSvd::least_squares] uses the default options.
+    fn least_squares_with_options(
+        &self,
+        rhs: &ArrayBase<D, I>,
+        options: LeastSquaresOptions<E::Real>,
+    ) -> Result<LeastSquaresResult<E, I>>;
 }
 
 /// Solve least squares for owned matrices
@@ -123,6 +160,15 @@ where
     /// be both either row- or column-major format, otherwise a
     /// `IncompatibleShape` error is raised.
     fn least_squares_into(self, rhs: ArrayBase<D, I>) -> Result<LeastSquaresResult<E, I>>;
+
+    /// Solve a least squares problem of the form `Ax = rhs`
+    /// using the driver and the rank cutoff specified by `options`.
+    /// [LeastSquaresSvdInto::least_squares_into] uses the default options.
+    fn least_squares_into_with_options(
+        self,
+        rhs: ArrayBase<D, I>,
+        options: LeastSquaresOptions<E::Real>,
+    ) -> Result<LeastSquaresResult<E, I>>;
 }
 
 /// Solve least squares for mutable references, overwriting
@@ -145,6 +191,15 @@ where
         &mut self,
         rhs: &mut ArrayBase<D, I>,
     ) -> Result<LeastSquaresResult<E, I>>;
+
+    /// Solve a least squares problem of the form `Ax = rhs`
+    /// using the driver and the rank cutoff specified by `options`.
+    /// [LeastSquaresSvdInPlace::least_squares_in_place] uses the default options.
+    fn least_squares_in_place_with_options(
+        &mut self,
+        rhs: &mut ArrayBase<D, I>,
+        options: LeastSquaresOptions<E::Real>,
+    ) -> Result<LeastSquaresResult<E, I>>;
 }
 
 /// Solve least squares for immutable references and a single
@@ -165,9 +220,17 @@ where
     /// be both either row- or column-major format, otherwise a
     /// `IncompatibleShape` error is raised.
     fn least_squares(&self, rhs: &ArrayBase<D2, Ix1>) -> Result<LeastSquaresResult<E, Ix1>> {
+        self.least_squares_with_options(rhs, LeastSquaresOptions::default())
+    }
+
+    fn least_squares_with_options(
+        &self,
+        rhs: &ArrayBase<D2, Ix1>,
+        options: LeastSquaresOptions<E::Real>,
+    ) -> Result<LeastSquaresResult<E, Ix1>> {
         let a = self.to_owned();
         let b = rhs.to_owned();
-        a.least_squares_into(b)
+        a.least_squares_into_with_options(b, options)
     }
 }
 
@@ -189,9 +252,17 @@ where
     /// be both either row- or column-major format, otherwise a
     /// `IncompatibleShape` error is raised.
     fn least_squares(&self, rhs: &ArrayBase<D2, Ix2>) -> Result<LeastSquaresResult<E, Ix2>> {
+        self.least_squares_with_options(rhs, LeastSquaresOptions::default())
+    }
+
+    fn least_squares_with_options(
+        &self,
+        rhs: &ArrayBase<D2, Ix2>,
+        options: LeastSquaresOptions<E::Real>,
+    ) -> Result<LeastSquaresResult<E, Ix2>> {
         let a = self.to_owned();
         let b = rhs.to_owned();
-        a.least_squares_into(b)
+        a.least_squares_into_with_options(b, options)
     }
 }
 
@@ -214,11 +285,16 @@ where
     /// `A` and `rhs` must have the same layout, i.e. they must
     /// be both either row- or column-major format, otherwise a
     /// `IncompatibleShape` error is raised.
-    fn least_squares_into(
+    fn least_squares_into(self, rhs: ArrayBase<D2, Ix1>) -> Result<LeastSquaresResult<E, Ix1>> {
+        self.least_squares_into_with_options(rhs, LeastSquaresOptions::default())
+    }
+
+    fn least_squares_into_with_options(
         mut self,
         mut rhs: ArrayBase<D2, Ix1>,
+        options: LeastSquaresOptions<E::Real>,
     ) -> Result<LeastSquaresResult<E, Ix1>> {
-        self.least_squares_in_place(&mut rhs)
+        self.least_squares_in_place_with_options(&mut rhs, options)
     }
 }
 
@@ -241,11 +317,16 @@ where
     /// `A` and `rhs` must have the same layout, i.e. they must
     /// be both either row- or column-major format, otherwise a
     /// `IncompatibleShape` error is raised.
-    fn least_squares_into(
+    fn least_squares_into(self, rhs: ArrayBase<D2, Ix2>) -> Result<LeastSquaresResult<E, Ix2>> {
+        self.least_squares_into_with_options(rhs, LeastSquaresOptions::default())
+    }
+
+    fn least_squares_into_with_options(
         mut self,
         mut rhs: ArrayBase<D2, Ix2>,
+        options: LeastSquaresOptions<E::Real>,
     ) -> Result<LeastSquaresResult<E, Ix2>> {
-        self.least_squares_in_place(&mut rhs)
+        self.least_squares_in_place_with_options(&mut rhs, options)
     }
 }
 
@@ -271,6 +352,14 @@ where
     fn least_squares_in_place(
         &mut self,
         rhs: &mut ArrayBase<D2, Ix1>,
+    ) -> Result<LeastSquaresResult<E, Ix1>> {
+        self.least_squares_in_place_with_options(rhs, LeastSquaresOptions::default())
+    }
+
+    fn least_squares_in_place_with_options(
+        &mut self,
+        rhs: &mut ArrayBase<D2, Ix1>,
+        options: LeastSquaresOptions<E::Real>,
     ) -> Result<LeastSquaresResult<E, Ix1>> {
         if self.shape()[0] != rhs.shape()[0] {
             return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
@@ -281,9 +370,9 @@ where
             // for which we need `n` entries
             let mut new_rhs = Array1::<E>::zeros((n,));
             new_rhs.slice_mut(s![0..m]).assign(rhs);
-            compute_least_squares_srhs(self, &mut new_rhs)
+            compute_least_squares_srhs(self, &mut new_rhs, options)
         } else {
-            compute_least_squares_srhs(self, rhs)
+            compute_least_squares_srhs(self, rhs, options)
         }
     }
 }
@@ -291,20 +380,26 @@ where
 fn compute_least_squares_srhs<E, D1, D2>(
     a: &mut ArrayBase<D1, Ix2>,
     rhs: &mut ArrayBase<D2, Ix1>,
+    options: LeastSquaresOptions<E::Real>,
 ) -> Result<LeastSquaresResult<E, Ix1>>
 where
     E: Scalar + Lapack,
     D1: DataMut<Elem = E>,
     D2: DataMut<Elem = E>,
 {
+    let a_layout = a.layout()?;
+    let rhs_layout = a_layout.resized(rhs.len() as i32, 1);
     let LeastSquaresOwned::<E> {
         singular_values,
         rank,
-    } = E::least_squares(
-        a.layout()?,
+        driver,
+    } = E::least_squares_nrhs_with_options(
+        a_layout,
         a.as_allocated_mut()?,
+        rhs_layout,
         rhs.as_slice_memory_order_mut()
             .ok_or(LinalgError::MemoryNotCont)?,
+        options,
     )?;
 
     let (m, n) = (a.shape()[0], a.shape()[1]);
@@ -314,6 +409,7 @@ where
         solution,
         singular_values: Array::from_shape_vec((singular_values.len(),), singular_values)?,
         rank,
+        driver,
         residual_sum_of_squares,
     })
 }
@@ -354,6 +450,14 @@ where
     fn least_squares_in_place(
         &mut self,
         rhs: &mut ArrayBase<D2, Ix2>,
+    ) -> Result<LeastSquaresResult<E, Ix2>> {
+        self.least_squares_in_place_with_options(rhs, LeastSquaresOptions::default())
+    }
+
+    fn least_squares_in_place_with_options(
+        &mut self,
+        rhs: &mut ArrayBase<D2, Ix2>,
+        options: LeastSquaresOptions<E::Real>,
     ) -> Result<LeastSquaresResult<E, Ix2>> {
         if self.shape()[0] != rhs.shape()[0] {
             return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
@@ -368,9 +472,9 @@ where
                 MatrixLayout::F { .. } => Array2::<E>::zeros((n, k).f()),
             };
             new_rhs.slice_mut(s![0..m, ..]).assign(rhs);
-            compute_least_squares_nrhs(self, &mut new_rhs)
+            compute_least_squares_nrhs(self, &mut new_rhs, options)
         } else {
-            compute_least_squares_nrhs(self, rhs)
+            compute_least_squares_nrhs(self, rhs, options)
         }
     }
 }
@@ -378,6 +482,7 @@ where
 fn compute_least_squares_nrhs<E, D1, D2>(
     a: &mut ArrayBase<D1, Ix2>,
     rhs: &mut ArrayBase<D2, Ix2>,
+    options: LeastSquaresOptions<E::Real>,
 ) -> Result<LeastSquaresResult<E, Ix2>>
 where
     E: Scalar + Lapack,
@@ -389,11 +494,13 @@ where
     let LeastSquaresOwned::<E> {
         singular_values,
         rank,
-    } = E::least_squares_nrhs(
+        driver,
+    } = E::least_squares_nrhs_with_options(
         a_layout,
         a.as_allocated_mut()?,
         rhs_layout,
         rhs.as_allocated_mut()?,
+        options,
     )?;
 
     let solution: Array2<E> = rhs.slice(s![..a.shape()[1], ..]).to_owned();
@@ -404,6 +511,7 @@ where
         solution,
         singular_values,
         rank,
+        driver,
         residual_sum_of_squares,
     })
 }
@@ -9,13 +9,19 @@
 //!     - [Cholesky/LU decomposition](cholesky/index.html)
 //!     - [Eigenvalue decomposition](eig/index.html)
 //!     - [Eigenvalue decomposition for Hermite matrices](eigh/index.html)
+//!     - [Hessenberg decomposition](hessenberg/index.html)
+//!     - [Bidiagonal reduction](bidiagonal/index.html)
+//!     - [Schur decomposition](schur/index.html)
 //!     - [**S**ingular **V**alue **D**ecomposition](svd/index.html)
 //! - Solution of linear systems:
 //!    - [General matrices](solve/index.html)
 //!    - [Triangular matrices](triangular/index.html)
 //!    - [Hermitian/real symmetric matrices](solveh/index.html)
 //!    - [Tridiagonal matrices](tridiagonal/index.html)
+//!    - [Banded matrices](banded/index.html)
+//!    - [Hermitian/real symmetric matrices in packed storage](packed/index.html)
 //! - [Inverse matrix computation](solve/trait.Inverse.html)
+//! - [Sylvester, Lyapunov, and algebraic Riccati matrix equations](matrix_equation/index.html)
 //!
 //! Naming Convention
 //! -----------------------
@@ -50,6 +56,8 @@
 extern crate ndarray;
 
 pub mod assert;
+pub mod banded;
+pub mod bidiagonal;
 pub mod cholesky;
 pub mod convert;
 pub mod diagonal;
@@ -58,16 +66,20 @@ pub mod eigh;
 pub mod error;
 pub mod expm;
 pub mod generate;
+pub mod hessenberg;
 pub mod inner;
 pub mod krylov;
 pub mod layout;
 pub mod least_squares;
 pub mod lobpcg;
+pub mod matrix_equation;
 pub mod norm;
 pub mod normest1;
 pub mod operator;
 pub mod opnorm;
+pub mod packed;
 pub mod qr;
+pub mod schur;
 pub mod solve;
 pub mod solveh;
 pub mod svd;
@@ -76,23 +88,29 @@ pub mod trace;
 pub mod triangular;
 pub mod tridiagonal;
 pub mod types;
-pub mod expm;
 
 pub use crate::assert::*;
+pub use crate::banded::*;
+pub use crate::bidiagonal::*;
 pub use crate::cholesky::*;
 pub use crate::convert::*;
 pub use crate::diagonal::*;
 pub use crate::eig::*;
 pub use crate::eigh::*;
+pub use crate::expm::*;
 pub use crate::generate::*;
+pub use crate::hessenberg::*;
 pub use crate::inner::*;
 pub use crate::layout::*;
 pub use crate::least_squares::*;
 pub use crate::lobpcg::{TruncatedEig, TruncatedOrder, TruncatedSvd};
+pub use crate::matrix_equation::*;
 pub use crate::norm::*;
 pub use crate::operator::*;
 pub use crate::opnorm::*;
+pub use crate::packed::*;
 pub use crate::qr::*;
+pub use crate::schur::*;
 pub use crate::solve::*;
 pub use crate::solveh::*;
 pub use crate::svd::*;
@@ -101,4 +119,3 @@ pub use crate::trace::*;
 pub use crate::triangular::*;
 pub use crate::tridiagonal::*;
 pub use crate::types::*;
-pub use crate::expm::*;
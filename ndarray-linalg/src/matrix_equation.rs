@@ -0,0 +1,348 @@
+//! Sylvester, Lyapunov, and algebraic Riccati matrix equations
+//!
+//! - [solve_sylvester] solves the Sylvester equation `A X + X B = C`
+//! - [solve_continuous_lyapunov] solves the continuous Lyapunov equation `A X + X A^H + Q = 0`
+//! - [solve_discrete_lyapunov] solves the discrete Lyapunov (Stein) equation `A X A^H - X + Q = 0`
+//! - [solve_care] solves the continuous-time algebraic Riccati equation
+//!   `A^H X + X A - X B R^{-1} B^H X + Q = 0`
+//! - [solve_dare] solves the discrete-time algebraic Riccati equation
+//!   `A^H X A - X - A^H X B (R + B^H X B)^{-1} B^H X A + Q = 0`
+//!
+//! The linear equations are solved by the Bartels–Stewart algorithm, i.e. the coefficients are reduced
+//! into Schur forms, and the resulting triangular equation is solved by LAPACK `?trsyl`.
+//! For real matrices, the real Schur forms are used and the computation is done in real arithmetic.
+//!
+//! The Sylvester equation has a unique solution if and only if `A` and `-B` have no common eigenvalue.
+//! [LinalgError::SingularMatrixEquation] is returned if their spectra (nearly) overlap.
+//!
+//! The Riccati equations are solved by the Schur method, i.e. the stabilizing solution is computed
+//! from the stable invariant (deflating) subspace of the Hamiltonian matrix (symplectic pencil).
+//! [LinalgError::NoStabilizingSolution] is returned if the stabilizing solution does not exist.
+//!
+//! # Example
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! // Stable matrix, i.e. all eigenvalues have negative real parts
+//! let a: Array2<f64> = array![[-1.0, 2.0], [0.0, -3.0]];
+//! let q: Array2<f64> = Array2::eye(2);
+//! let x = solve_continuous_lyapunov(&a, &q)?;
+//! assert_close_l2!(&(a.dot(&x) + x.dot(&a.t())), &-&q, 1e-7);
+//! // The solution is symmetric positive definite
+//! assert_eq!(x, x.t());
+//! assert!(x.eigvalsh(UPLO::Upper)?.iter().all(|&e| e > 0.0));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! Linear quadratic regulator (LQR) of a double integrator:
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = array![[0.0, 1.0], [0.0, 0.0]];
+//! let b: Array2<f64> = array![[0.0], [1.0]];
+//! let q: Array2<f64> = Array2::eye(2);
+//! let r: Array2<f64> = Array2::eye(1);
+//! let (x, k) = solve_care(&a, &b, &q, &r)?;
+//! let residual = a.t().dot(&x) + x.dot(&a) - x.dot(&b).dot(&b.t()).dot(&x);
+//! assert_close_l2!(&residual, &-&q, 1e-7);
+//! // u = -K x stabilizes the system
+//! let eigs = (&a - &b.dot(&k)).eigvals()?;
+//! assert!(eigs.iter().all(|e| e.re < 0.0));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use ndarray::*;
+
+use crate::error::*;
+use crate::generate::conjugate;
+use crate::layout::*;
+use crate::opnorm::*;
+use crate::schur::*;
+use crate::solve::*;
+use crate::solveh::*;
+use crate::types::*;
+
+use lax::Transpose;
+use num_traits::{Float, Zero};
+
+/// Solve Sylvester equation `A X + X B = C`
+///
+/// `A`, `B`, and `C` must be of shapes `(m, m)`, `(n, n)`, and `(m, n)`, respectively.
+pub fn solve_sylvester<A, Sa, Sb, Sc>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix2>,
+    c: &ArrayBase<Sc, Ix2>,
+) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sc: Data<Elem = A>,
+{
+    a.ensure_square()?;
+    b.ensure_square()?;
+    if c.dim() != (a.nrows(), b.nrows()) {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let (ta, ua) = to_fortran(a).schur_into()?;
+    let (tb, ub) = to_fortran(b).schur_into()?;
+    // A = Ua Ta Ua^H and B = Ub Tb Ub^H yield Ta Y + Y Tb = Ua^H C Ub with X = Ua Y Ub^H
+    let uah: Array2<A> = conjugate(&ua);
+    let ubh: Array2<A> = conjugate(&ub);
+    let c = to_fortran(&uah.dot(c).dot(&ub));
+    let y = sylvester_schur(&ta, &tb, Transpose::No, c)?;
+    Ok(ua.dot(&y).dot(&ubh))
+}
+
+/// Solve continuous Lyapunov equation `A X + X A^H + Q = 0`
+///
+/// `Q` must be Hermitian (or real symmetric), and then the solution `X` is also Hermitian.
+/// The solution is explicitly symmetrized to cancel the rounding errors.
+/// If `A` is stable, i.e. all eigenvalues of `A` have negative real parts,
+/// and `Q` is positive (semi-)definite, `X` is positive (semi-)definite.
+pub fn solve_continuous_lyapunov<A, Sa, Sq>(
+    a: &ArrayBase<Sa, Ix2>,
+    q: &ArrayBase<Sq, Ix2>,
+) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sq: Data<Elem = A>,
+{
+    a.ensure_square()?;
+    if q.dim() != a.dim() {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let (t, u) = to_fortran(a).schur_into()?;
+    // A = U T U^H yields T Y + Y T^H = -U^H Q U with X = U Y U^H
+    let uh: Array2<A> = conjugate(&u);
+    let c = to_fortran(&-uh.dot(q).dot(&u));
+    let y = sylvester_schur(&t, &t, Transpose::Hermite, c)?;
+    Ok(symmetrize(u.dot(&y).dot(&uh)))
+}
+
+/// Solve discrete Lyapunov equation, also known as Stein equation, `A X A^H - X + Q = 0`
+///
+/// `Q` must be Hermitian (or real symmetric), and then the solution `X` is also Hermitian.
+/// If `A` is stable in the discrete sense, i.e. all eigenvalues of `A` lie in the unit disk,
+/// and `Q` is positive (semi-)definite, `X` is positive (semi-)definite.
+///
+/// This is reduced into the continuous Lyapunov equation `Ac X + X Ac^H + Qc = 0`
+/// by the Cayley transform `Ac = (A - I)(A + I)^{-1}` and `Qc = 2 (A + I)^{-1} Q (A + I)^{-H}`,
+/// and thus `-1` must not be an eigenvalue of `A`.
+/// Since the equation is singular in this case, [LinalgError::SingularMatrixEquation] is returned
+/// if `A + I` is exactly singular.
+pub fn solve_discrete_lyapunov<A, Sa, Sq>(
+    a: &ArrayBase<Sa, Ix2>,
+    q: &ArrayBase<Sq, Ix2>,
+) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sq: Data<Elem = A>,
+{
+    a.ensure_square()?;
+    if q.dim() != a.dim() {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let n = a.nrows();
+    let two = A::from_real(A::real(2.0));
+    // M = (A + I)^{-1}, and then Ac = (A - I) M = I - 2M
+    let m = (a + &Array2::eye(n)).inv().map_err(|e| match e {
+        LinalgError::Lapack(lax::error::Error::LapackComputationalFailure { .. }) => {
+            LinalgError::SingularMatrixEquation
+        }
+        e => e,
+    })?;
+    let ac = Array2::eye(n) - m.mapv(|x| x * two);
+    let mh: Array2<A> = conjugate(&m);
+    let qc = m.dot(q).dot(&mh).mapv(|x| x * two);
+    solve_continuous_lyapunov(&ac, &qc)
+}
+
+/// Solve continuous-time algebraic Riccati equation (CARE) `A^H X + X A - X B R^{-1} B^H X + Q = 0`
+///
+/// `A`, `B`, `Q`, and `R` must be of shapes `(n, n)`, `(n, m)`, `(n, n)`, and `(m, m)`, respectively.
+/// `Q` must be Hermitian, and `R` must be Hermitian and nonsingular.
+///
+/// This returns the stabilizing solution `X` and the feedback gain `K = R^{-1} B^H X`,
+/// i.e. all eigenvalues of the closed-loop matrix `A - B K` have negative real parts.
+/// `X` is computed from the stable invariant subspace of the Hamiltonian matrix
+/// `[[A, -B R^{-1} B^H], [-Q, -A^H]]`, and [LinalgError::NoStabilizingSolution] is returned
+/// if it has eigenvalues (nearly) on the imaginary axis or the subspace does not yield a solution,
+/// e.g. when `(A, B)` is not stabilizable.
+pub fn solve_care<A, Sa, Sb, Sq, Sr>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix2>,
+    q: &ArrayBase<Sq, Ix2>,
+    r: &ArrayBase<Sr, Ix2>,
+) -> Result<(Array2<A>, Array2<A>)>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sq: Data<Elem = A>,
+    Sr: Data<Elem = A>,
+{
+    let n = check_riccati_shape(a, b, q, r)?;
+    let ah: Array2<A> = conjugate(a);
+    let bh: Array2<A> = conjugate(b);
+    let rinv_bh = r.invh()?.dot(&bh);
+    let g = b.dot(&rinv_bh);
+
+    let mut h = Array2::zeros((2 * n, 2 * n).f());
+    h.slice_mut(s![..n, ..n]).assign(a);
+    h.slice_mut(s![..n, n..]).assign(&-g);
+    h.slice_mut(s![n.., ..n]).assign(&q.mapv(|x| -x));
+    h.slice_mut(s![n.., n..]).assign(&-ah);
+
+    let tol = A::real(100.0) * A::Real::epsilon() * h.opnorm_one()?;
+    let mut on_axis = false;
+    let (_, z, m) = h.schur_select(|e| {
+        on_axis |= Float::abs(e.re()) <= tol;
+        e.re() < A::Real::zero()
+    })?;
+    if on_axis || m != n {
+        return Err(LinalgError::NoStabilizingSolution);
+    }
+    let x = riccati_solution(z.slice(s![..n, ..n]), z.slice(s![n.., ..n]))?;
+    let k = rinv_bh.dot(&x);
+    Ok((x, k))
+}
+
+/// Solve discrete-time algebraic Riccati equation (DARE)
+/// `A^H X A - X - A^H X B (R + B^H X B)^{-1} B^H X A + Q = 0`
+///
+/// `A`, `B`, `Q`, and `R` must be of shapes `(n, n)`, `(n, m)`, `(n, n)`, and `(m, m)`, respectively.
+/// `Q` must be Hermitian, and `R` must be Hermitian and nonsingular.
+///
+/// This returns the stabilizing solution `X` and the feedback gain `K = (R + B^H X B)^{-1} B^H X A`,
+/// i.e. all eigenvalues of the closed-loop matrix `A - B K` lie in the unit disk.
+/// `X` is computed from the stable deflating subspace of the symplectic pencil
+/// `[[A, 0], [-Q, I]] - λ [[I, B R^{-1} B^H], [0, A^H]]` by the ordered generalized Schur decomposition,
+/// and thus `A` may be singular.
+/// [LinalgError::NoStabilizingSolution] is returned if the pencil has eigenvalues (nearly) on the unit circle
+/// or the subspace does not yield a solution, e.g. when `(A, B)` is not stabilizable.
+pub fn solve_dare<A, Sa, Sb, Sq, Sr>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix2>,
+    q: &ArrayBase<Sq, Ix2>,
+    r: &ArrayBase<Sr, Ix2>,
+) -> Result<(Array2<A>, Array2<A>)>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sq: Data<Elem = A>,
+    Sr: Data<Elem = A>,
+{
+    let n = check_riccati_shape(a, b, q, r)?;
+    let ah: Array2<A> = conjugate(a);
+    let bh: Array2<A> = conjugate(b);
+    let g = b.dot(&r.invh()?).dot(&bh);
+
+    let mut m = Array2::zeros((2 * n, 2 * n).f());
+    m.slice_mut(s![..n, ..n]).assign(a);
+    m.slice_mut(s![n.., ..n]).assign(&q.mapv(|x| -x));
+    m.slice_mut(s![n.., n..]).assign(&Array2::eye(n));
+    let mut l = Array2::zeros((2 * n, 2 * n).f());
+    l.slice_mut(s![..n, ..n]).assign(&Array2::eye(n));
+    l.slice_mut(s![..n, n..]).assign(&g);
+    l.slice_mut(s![n.., n..]).assign(&ah);
+
+    let tol = A::real(100.0) * A::Real::epsilon();
+    let mut on_circle = false;
+    let (_, _, _, z, dim) = (m, l).schur_generalized_select(|alpha, beta| {
+        let (alpha, beta) = (alpha.abs(), beta.abs());
+        on_circle |= Float::abs(alpha - beta) <= tol * Float::max(alpha, beta);
+        alpha < beta
+    })?;
+    if on_circle || dim != n {
+        return Err(LinalgError::NoStabilizingSolution);
+    }
+    let x = riccati_solution(z.slice(s![..n, ..n]), z.slice(s![n.., ..n]))?;
+    let bh_x = bh.dot(&x);
+    let k = (r + &bh_x.dot(b)).invh()?.dot(&bh_x).dot(a);
+    Ok((x, k))
+}
+
+/// Solve `op(Ta) Y + Y op(Tb) = C` where `op(Ta) = Ta` and `op(Tb)` is specified by `tranb`,
+/// for the matrices in the Schur canonical form in column-major order
+fn sylvester_schur<A: Scalar + Lapack>(
+    ta: &Array2<A>,
+    tb: &Array2<A>,
+    tranb: Transpose,
+    mut c: Array2<A>,
+) -> Result<Array2<A>> {
+    let scale = A::sylvester(
+        c.layout()?,
+        Transpose::No,
+        tranb,
+        1,
+        ta.as_allocated()?,
+        tb.as_allocated()?,
+        c.as_allocated_mut()?,
+    )
+    .map_err(|e| match e {
+        lax::error::Error::LapackComputationalFailure { return_code: 1 } => {
+            LinalgError::SingularMatrixEquation
+        }
+        e => e.into(),
+    })?;
+    let scale = A::from_real(scale);
+    Ok(c.mapv_into(|x| x / scale))
+}
+
+/// Check the shapes of the coefficients of Riccati equations, and returns the size of `X`
+fn check_riccati_shape<A, Sa, Sb, Sq, Sr>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix2>,
+    q: &ArrayBase<Sq, Ix2>,
+    r: &ArrayBase<Sr, Ix2>,
+) -> Result<usize>
+where
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sq: Data<Elem = A>,
+    Sr: Data<Elem = A>,
+{
+    a.ensure_square()?;
+    r.ensure_square()?;
+    let (n, m) = b.dim();
+    if a.nrows() != n || q.dim() != (n, n) || r.nrows() != m {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    Ok(n)
+}
+
+/// `X = U2 U1^{-1}` from the basis `[U1; U2]` of the stable subspace
+fn riccati_solution<A: Scalar + Lapack>(u1: ArrayView2<A>, u2: ArrayView2<A>) -> Result<Array2<A>> {
+    let f = u1.factorize().map_err(|e| match e {
+        LinalgError::Lapack(lax::error::Error::LapackComputationalFailure { .. }) => {
+            LinalgError::NoStabilizingSolution
+        }
+        e => e,
+    })?;
+    if f.rcond()? < A::Real::epsilon() {
+        return Err(LinalgError::NoStabilizingSolution);
+    }
+    Ok(symmetrize(u2.dot(&f.inv()?)))
+}
+
+/// Copy into a new matrix in column-major order
+fn to_fortran<A: Scalar, S: Data<Elem = A>>(a: &ArrayBase<S, Ix2>) -> Array2<A> {
+    let mut f = Array2::zeros(a.dim().f());
+    f.assign(a);
+    f
+}
+
+/// `(X + X^H) / 2`
+fn symmetrize<A: Scalar>(x: Array2<A>) -> Array2<A> {
+    let half = A::from_real(A::real(0.5));
+    let xh: Array2<A> = conjugate(&x);
+    (x + xh).mapv_into(|v| v * half)
+}
@@ -1,6 +1,6 @@
 //! Operator norm
 
-use lax::Tridiagonal;
+use lax::{Banded, Tridiagonal};
 use ndarray::*;
 
 use crate::error::*;
@@ -101,3 +101,14 @@ where
         Ok(A::opnorm(t, l, a))
     }
 }
+
+impl<A> OperationNorm for Banded<A>
+where
+    A: Scalar + Lapack,
+{
+    type Output = A::Real;
+
+    fn opnorm(&self, t: NormType) -> Result<Self::Output> {
+        Ok(A::opnorm_banded(t, self))
+    }
+}
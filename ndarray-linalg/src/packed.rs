@@ -0,0 +1,353 @@
+//! Symmetric/Hermitian matrices in packed storage
+//! &
+//! Methods for packed matrices
+//!
+//! Only the upper or lower triangular part of the matrix is stored,
+//! which halves the memory footprint compared to the full `n x n` array.
+//!
+//! # Example
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = array![
+//!     [4.0, 1.0, 2.0],
+//!     [1.0, 5.0, 3.0],
+//!     [2.0, 3.0, 6.0],
+//! ];
+//! let p = a.extract_packed(UPLO::Upper)?;
+//! assert_eq!(p.ap.len(), 6);
+//! assert_close_l2!(&p.to_dense(), &a, 1e-9);
+//!
+//! // Cholesky decomposition, since `a` is positive definite
+//! let b = array![1.0, 2.0, 3.0];
+//! let f = p.factorizec_packed()?;
+//! let x = f.solvec(&b)?;
+//! assert_close_l2!(&a.dot(&x), &b, 1e-9);
+//!
+//! // Eigenvalues
+//! let e = p.eigvalsh_packed()?;
+//! assert_close_l2!(&e, &a.eigvalsh(UPLO::Upper)?, 1e-9);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use super::cholesky::*;
+use super::error::*;
+use super::layout::*;
+use super::solveh::*;
+use cauchy::Scalar;
+use lax::*;
+use ndarray::*;
+
+pub use lax::Packed;
+
+/// An interface for making a Packed struct.
+pub trait ExtractPacked<A: Scalar> {
+    /// Extract the triangular part specified by `uplo` into packed storage.
+    ///
+    /// The other triangular part of the raw matrix is ignored.
+    fn extract_packed(&self, uplo: UPLO) -> Result<Packed<A>>;
+}
+
+impl<A, S> ExtractPacked<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn extract_packed(&self, uplo: UPLO) -> Result<Packed<A>> {
+        let l = self.square_layout()?;
+        let n = l.len() as usize;
+        let mut ap = Vec::with_capacity(Packed::<A>::packed_len(l.len()));
+        for j in 0..n {
+            let rows = match uplo {
+                UPLO::Upper => 0..(j + 1),
+                UPLO::Lower => j..n,
+            };
+            ap.extend(rows.map(|i| self[(i, j)]));
+        }
+        Ok(Packed { l, uplo, ap })
+    }
+}
+
+/// Expand packed storage into a full matrix
+pub trait ToDense<A: Scalar> {
+    /// Returns the full `n x n` symmetric/Hermitian matrix,
+    /// where the triangular part not stored is filled with the conjugate of the stored one.
+    fn to_dense(&self) -> Array2<A>;
+}
+
+impl<A> ToDense<A> for Packed<A>
+where
+    A: Scalar + Lapack,
+{
+    fn to_dense(&self) -> Array2<A> {
+        let n = self.l.len() as usize;
+        Array2::from_shape_fn((n, n), |(i, j)| {
+            let (i, j) = (i as i32, j as i32);
+            match (self.uplo, i <= j) {
+                (UPLO::Upper, true) | (UPLO::Lower, false) => self[(i, j)],
+                _ => self[(j, i)].conj(),
+            }
+        })
+    }
+}
+
+/// Cholesky decomposition of Hermitian (or real symmetric) positive definite matrix in packed storage
+pub struct CholeskyFactorizedPacked<A: Scalar> {
+    /// `L` from the decomposition `A = L * L^H` for `UPLO::Lower`,
+    /// or `U` from the decomposition `A = U^H * U` for `UPLO::Upper`
+    /// in packed storage.
+    pub factor: Packed<A>,
+}
+
+/// Represents the Bunch–Kaufman factorization of a Hermitian (or real
+/// symmetric) matrix in packed storage.
+pub struct BKFactorizedPacked<A: Scalar> {
+    pub a: Packed<A>,
+    pub ipiv: Pivot,
+}
+
+/// Cholesky decomposition of packed matrix reference
+pub trait FactorizeCPacked<A: Scalar> {
+    /// Computes the Cholesky decomposition of the Hermitian (or real
+    /// symmetric) positive definite matrix using the stored triangular part.
+    fn factorizec_packed(&self) -> Result<CholeskyFactorizedPacked<A>>;
+}
+
+/// Cholesky decomposition of packed matrix
+pub trait FactorizeCPackedInto<A: Scalar> {
+    /// Computes the Cholesky decomposition of the Hermitian (or real
+    /// symmetric) positive definite matrix, reusing the packed storage for the factor.
+    fn factorizec_packed_into(self) -> Result<CholeskyFactorizedPacked<A>>;
+}
+
+impl<A> FactorizeCPackedInto<A> for Packed<A>
+where
+    A: Scalar + Lapack,
+{
+    fn factorizec_packed_into(mut self) -> Result<CholeskyFactorizedPacked<A>> {
+        A::cholesky_packed(&mut self)?;
+        Ok(CholeskyFactorizedPacked { factor: self })
+    }
+}
+
+impl<A> FactorizeCPacked<A> for Packed<A>
+where
+    A: Scalar + Lapack,
+{
+    fn factorizec_packed(&self) -> Result<CholeskyFactorizedPacked<A>> {
+        self.clone().factorizec_packed_into()
+    }
+}
+
+impl<A> SolveC<A> for CholeskyFactorizedPacked<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solvec_inplace<'a, Sb>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let n = self.factor.l.len();
+        assert_eq!(
+            b.len(),
+            n as usize,
+            "The length of `b` must be compatible with the shape of the factored matrix.",
+        );
+        A::solve_cholesky_packed(
+            &self.factor,
+            MatrixLayout::F { col: 1, lda: n },
+            b.as_slice_mut().unwrap(),
+        )?;
+        Ok(b)
+    }
+}
+
+impl<A> SolveC<A> for Packed<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solvec_inplace<'a, Sb>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        self.factorizec_packed()?.solvec_inplace(b)
+    }
+}
+
+impl<A> InverseC for CholeskyFactorizedPacked<A>
+where
+    A: Scalar + Lapack,
+{
+    type Output = Packed<A>;
+
+    fn invc(&self) -> Result<Self::Output> {
+        let f = CholeskyFactorizedPacked {
+            factor: self.factor.clone(),
+        };
+        f.invc_into()
+    }
+}
+
+impl<A> InverseCInto for CholeskyFactorizedPacked<A>
+where
+    A: Scalar + Lapack,
+{
+    type Output = Packed<A>;
+
+    fn invc_into(self) -> Result<Self::Output> {
+        let mut a = self.factor;
+        A::inv_cholesky_packed(&mut a)?;
+        Ok(a)
+    }
+}
+
+impl<A> InverseC for Packed<A>
+where
+    A: Scalar + Lapack,
+{
+    type Output = Packed<A>;
+
+    fn invc(&self) -> Result<Self::Output> {
+        self.factorizec_packed()?.invc_into()
+    }
+}
+
+impl<A> InverseCInto for Packed<A>
+where
+    A: Scalar + Lapack,
+{
+    type Output = Packed<A>;
+
+    fn invc_into(self) -> Result<Self::Output> {
+        self.factorizec_packed_into()?.invc_into()
+    }
+}
+
+/// Bunch–Kaufman factorization of packed matrix reference
+pub trait FactorizeHPacked<A: Scalar> {
+    /// Computes the Bunch–Kaufman factorization of a Hermitian (or real
+    /// symmetric) matrix using the stored triangular part.
+    fn factorizeh_packed(&self) -> Result<BKFactorizedPacked<A>>;
+}
+
+/// Bunch–Kaufman factorization of packed matrix
+pub trait FactorizeHPackedInto<A: Scalar> {
+    /// Computes the Bunch–Kaufman factorization of a Hermitian (or real
+    /// symmetric) matrix, reusing the packed storage for the factorization.
+    fn factorizeh_packed_into(self) -> Result<BKFactorizedPacked<A>>;
+}
+
+impl<A> FactorizeHPackedInto<A> for Packed<A>
+where
+    A: Scalar + Lapack,
+{
+    fn factorizeh_packed_into(mut self) -> Result<BKFactorizedPacked<A>> {
+        let ipiv = A::bk_packed(&mut self)?;
+        Ok(BKFactorizedPacked { a: self, ipiv })
+    }
+}
+
+impl<A> FactorizeHPacked<A> for Packed<A>
+where
+    A: Scalar + Lapack,
+{
+    fn factorizeh_packed(&self) -> Result<BKFactorizedPacked<A>> {
+        self.clone().factorizeh_packed_into()
+    }
+}
+
+impl<A> SolveH<A> for BKFactorizedPacked<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solveh_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let n = self.a.l.len();
+        assert_eq!(
+            rhs.len(),
+            n as usize,
+            "The length of `rhs` must be compatible with the shape of the factored matrix.",
+        );
+        A::solveh_packed(
+            &self.a,
+            &self.ipiv,
+            MatrixLayout::F { col: 1, lda: n },
+            rhs.as_slice_mut().unwrap(),
+        )?;
+        Ok(rhs)
+    }
+}
+
+impl<A> SolveH<A> for Packed<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solveh_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        self.factorizeh_packed()?.solveh_inplace(rhs)
+    }
+}
+
+/// Calculate eigenvalues and eigenvectors of symmetric/Hermitian matrix in packed storage
+pub trait EighPacked {
+    type EigVal;
+    type EigVec;
+
+    /// Compute all eigenvalues in ascending order and the corresponding eigenvectors.
+    fn eigh_packed(&self) -> Result<(Self::EigVal, Self::EigVec)>;
+}
+
+impl<A> EighPacked for Packed<A>
+where
+    A: Scalar + Lapack,
+{
+    type EigVal = Array1<A::Real>;
+    type EigVec = Array2<A>;
+
+    fn eigh_packed(&self) -> Result<(Self::EigVal, Self::EigVec)> {
+        let lax::EighRangeOwned { eigs, z } = A::eigh_packed(true, &mut self.clone())?;
+        let n = self.l.len() as usize;
+        Ok((
+            ArrayBase::from(eigs),
+            Array2::from_shape_vec((n, n).f(), z.unwrap()).unwrap(),
+        ))
+    }
+}
+
+/// Calculate eigenvalues of symmetric/Hermitian matrix in packed storage without eigenvectors
+pub trait EigValshPacked {
+    type EigVal;
+
+    /// Compute all eigenvalues in ascending order. See [EighPacked::eigh_packed]
+    fn eigvalsh_packed(&self) -> Result<Self::EigVal>;
+}
+
+impl<A> EigValshPacked for Packed<A>
+where
+    A: Scalar + Lapack,
+{
+    type EigVal = Array1<A::Real>;
+
+    fn eigvalsh_packed(&self) -> Result<Self::EigVal> {
+        let lax::EighRangeOwned { eigs, .. } = A::eigh_packed(false, &mut self.clone())?;
+        Ok(ArrayBase::from(eigs))
+    }
+}
@@ -128,6 +128,110 @@ where
     }
 }
 
+/// Shape of the QR decomposition of `m x n` matrix
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QRMode {
+    /// `Q` is `m x k` and `R` is `k x n` where `k = min(m, n)`, same as [QR]
+    Economy,
+    /// `Q` is `m x m` unitary matrix and `R` is `m x n`
+    Full,
+}
+
+/// QR decomposition with column pivoting for matrix reference
+///
+/// This computes `A P = Q R` where `P` is a permutation matrix chosen so that
+/// the absolute values of the diagonal elements of `R` are non-increasing.
+/// The permutation is returned as column indices `p`,
+/// i.e. the `j`-th column of `A P` is the `p[j]`-th column of `A`.
+pub trait QRPivoted {
+    type Q;
+    type R;
+    fn qr_pivoted(&self, mode: QRMode) -> Result<(Self::Q, Self::R, Vec<usize>)>;
+}
+
+/// QR decomposition with column pivoting
+///
+/// See [QRPivoted] for detail.
+pub trait QRPivotedInto: Sized {
+    type Q;
+    type R;
+    fn qr_pivoted_into(self, mode: QRMode) -> Result<(Self::Q, Self::R, Vec<usize>)>;
+}
+
+impl<A, S> QRPivotedInto for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type Q = Array2<A>;
+    type R = Array2<A>;
+
+    fn qr_pivoted_into(mut self, mode: QRMode) -> Result<(Self::Q, Self::R, Vec<usize>)> {
+        let m = self.nrows();
+        let n = self.ncols();
+        let k = ::std::cmp::min(m, n);
+        let l = self.layout()?;
+        let (mut tau, jpvt) = A::householder_pivoted(l, self.as_allocated_mut()?)?;
+        let rows = match mode {
+            QRMode::Economy => k,
+            QRMode::Full => m,
+        };
+        let r = take_slice_upper(&self, rows, n);
+        // Reflectors beyond `k` are padded by identity, i.e. `tau = 0`
+        tau.resize(rows, A::zero());
+        let mut q = Array2::zeros((m, rows).f());
+        q.slice_mut(s![.., ..k]).assign(&self.slice(s![.., ..k]));
+        A::q(q.layout()?, q.as_allocated_mut()?, &tau)?;
+        let p = jpvt.into_iter().map(|j| j as usize - 1).collect();
+        Ok((q, r, p))
+    }
+}
+
+impl<A, S> QRPivoted for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Q = Array2<A>;
+    type R = Array2<A>;
+
+    fn qr_pivoted(&self, mode: QRMode) -> Result<(Self::Q, Self::R, Vec<usize>)> {
+        let a = self.to_owned();
+        a.qr_pivoted_into(mode)
+    }
+}
+
+/// Numerical rank of matrix
+pub trait Rank {
+    type Real;
+
+    /// Estimates the numerical rank using QR decomposition with column pivoting.
+    ///
+    /// Since the absolute values of the diagonal elements of `R` are non-increasing,
+    /// this counts the diagonal elements satisfying `|R_ii| > tol * |R_00|`.
+    fn rank(&self, tol: Self::Real) -> Result<usize>;
+}
+
+impl<A, S> Rank for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Real = A::Real;
+
+    fn rank(&self, tol: Self::Real) -> Result<usize> {
+        let mut a = self.to_owned();
+        let l = a.layout()?;
+        A::householder_pivoted(l, a.as_allocated_mut()?)?;
+        let diag = a.diag();
+        let r00 = match diag.first() {
+            Some(r) => r.abs(),
+            None => return Ok(0),
+        };
+        Ok(diag.iter().take_while(|r| r.abs() > tol * r00).count())
+    }
+}
+
 fn take_slice<A, S1, S2>(a: &ArrayBase<S1, Ix2>, n: usize, m: usize) -> ArrayBase<S2, Ix2>
 where
     A: Copy,
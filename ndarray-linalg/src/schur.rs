@@ -0,0 +1,229 @@
+//! Schur decomposition for general square matrices
+//!
+//! For a square matrix `A`, this computes `A = Z T Z^H`
+//! where `Z` is unitary (orthogonal for real matrices) and `T` is upper triangular.
+//! For real matrices, `T` is quasi upper triangular,
+//! i.e. a complex conjugate pair of eigenvalues appears as a 2-by-2 diagonal block.
+//!
+//! For a pair of square matrices `(A, B)`, the generalized Schur (QZ) decomposition
+//! `A = Q S Z^H`, `B = Q T Z^H` is also provided by [SchurGeneralized].
+//!
+//! # Example
+//!
+//! Move the stable eigenvalues (`Re λ < 0`) to the leading block of `T`:
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = array![
+//!     [1.0, 2.0, 0.5],
+//!     [0.0, -3.0, 1.0],
+//!     [0.0, 0.0, -1.0],
+//! ];
+//! let (t, z, m) = a.schur_select(|e| e.re < 0.0)?;
+//! assert_eq!(m, 2);
+//! assert!(t[(0, 0)] < 0.0 && t[(1, 1)] < 0.0);
+//! assert_close_l2!(&z.dot(&t).dot(&z.t()), &a, 1e-7);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use ndarray::*;
+
+use crate::convert::*;
+use crate::eig::to_fortran_pair;
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+
+/// Schur decomposition of general matrix reference
+pub trait Schur {
+    type Elem: Scalar;
+    type T;
+    type Z;
+
+    /// Compute Schur decomposition `A = Z T Z^H` and returns `(T, Z)`
+    fn schur(&self) -> Result<(Self::T, Self::Z)>;
+
+    /// Compute Schur decomposition,
+    /// and reorder it so that the eigenvalues for which `select` returns `true`
+    /// appear in the leading diagonal block of `T`.
+    ///
+    /// Returns `(T, Z, m)` where the first `m` columns of `Z`
+    /// span the invariant subspace of the selected eigenvalues.
+    /// For real matrices, a complex conjugate pair is selected
+    /// if `select` returns `true` for either of them.
+    fn schur_select<F>(&self, select: F) -> Result<(Self::T, Self::Z, usize)>
+    where
+        F: FnMut(<Self::Elem as Scalar>::Complex) -> bool;
+}
+
+/// Schur decomposition of general matrix
+pub trait SchurInto: Sized {
+    type Elem: Scalar;
+    type Z;
+
+    /// Compute Schur decomposition `A = Z T Z^H` and returns `(T, Z)`
+    fn schur_into(self) -> Result<(Self, Self::Z)>;
+
+    /// Compute reordered Schur decomposition. See [Schur::schur_select]
+    fn schur_select_into<F>(self, select: F) -> Result<(Self, Self::Z, usize)>
+    where
+        F: FnMut(<Self::Elem as Scalar>::Complex) -> bool;
+}
+
+impl<A, S> SchurInto for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type Elem = A;
+    type Z = Array2<A>;
+
+    fn schur_into(mut self) -> Result<(Self, Self::Z)> {
+        let layout = self.square_layout()?;
+        let lax::SchurOwned { vs, .. } = A::schur(true, layout, self.as_allocated_mut()?)?;
+        let z = into_matrix(layout, vs.unwrap())?;
+        Ok((self, z))
+    }
+
+    fn schur_select_into<F>(mut self, select: F) -> Result<(Self, Self::Z, usize)>
+    where
+        F: FnMut(A::Complex) -> bool,
+    {
+        let layout = self.square_layout()?;
+        let t = self.as_allocated_mut()?;
+        let lax::SchurOwned { eigs, vs } = A::schur(true, layout, t)?;
+        let mut z = vs.unwrap();
+        let select: Vec<bool> = eigs.into_iter().map(select).collect();
+        let (_, m) = A::schur_reorder(layout, &select, t, &mut z)?;
+        let z = into_matrix(layout, z)?;
+        Ok((self, z, m as usize))
+    }
+}
+
+impl<A, S> Schur for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Elem = A;
+    type T = Array2<A>;
+    type Z = Array2<A>;
+
+    fn schur(&self) -> Result<(Self::T, Self::Z)> {
+        let a = self.to_owned();
+        a.schur_into()
+    }
+
+    fn schur_select<F>(&self, select: F) -> Result<(Self::T, Self::Z, usize)>
+    where
+        F: FnMut(A::Complex) -> bool,
+    {
+        let a = self.to_owned();
+        a.schur_select_into(select)
+    }
+}
+
+/// Generalized Schur (QZ) decomposition of a pair of general matrices
+pub trait SchurGeneralized {
+    type Elem: Scalar;
+    type Complex;
+    type Matrix;
+
+    /// Compute generalized Schur decomposition `A = Q S Z^H`, `B = Q T Z^H`
+    /// and returns `(S, T, Q, Z)`
+    ///
+    /// `T` is upper triangular, and `S` is upper triangular for complex matrices
+    /// or quasi upper triangular for real matrices.
+    /// The generalized eigenvalues are given as the ratio of the diagonal elements `S_ii / T_ii`
+    /// for triangular `S`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shapes of the matrices are different.
+    fn schur_generalized(&self)
+        -> Result<(Self::Matrix, Self::Matrix, Self::Matrix, Self::Matrix)>;
+
+    /// Compute generalized Schur decomposition, and reorder it
+    /// so that the generalized eigenvalues `alpha / beta` selected by `select(alpha, beta)`
+    /// appear in the leading blocks of `S` and `T`.
+    ///
+    /// Returns `(S, T, Q, Z, m)` where the leading `m` columns of `Z` span
+    /// the deflating subspace corresponding to the selected eigenvalues.
+    /// For real matrices, a complex conjugate pair of eigenvalues is selected together.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shapes of the matrices are different.
+    fn schur_generalized_select<F>(
+        &self,
+        select: F,
+    ) -> Result<(
+        Self::Matrix,
+        Self::Matrix,
+        Self::Matrix,
+        Self::Matrix,
+        usize,
+    )>
+    where
+        F: FnMut(Self::Complex, Self::Elem) -> bool;
+}
+
+impl<A, S, S2> SchurGeneralized for (ArrayBase<S, Ix2>, ArrayBase<S2, Ix2>)
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    type Elem = A;
+    type Complex = A::Complex;
+    type Matrix = Array2<A>;
+
+    fn schur_generalized(
+        &self,
+    ) -> Result<(Self::Matrix, Self::Matrix, Self::Matrix, Self::Matrix)> {
+        let (mut a, mut b) = to_fortran_pair(&self.0, &self.1);
+        let layout = a.square_layout()?;
+        let lax::SchurGeneralizedOwned { vsl, vsr, .. } =
+            A::schur_generalized(true, layout, a.as_allocated_mut()?, b.as_allocated_mut()?)?;
+        let q = into_matrix(layout, vsl.unwrap())?;
+        let z = into_matrix(layout, vsr.unwrap())?;
+        Ok((a, b, q, z))
+    }
+
+    fn schur_generalized_select<F>(
+        &self,
+        mut select: F,
+    ) -> Result<(
+        Self::Matrix,
+        Self::Matrix,
+        Self::Matrix,
+        Self::Matrix,
+        usize,
+    )>
+    where
+        F: FnMut(A::Complex, A) -> bool,
+    {
+        let (mut a, mut b) = to_fortran_pair(&self.0, &self.1);
+        let layout = a.square_layout()?;
+        let s = a.as_allocated_mut()?;
+        let t = b.as_allocated_mut()?;
+        let lax::SchurGeneralizedOwned {
+            alpha,
+            beta,
+            vsl,
+            vsr,
+        } = A::schur_generalized(true, layout, s, t)?;
+        let (mut q, mut z) = (vsl.unwrap(), vsr.unwrap());
+        let select: Vec<bool> = alpha
+            .into_iter()
+            .zip(beta)
+            .map(|(alpha, beta)| select(alpha, beta))
+            .collect();
+        let (_, _, m) = A::schur_generalized_reorder(layout, &select, s, t, &mut q, &mut z)?;
+        let q = into_matrix(layout, q)?;
+        let z = into_matrix(layout, z)?;
+        Ok((a, b, q, z, m as usize))
+    }
+}
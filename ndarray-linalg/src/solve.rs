@@ -45,7 +45,7 @@ use crate::layout::*;
 use crate::opnorm::OperationNorm;
 use crate::types::*;
 
-pub use lax::{Pivot, Transpose};
+pub use lax::{Equilibration, Pivot, Refinement, Transpose};
 
 /// An interface for solving systems of linear equations.
 ///
@@ -565,9 +565,17 @@ pub trait ReciprocalConditionNum<A: Scalar> {
     /// *Estimates* the reciprocal of the condition number of the matrix in
     /// 1-norm.
     ///
-    /// This method uses the LAPACK `*gecon` routines, which *estimate*
-    /// `self.inv().opnorm_one()` and then compute `rcond = 1. /
-    /// (self.opnorm_one() * self.inv().opnorm_one())`.
+    /// This method uses the LAPACK `*gecon` routines for the LU factorization,
+    /// `*pocon` for the Cholesky factorization, and `*sycon`/`*hecon` for the
+    /// Bunch–Kaufman factorization, which *estimate* `self.inv().opnorm_one()`
+    /// and then compute `rcond = 1. / (self.opnorm_one() * self.inv().opnorm_one())`.
+    /// The factorizations with [PivotStrategy::Rook](crate::PivotStrategy::Rook) and
+    /// [PivotStrategy::Aasen](crate::PivotStrategy::Aasen) use the explicit inverse,
+    /// which costs O(n³), instead of an estimate.
+    ///
+    /// For the Cholesky and Bunch–Kaufman factorizations, this is implemented for a pair
+    /// `(&A, &f)` of the original matrix `A` and its factorization `f`,
+    /// since the 1-norm of `A` is not kept in `f`.
     ///
     /// * If `rcond` is near `0.`, the matrix is badly conditioned.
     /// * If `rcond` is near `1.`, the matrix is well conditioned.
@@ -631,3 +639,167 @@ where
         self.factorize_into()?.rcond_into()
     }
 }
+
+/// Solution of a system of linear equations with its error bounds computed by
+/// [SolveExpert], [SolveCExpert](crate::SolveCExpert), or [SolveHExpert](crate::SolveHExpert)
+#[derive(Debug, Clone)]
+pub struct SolveExpertResult<A: Scalar> {
+    /// The solution `x`
+    pub x: Array1<A>,
+    /// Estimate of the reciprocal condition number of the (equilibrated) matrix in 1-norm
+    pub rcond: A::Real,
+    /// Estimated forward error bound, i.e. an upper bound of `max|x - x_true| / max|x|`
+    pub forward_error: A::Real,
+    /// Componentwise relative backward error, i.e. the smallest relative change
+    /// in any element of `A` or `b` that makes `x` an exact solution
+    pub backward_error: A::Real,
+    /// Equilibration applied to `A` before the factorization
+    pub equilibration: Equilibration,
+}
+
+impl<A: Scalar> SolveExpertResult<A> {
+    pub(crate) fn new(x: Array1<A>, result: lax::SolveExpertOwned<A>) -> Self {
+        SolveExpertResult {
+            x,
+            rcond: result.rcond,
+            forward_error: result.ferr,
+            backward_error: result.berr,
+            equilibration: result.equed,
+        }
+    }
+}
+
+/// An interface for solving systems of linear equations with equilibration,
+/// iterative refinement, and error bounds using LU factorization.
+///
+/// This is implemented for a matrix `A`, and for a pair `(&A, &f)` of the matrix
+/// and its LU factorization `f` computed by [Factorize]. In the latter case,
+/// the factorization is reused and equilibration is not performed.
+pub trait SolveExpert<A: Scalar> {
+    /// Solves a system of linear equations `A * x = b` where `b` is the argument.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `b` is not the equal to the number of columns
+    /// of `A`.
+    fn solve_expert<S: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, Ix1>,
+    ) -> Result<SolveExpertResult<A>>;
+}
+
+impl<A, S> SolveExpert<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn solve_expert<Sb>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<SolveExpertResult<A>>
+    where
+        Sb: Data<Elem = A>,
+    {
+        assert_eq!(
+            b.len(),
+            self.len_of(Axis(1)),
+            "The length of `b` must be compatible with the shape of the matrix.",
+        );
+        let mut a: Array2<A> = replicate(self);
+        let mut x: Array1<A> = replicate(b);
+        let result = A::solve_expert(
+            a.square_layout()?,
+            Transpose::No,
+            a.as_allocated_mut()?,
+            None,
+            x.as_slice_mut().unwrap(),
+        )?;
+        Ok(SolveExpertResult::new(x, result))
+    }
+}
+
+impl<A, S, Sf> SolveExpert<A> for (&ArrayBase<S, Ix2>, &LUFactorized<Sf>)
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    Sf: Data<Elem = A> + RawDataClone,
+{
+    fn solve_expert<Sb>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<SolveExpertResult<A>>
+    where
+        Sb: Data<Elem = A>,
+    {
+        let (a, f) = *self;
+        assert_eq!(
+            a.shape(),
+            f.a.shape(),
+            "The shapes of the matrix and its factorization must be identical.",
+        );
+        assert_eq!(
+            b.len(),
+            a.len_of(Axis(1)),
+            "The length of `b` must be compatible with the shape of the matrix.",
+        );
+        // The original matrix must be in the same layout as the factorization
+        let l = f.a.square_layout()?;
+        let mut a: Array2<A> = clone_with_layout(l, a);
+        let mut x: Array1<A> = replicate(b);
+        let result = A::solve_expert(
+            l,
+            Transpose::No,
+            a.as_allocated_mut()?,
+            Some((f.a.as_allocated()?, &f.ipiv)),
+            x.as_slice_mut().unwrap(),
+        )?;
+        Ok(SolveExpertResult::new(x, result))
+    }
+}
+
+/// Solution of a system of linear equations computed by mixed-precision
+/// iterative refinement, see [SolveMixed] and [SolveCMixed](crate::SolveCMixed)
+#[derive(Debug, Clone)]
+pub struct SolveMixedResult<A> {
+    /// The solution `x`
+    pub x: Array1<A>,
+    /// Number of refinement iterations, or the reason why the solver fell back
+    /// to the factorization in full precision
+    pub refinement: Refinement,
+}
+
+/// An interface for solving systems of linear equations using LU factorization
+/// in single precision with iterative refinement in double precision.
+///
+/// This is implemented only for `f64` and `c64` matrices. If the refinement
+/// does not converge, e.g. for ill-conditioned matrices, the system is solved
+/// by LU factorization in double precision instead, and it is reported as
+/// [Refinement::FullPrecision].
+pub trait SolveMixed<A: Scalar> {
+    /// Solves a system of linear equations `A * x = b` where `b` is the argument.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `b` is not the equal to the number of columns
+    /// of `A`.
+    fn solve_mixed<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<SolveMixedResult<A>>;
+}
+
+impl<A, S> SolveMixed<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + LapackMixed,
+    S: Data<Elem = A>,
+{
+    fn solve_mixed<Sb>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<SolveMixedResult<A>>
+    where
+        Sb: Data<Elem = A>,
+    {
+        assert_eq!(
+            b.len(),
+            self.len_of(Axis(1)),
+            "The length of `b` must be compatible with the shape of the matrix.",
+        );
+        let mut a: Array2<A> = replicate(self);
+        let mut x: Array1<A> = replicate(b);
+        let refinement = A::solve_mixed(
+            a.square_layout()?,
+            a.as_allocated_mut()?,
+            x.as_slice_mut().unwrap(),
+        )?;
+        Ok(SolveMixedResult { x, refinement })
+    }
+}
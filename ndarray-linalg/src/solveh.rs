@@ -39,6 +39,40 @@
 //!     let x = f.solveh_into(b).unwrap(); // Solve A * x = b using the factorization
 //! }
 //! ```
+//!
+//! The pivoting strategy can be chosen by [FactorizeH::factorizeh_with],
+//! and the factors `A = P * L * D * L^H * P^T` are available as arrays:
+//!
+//! ```
+//! use ndarray::prelude::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = array![
+//!     [1., 2., 3.],
+//!     [2., 0., 1.],
+//!     [3., 1., -4.]
+//! ];
+//! let f = a.factorizeh_with(PivotStrategy::Rook).unwrap();
+//! let (l, d, p) = (f.l(), f.d(), f.p());
+//! assert!(p.dot(&l).dot(&d).dot(&l.t()).dot(&p.t()).abs_diff_eq(&a, 1e-9));
+//! ```
+//!
+//! The inertia of `A`, i.e. the numbers of positive, negative, and zero eigenvalues,
+//! is obtained from `D` by [Inertia] without computing the eigenvalues:
+//!
+//! ```
+//! use ndarray::prelude::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = array![
+//!     [2., 1., 0.],
+//!     [1., 2., 0.],
+//!     [0., 0., -1.]
+//! ];
+//! assert_eq!(a.inertia().unwrap(), (2, 1, 0));
+//! // Eigenvalues are -1, 1, and 3
+//! assert_eq!(a.count_eigenvalues_below(2.).unwrap(), 2);
+//! ```
 
 use ndarray::*;
 use num_traits::{Float, One, Zero};
@@ -46,9 +80,12 @@ use num_traits::{Float, One, Zero};
 use crate::convert::*;
 use crate::error::*;
 use crate::layout::*;
+use crate::opnorm::OperationNorm;
+use crate::solve::{ReciprocalConditionNum, SolveExpertResult};
 use crate::types::*;
 
-pub use lax::{Pivot, UPLO};
+use lax::NormType;
+pub use lax::{Pivot, PivotStrategy, UPLO};
 
 /// An interface for solving systems of Hermitian (or real symmetric) linear equations.
 ///
@@ -104,9 +141,15 @@ pub trait SolveH<A: Scalar> {
 
 /// Represents the Bunch–Kaufman factorization of a Hermitian (or real
 /// symmetric) matrix as `A = P * U * D * U^H * P^T`.
+///
+/// With [PivotStrategy::Aasen], this represents `A = P * U^H * T * U * P^T`
+/// with a tridiagonal matrix `T` instead.
+/// The factors are stored in the LAPACK format,
+/// and can be unpacked by [BKFactorized::l], [BKFactorized::d], and [BKFactorized::p].
 pub struct BKFactorized<S: Data> {
     pub a: ArrayBase<S, Ix2>,
     pub ipiv: Pivot,
+    strategy: PivotStrategy,
 }
 
 impl<A, S> SolveH<A> for BKFactorized<S>
@@ -126,9 +169,10 @@ where
             self.a.len_of(Axis(1)),
             "The length of `rhs` must be compatible with the shape of the factored matrix.",
         );
-        A::solveh(
+        A::solve_ldl(
             self.a.square_layout()?,
             UPLO::Upper,
+            self.strategy,
             self.a.as_allocated()?,
             &self.ipiv,
             rhs.as_slice_mut().unwrap(),
@@ -154,12 +198,102 @@ where
     }
 }
 
+/// An interface for solving systems of Hermitian (or real symmetric) linear equations
+/// with iterative refinement and error bounds.
+///
+/// This is implemented for a matrix `A`, and for a pair `(&A, &f)` of the matrix
+/// and its Bunch–Kaufman factorization `f` computed by [FactorizeH].
+/// In the latter case, the factorization is reused if it is computed with
+/// [PivotStrategy::BunchKaufman], and otherwise `A` is factorized again.
+/// Equilibration is not performed in either case.
+pub trait SolveHExpert<A: Scalar> {
+    /// Solves a system of linear equations `A * x = b` with Hermitian (or real
+    /// symmetric) matrix `A`, where `b` is the argument.
+    fn solveh_expert<S: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, Ix1>,
+    ) -> Result<SolveExpertResult<A>>;
+}
+
+impl<A, S> SolveHExpert<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn solveh_expert<Sb>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<SolveExpertResult<A>>
+    where
+        Sb: Data<Elem = A>,
+    {
+        assert_eq!(
+            b.len(),
+            self.len_of(Axis(1)),
+            "The length of `b` must be compatible with the shape of the matrix.",
+        );
+        let a: Array2<A> = replicate(self);
+        let mut x: Array1<A> = replicate(b);
+        let result = A::solveh_expert(
+            a.square_layout()?,
+            UPLO::Upper,
+            a.as_allocated()?,
+            None,
+            x.as_slice_mut().unwrap(),
+        )?;
+        Ok(SolveExpertResult::new(x, result))
+    }
+}
+
+impl<A, S, Sf> SolveHExpert<A> for (&ArrayBase<S, Ix2>, &BKFactorized<Sf>)
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    Sf: Data<Elem = A>,
+{
+    fn solveh_expert<Sb>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<SolveExpertResult<A>>
+    where
+        Sb: Data<Elem = A>,
+    {
+        let (a, f) = *self;
+        assert_eq!(
+            a.shape(),
+            f.a.shape(),
+            "The shapes of the matrix and its factorization must be identical.",
+        );
+        assert_eq!(
+            b.len(),
+            a.len_of(Axis(1)),
+            "The length of `b` must be compatible with the shape of the matrix.",
+        );
+        // The original matrix must be in the same layout as the factorization
+        let l = f.a.square_layout()?;
+        let a: Array2<A> = clone_with_layout(l, a);
+        let mut x: Array1<A> = replicate(b);
+        let bk = match f.strategy {
+            PivotStrategy::BunchKaufman => Some((f.a.as_allocated()?, &f.ipiv)),
+            PivotStrategy::Rook | PivotStrategy::Aasen => None,
+        };
+        let result = A::solveh_expert(
+            l,
+            UPLO::Upper,
+            a.as_allocated()?,
+            bk,
+            x.as_slice_mut().unwrap(),
+        )?;
+        Ok(SolveExpertResult::new(x, result))
+    }
+}
+
 /// An interface for computing the Bunch–Kaufman factorization of Hermitian (or
 /// real symmetric) matrix refs.
 pub trait FactorizeH<S: Data> {
     /// Computes the Bunch–Kaufman factorization of a Hermitian (or real
     /// symmetric) matrix.
-    fn factorizeh(&self) -> Result<BKFactorized<S>>;
+    fn factorizeh(&self) -> Result<BKFactorized<S>> {
+        self.factorizeh_with(PivotStrategy::BunchKaufman)
+    }
+
+    /// Computes the factorization of a Hermitian (or real symmetric) matrix
+    /// with the given pivoting strategy.
+    fn factorizeh_with(&self, strategy: PivotStrategy) -> Result<BKFactorized<S>>;
 }
 
 /// An interface for computing the Bunch–Kaufman factorization of Hermitian (or
@@ -167,7 +301,16 @@ pub trait FactorizeH<S: Data> {
 pub trait FactorizeHInto<S: Data> {
     /// Computes the Bunch–Kaufman factorization of a Hermitian (or real
     /// symmetric) matrix.
-    fn factorizeh_into(self) -> Result<BKFactorized<S>>;
+    fn factorizeh_into(self) -> Result<BKFactorized<S>>
+    where
+        Self: Sized,
+    {
+        self.factorizeh_into_with(PivotStrategy::BunchKaufman)
+    }
+
+    /// Computes the factorization of a Hermitian (or real symmetric) matrix
+    /// with the given pivoting strategy.
+    fn factorizeh_into_with(self, strategy: PivotStrategy) -> Result<BKFactorized<S>>;
 }
 
 impl<A, S> FactorizeHInto<S> for ArrayBase<S, Ix2>
@@ -175,9 +318,20 @@ where
     A: Scalar + Lapack,
     S: DataMut<Elem = A>,
 {
-    fn factorizeh_into(mut self) -> Result<BKFactorized<S>> {
-        let ipiv = A::bk(self.square_layout()?, UPLO::Upper, self.as_allocated_mut()?)?;
-        Ok(BKFactorized { a: self, ipiv })
+    fn factorizeh_into_with(mut self, strategy: PivotStrategy) -> Result<BKFactorized<S>> {
+        let ipiv = A::ldl(
+            self.square_layout()?,
+            UPLO::Upper,
+            strategy,
+            self.as_allocated_mut()?,
+        )?;
+        let f = BKFactorized {
+            a: self,
+            ipiv,
+            strategy,
+        };
+        f.check_nonsingular()?;
+        Ok(f)
     }
 }
 
@@ -186,10 +340,17 @@ where
     A: Scalar + Lapack,
     Si: Data<Elem = A>,
 {
-    fn factorizeh(&self) -> Result<BKFactorized<OwnedRepr<A>>> {
+    fn factorizeh_with(&self, strategy: PivotStrategy) -> Result<BKFactorized<OwnedRepr<A>>> {
         let mut a: Array2<A> = replicate(self);
-        let ipiv = A::bk(a.square_layout()?, UPLO::Upper, a.as_allocated_mut()?)?;
-        Ok(BKFactorized { a, ipiv })
+        let ipiv = A::ldl(
+            a.square_layout()?,
+            UPLO::Upper,
+            strategy,
+            a.as_allocated_mut()?,
+        )?;
+        let f = BKFactorized { a, ipiv, strategy };
+        f.check_nonsingular()?;
+        Ok(f)
     }
 }
 
@@ -215,13 +376,29 @@ where
     type Output = ArrayBase<S, Ix2>;
 
     fn invh_into(mut self) -> Result<ArrayBase<S, Ix2>> {
-        A::invh(
-            self.a.square_layout()?,
-            UPLO::Upper,
-            self.a.as_allocated_mut()?,
-            &self.ipiv,
-        )?;
-        triangular_fill_hermitian(&mut self.a, UPLO::Upper);
+        match self.strategy {
+            PivotStrategy::BunchKaufman => {
+                A::invh(
+                    self.a.square_layout()?,
+                    UPLO::Upper,
+                    self.a.as_allocated_mut()?,
+                    &self.ipiv,
+                )?;
+                triangular_fill_hermitian(&mut self.a, UPLO::Upper);
+            }
+            PivotStrategy::Rook | PivotStrategy::Aasen => {
+                // Solve `A X = I` column by column since `?sytri` supports only Bunch-Kaufman
+                let n = self.a.nrows();
+                let mut inv = Array2::zeros((n, n));
+                for (j, mut col) in inv.axis_iter_mut(Axis(1)).enumerate() {
+                    let mut e = Array1::zeros(n);
+                    e[j] = A::one();
+                    self.solveh_inplace(&mut e)?;
+                    col.assign(&e);
+                }
+                self.a.assign(&inv);
+            }
+        }
         Ok(self.a)
     }
 }
@@ -237,11 +414,52 @@ where
         let f = BKFactorized {
             a: replicate(&self.a),
             ipiv: self.ipiv.clone(),
+            strategy: self.strategy,
         };
         f.invh_into()
     }
 }
 
+impl<A, S, Sf> ReciprocalConditionNum<A> for (&ArrayBase<S, Ix2>, &BKFactorized<Sf>)
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    Sf: Data<Elem = A>,
+{
+    fn rcond(&self) -> Result<A::Real> {
+        let (a, f) = *self;
+        assert_eq!(
+            a.shape(),
+            f.a.shape(),
+            "The shapes of the matrix and its factorization must be identical.",
+        );
+        // The 1-norm of the original matrix is not kept in the factorization,
+        // and is computed from the triangular part referenced by the factorization
+        let l = f.a.square_layout()?;
+        let a: Array2<A> = clone_with_layout(l, a);
+        let anorm = ldl_opnorm_one(&a)?;
+        match f.strategy {
+            PivotStrategy::BunchKaufman => Ok(A::rcond_hermitian(
+                l,
+                UPLO::Upper,
+                f.a.as_allocated()?,
+                &f.ipiv,
+                anorm,
+            )?),
+            // `?sycon_rook`/`?hecon_rook` are not bound by lapack-sys, and LAPACK has
+            // no estimator for the Aasen factorization. The O(n^3) explicit inverse
+            // is used instead of an estimate.
+            PivotStrategy::Rook | PivotStrategy::Aasen => {
+                if anorm.is_zero() {
+                    return Ok(A::Real::zero());
+                }
+                let ainv_opnorm_one = f.invh()?.opnorm_one()?;
+                Ok(A::Real::one() / (anorm * ainv_opnorm_one))
+            }
+        }
+    }
+}
+
 impl<A, S> InverseHInto for ArrayBase<S, Ix2>
 where
     A: Scalar + Lapack,
@@ -375,6 +593,11 @@ where
     A: Scalar + Lapack,
     S: Data<Elem = A>,
 {
+    /// Pivoting strategy used for the factorization
+    pub fn strategy(&self) -> PivotStrategy {
+        self.strategy
+    }
+
     /// Computes the determinant of the factorized Hermitian (or real
     /// symmetric) matrix.
     pub fn deth(&self) -> A::Real {
@@ -396,7 +619,12 @@ where
     /// determinants since it returns the natural logarithm of the determinant
     /// rather than the determinant itself.
     pub fn sln_deth(&self) -> (A::Real, A::Real) {
-        bk_sln_det(UPLO::Upper, self.ipiv.iter().cloned(), &self.a)
+        match self.strategy {
+            PivotStrategy::BunchKaufman | PivotStrategy::Rook => {
+                bk_sln_det(UPLO::Upper, self.ipiv.iter().cloned(), &self.a)
+            }
+            PivotStrategy::Aasen => tridiagonal_sln_det(&self.stored_factor()),
+        }
     }
 
     /// Computes the determinant of the factorized Hermitian (or real
@@ -420,7 +648,209 @@ where
     /// large determinants since it returns the natural logarithm of the
     /// determinant rather than the determinant itself.
     pub fn sln_deth_into(self) -> (A::Real, A::Real) {
-        bk_sln_det(UPLO::Upper, self.ipiv.into_iter(), &self.a)
+        match self.strategy {
+            PivotStrategy::BunchKaufman | PivotStrategy::Rook => {
+                bk_sln_det(UPLO::Upper, self.ipiv.into_iter(), &self.a)
+            }
+            PivotStrategy::Aasen => tridiagonal_sln_det(&self.stored_factor()),
+        }
+    }
+
+    /// Returns the unit lower triangular matrix `L` of the factorization
+    /// `A = P * L * D * L^H * P^T`.
+    pub fn l(&self) -> Array2<A> {
+        self.unpack().0
+    }
+
+    /// Returns the matrix `D` of the factorization `A = P * L * D * L^H * P^T`.
+    ///
+    /// This is block diagonal with 1x1 and 2x2 blocks for [PivotStrategy::BunchKaufman]
+    /// and [PivotStrategy::Rook], and tridiagonal for [PivotStrategy::Aasen].
+    /// The inertia of `A` is equal to that of `D`.
+    pub fn d(&self) -> Array2<A> {
+        self.unpack().1
+    }
+
+    /// Returns the permutation matrix `P` of the factorization `A = P * L * D * L^H * P^T`.
+    pub fn p(&self) -> Array2<A> {
+        let perm = self.unpack().2;
+        let n = perm.len();
+        let mut p = Array2::zeros((n, n));
+        for (j, &i) in perm.iter().enumerate() {
+            p[(i, j)] = A::one();
+        }
+        p
+    }
+
+    /// Returns an error if `D` has an exactly zero 1x1 block as `?sytrf` reports,
+    /// or the tridiagonal `T` of [PivotStrategy::Aasen] is exactly singular,
+    /// since [lax::Lapack::ldl] does not fail in these cases.
+    /// `return_code` is the order of `T` in the latter case.
+    fn check_nonsingular(&self) -> Result<()> {
+        match self.strategy {
+            PivotStrategy::BunchKaufman | PivotStrategy::Rook => {
+                for k in (0..self.ipiv.len()).rev() {
+                    if self.ipiv[k] > 0 && self.a[(k, k)].is_zero() {
+                        return Err(LinalgError::Lapack(
+                            lax::error::Error::LapackComputationalFailure {
+                                return_code: k as i32 + 1,
+                            },
+                        ));
+                    }
+                }
+                Ok(())
+            }
+            PivotStrategy::Aasen => {
+                let (sign, _) = tridiagonal_sln_det(&self.stored_factor());
+                if sign.is_zero() {
+                    return Err(LinalgError::Lapack(
+                        lax::error::Error::LapackComputationalFailure {
+                            return_code: self.ipiv.len() as i32,
+                        },
+                    ));
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// The factorization of `A` as stored by LAPACK in column-major order.
+    ///
+    /// Row-major Hermitian matrix is regarded as its conjugate in column-major,
+    /// and thus the factors of `A` are obtained by conjugating the transposed storage.
+    fn stored_factor(&self) -> Array2<A> {
+        match self.a.layout().unwrap() {
+            MatrixLayout::C { .. } => self.a.t().mapv(|elem| elem.conj()),
+            MatrixLayout::F { .. } => self.a.to_owned(),
+        }
+    }
+
+    /// Unpacks `(L, D, perm)` of `A = P * L * D * L^H * P^T`,
+    /// where `perm[j]` is the row index of the non-zero element in the `j`-th column of `P`.
+    fn unpack(&self) -> (Array2<A>, Array2<A>, Vec<usize>) {
+        let f = self.stored_factor();
+        let n = f.nrows();
+        let mut u = Array2::<A>::eye(n);
+        let mut d = Array2::<A>::zeros((n, n));
+        // `swaps[k]` is the row interchanged with the `k`-th row
+        let mut swaps: Vec<usize> = (0..n).collect();
+        match self.strategy {
+            PivotStrategy::BunchKaufman | PivotStrategy::Rook => {
+                // `A = P * U * D * U^H * P^T` where `P` is the product of the interchanges
+                // in the factorized order, i.e. `k` decreasing from `n - 1` to `0`,
+                // and `U` is a unit upper triangular matrix.
+                let mut blocks = Vec::new();
+                let mut k = n;
+                while k > 0 {
+                    let end = k - 1;
+                    let start = if self.ipiv[end] > 0 {
+                        swaps[end] = self.ipiv[end] as usize - 1;
+                        d[(end, end)] = f[(end, end)];
+                        end
+                    } else {
+                        let start = end - 1;
+                        match self.strategy {
+                            // Only the `k-1`-th row is interchanged for a 2x2 block
+                            PivotStrategy::BunchKaufman => {
+                                swaps[start] = (-self.ipiv[end]) as usize - 1;
+                            }
+                            _ => {
+                                swaps[end] = (-self.ipiv[end]) as usize - 1;
+                                swaps[start] = (-self.ipiv[start]) as usize - 1;
+                            }
+                        }
+                        d[(start, start)] = f[(start, start)];
+                        d[(end, end)] = f[(end, end)];
+                        d[(start, end)] = f[(start, end)];
+                        d[(end, start)] = f[(start, end)].conj();
+                        start
+                    };
+                    for j in start..=end {
+                        for i in 0..start {
+                            u[(i, j)] = f[(i, j)];
+                        }
+                    }
+                    blocks.push((start, end));
+                    k = start;
+                }
+                // Move the interchanges to the left of `U` as `?syconvf_rook` in LAPACK
+                for &(start, end) in &blocks {
+                    for idx in (start..=end).rev() {
+                        let p = swaps[idx];
+                        if p != idx {
+                            for j in end + 1..n {
+                                u.swap((idx, j), (p, j));
+                            }
+                        }
+                    }
+                }
+                // Reverse the order of rows and columns to obtain a lower triangular factor
+                let perm = permuted_indices(&swaps, (0..n).rev())
+                    .into_iter()
+                    .rev()
+                    .collect();
+                let l = u.slice(s![..;-1, ..;-1]).to_owned();
+                let d = d.slice(s![..;-1, ..;-1]).to_owned();
+                (l, d, perm)
+            }
+            PivotStrategy::Aasen => {
+                // `A = P * U^H * T * U * P^T` where `P` is the product of the interchanges
+                // in the factorized order, i.e. `k` increasing from `0` to `n - 1`,
+                // and `U` is a unit upper triangular matrix whose first row is `e_0`.
+                for k in 0..n {
+                    swaps[k] = self.ipiv[k] as usize - 1;
+                    d[(k, k)] = f[(k, k)];
+                    if k + 1 < n {
+                        d[(k, k + 1)] = f[(k, k + 1)];
+                        d[(k + 1, k)] = f[(k, k + 1)].conj();
+                    }
+                    for i in 1..k {
+                        u[(i, k)] = f[(i - 1, k)];
+                    }
+                }
+                let perm = permuted_indices(&swaps, 0..n);
+                (u.t().mapv(|elem| elem.conj()), d, perm)
+            }
+        }
+    }
+}
+
+/// Returns `perm` satisfying `(P^T b)[j] = b[perm[j]]`
+/// where `P^T` interchanges `k`-th and `swaps[k]`-th rows in the given order of `k`.
+fn permuted_indices(swaps: &[usize], order: impl Iterator<Item = usize>) -> Vec<usize> {
+    let mut perm: Vec<usize> = (0..swaps.len()).collect();
+    for k in order {
+        perm.swap(k, swaps[k]);
+    }
+    perm
+}
+
+/// Returns the sign and natural log of the determinant of Hermitian tridiagonal matrix `T`
+/// using the recurrence `f_k = T_kk f_{k-1} - |T_{k-1,k}|^2 f_{k-2}` with scaling.
+fn tridiagonal_sln_det<A: Scalar>(t: &Array2<A>) -> (A::Real, A::Real) {
+    let n = t.nrows();
+    let mut f_prev = A::Real::one();
+    let mut f = A::Real::one();
+    let mut ln_scale = A::Real::zero();
+    for k in 0..n {
+        let next = if k == 0 {
+            t[(0, 0)].re()
+        } else {
+            t[(k, k)].re() * f - t[(k - 1, k)].square() * f_prev
+        };
+        f_prev = f;
+        f = next;
+        let scale = Float::max(Float::abs(f_prev), Float::abs(f));
+        if scale > A::Real::zero() {
+            f_prev /= scale;
+            f /= scale;
+            ln_scale += Float::ln(scale);
+        }
+    }
+    if f == A::Real::zero() {
+        (A::Real::zero(), A::Real::neg_infinity())
+    } else {
+        (Float::signum(f), Float::ln(Float::abs(f)) + ln_scale)
     }
 }
 
@@ -475,3 +905,143 @@ where
         }
     }
 }
+
+/// An interface for computing the inertia of Hermitian (or real symmetric) matrices.
+pub trait Inertia {
+    /// Computes the inertia `(n_pos, n_neg, n_zero)` of the matrix, i.e. the numbers of
+    /// positive, negative, and zero eigenvalues.
+    ///
+    /// This is obtained from the factorization `A = P * L * D * L^H * P^T`
+    /// by Sylvester's law of inertia without computing the eigenvalues.
+    /// Zero eigenvalues are counted only if the corresponding block of `D` is exactly singular.
+    fn inertia(&self) -> Result<(usize, usize, usize)>;
+}
+
+impl<A, S> Inertia for BKFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn inertia(&self) -> Result<(usize, usize, usize)> {
+        match self.strategy {
+            PivotStrategy::BunchKaufman | PivotStrategy::Rook => {
+                Ok(bk_inertia(self.ipiv.iter().cloned(), &self.stored_factor()))
+            }
+            // The inertia of the tridiagonal `T` is computed by factorizing it again.
+            PivotStrategy::Aasen => factorizeh_singular(&self.d())?.inertia(),
+        }
+    }
+}
+
+impl<A, S> Inertia for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn inertia(&self) -> Result<(usize, usize, usize)> {
+        factorizeh_singular(self)?.inertia()
+    }
+}
+
+/// An interface for counting eigenvalues of Hermitian (or real symmetric) matrices.
+pub trait CountEigenvalues<A: Scalar> {
+    /// Counts the eigenvalues less than `sigma`
+    /// from the inertia of `A - sigma * I` without computing the eigenvalues.
+    fn count_eigenvalues_below(&self, sigma: A::Real) -> Result<usize>;
+}
+
+impl<A, S> CountEigenvalues<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn count_eigenvalues_below(&self, sigma: A::Real) -> Result<usize> {
+        let mut a: Array2<A> = replicate(self);
+        a.square_layout()?;
+        for d in a.diag_mut() {
+            *d -= A::from_real(sigma);
+        }
+        let (_, n_neg, _) = factorizeh_singular(&a)?.inertia()?;
+        Ok(n_neg)
+    }
+}
+
+/// Computes the Bunch–Kaufman factorization even if `D` is exactly singular.
+fn factorizeh_singular<A, S>(a: &ArrayBase<S, Ix2>) -> Result<BKFactorized<OwnedRepr<A>>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let mut a: Array2<A> = replicate(a);
+    let strategy = PivotStrategy::BunchKaufman;
+    let ipiv = A::ldl(
+        a.square_layout()?,
+        UPLO::Upper,
+        strategy,
+        a.as_allocated_mut()?,
+    )?;
+    Ok(BKFactorized { a, ipiv, strategy })
+}
+
+/// Computes the 1-norm of `a` using the triangular part referenced by the factorization,
+/// i.e. the upper one of the column-major view.
+fn ldl_opnorm_one<A, S>(a: &ArrayBase<S, Ix2>) -> Result<A::Real>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let l = a.square_layout()?;
+    let uplo = match l {
+        MatrixLayout::C { .. } => UPLO::Lower,
+        MatrixLayout::F { .. } => UPLO::Upper,
+    };
+    Ok(A::opnorm_hermitian(
+        NormType::One,
+        l,
+        uplo,
+        a.as_allocated()?,
+    ))
+}
+
+/// Returns the inertia of the block diagonal `D` stored in the column-major factor `f`.
+fn bk_inertia<P, A>(ipiv_iter: P, f: &Array2<A>) -> (usize, usize, usize)
+where
+    P: Iterator<Item = i32>,
+    A: Scalar,
+{
+    let mut inertia = (0, 0, 0);
+    let mut count = |x: A::Real| {
+        if x > A::Real::zero() {
+            inertia.0 += 1;
+        } else if x < A::Real::zero() {
+            inertia.1 += 1;
+        } else {
+            inertia.2 += 1;
+        }
+    };
+    let mut ipiv_enum = ipiv_iter.enumerate();
+    while let Some((k, ipiv_k)) = ipiv_enum.next() {
+        if ipiv_k > 0 {
+            // 1x1 block at k
+            count(f[(k, k)].re());
+        } else {
+            // 2x2 block at k..k+2, whose eigenvalues have the product `det`
+            // and the sum `trace`
+            let (a, c) = (f[(k, k)].re(), f[(k + 1, k + 1)].re());
+            let det = a * c - f[(k, k + 1)].square();
+            let trace = a + c;
+            if det < A::Real::zero() {
+                count(A::Real::one());
+                count(-A::Real::one());
+            } else if det > A::Real::zero() {
+                count(trace);
+                count(trace);
+            } else {
+                count(A::Real::zero());
+                count(trace);
+            }
+            ipiv_enum.next();
+        }
+    }
+    inertia
+}
@@ -1,6 +1,9 @@
 //! Singular-value decomposition (SVD)
 //!
 //! [Wikipedia article on SVD](https://en.wikipedia.org/wiki/Singular_value_decomposition)
+//!
+//! Generalized SVD (GSVD) of a pair of matrices is also provided by [GSVD] trait.
+//! [Wikipedia article on GSVD](https://en.wikipedia.org/wiki/Generalized_singular_value_decomposition)
 
 use crate::{convert::*, error::*, layout::*, types::*};
 use ndarray::*;
@@ -104,3 +107,91 @@ where
         Ok((u, s, vt))
     }
 }
+
+/// Result of generalized singular value decomposition
+///
+/// For a pair of matrices `A` of shape `(m, n)` and `B` of shape `(p, n)`,
+///
+/// ```text
+/// A = U D1 [0 R] Q^H
+/// B = V D2 [0 R] Q^H
+/// ```
+///
+/// where `D1` of shape `(m, k + l)` has `alpha[i]` at `(i, i)` for `i < min(m, k + l)`,
+/// and `D2` of shape `(p, k + l)` has `beta[k + i]` at `(i, k + i)` for `i < l`.
+/// The first `k` pairs are `(1, 0)`, i.e. the infinite generalized singular values,
+/// and `alpha[i] / beta[i]` for `k <= i < k + l` are the finite ones.
+#[derive(Debug, Clone)]
+pub struct GSVDResult<A: Scalar> {
+    /// Unitary matrix `U` of shape `(m, m)`
+    pub u: Option<Array2<A>>,
+    /// Unitary matrix `V` of shape `(p, p)`
+    pub v: Option<Array2<A>>,
+    /// Unitary matrix `Q` of shape `(n, n)`
+    pub q: Option<Array2<A>>,
+    /// Numerators of the generalized singular value pairs
+    pub alpha: Array1<A::Real>,
+    /// Denominators of the generalized singular value pairs
+    pub beta: Array1<A::Real>,
+    /// Number of the infinite generalized singular values
+    pub k: usize,
+    /// Rank of `B`
+    pub l: usize,
+    /// Upper triangular matrix `R` of shape `(k + l, k + l)`
+    pub r: Array2<A>,
+}
+
+/// generalized singular-value decomposition of a pair of matrices
+pub trait GSVD<A: Scalar> {
+    /// Panics if the numbers of columns of the matrices are different.
+    fn gsvd(&self, calc_u: bool, calc_v: bool, calc_q: bool) -> Result<GSVDResult<A>>;
+}
+
+impl<A, S, S2> GSVD<A> for (ArrayBase<S, Ix2>, ArrayBase<S2, Ix2>)
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    fn gsvd(&self, calc_u: bool, calc_v: bool, calc_q: bool) -> Result<GSVDResult<A>> {
+        assert_eq!(
+            self.0.ncols(),
+            self.1.ncols(),
+            "The numbers of columns of the matrices must be identical.",
+        );
+        let mut a = self.0.to_owned();
+        let mut b = self.1.to_owned();
+        let (m, n) = a.dim();
+        let p = b.nrows();
+        let a_layout = a.layout()?;
+        let b_layout = b.layout()?;
+        let lax::GsvdOwned {
+            k,
+            l,
+            alpha,
+            beta,
+            u,
+            v,
+            q,
+            r,
+        } = A::ggsvd3(
+            calc_u,
+            calc_v,
+            calc_q,
+            a_layout,
+            a.as_allocated_mut()?,
+            b_layout,
+            b.as_allocated_mut()?,
+        )?;
+        Ok(GSVDResult {
+            u: u.map(|u| Array2::from_shape_vec((m, m).f(), u).unwrap()),
+            v: v.map(|v| Array2::from_shape_vec((p, p).f(), v).unwrap()),
+            q: q.map(|q| Array2::from_shape_vec((n, n).f(), q).unwrap()),
+            alpha: ArrayBase::from(alpha),
+            beta: ArrayBase::from(beta),
+            k,
+            l,
+            r: Array2::from_shape_vec((k + l, k + l).f(), r).unwrap(),
+        })
+    }
+}
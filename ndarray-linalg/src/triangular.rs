@@ -9,7 +9,7 @@ use super::error::*;
 use super::layout::*;
 use super::types::*;
 
-pub use lax::Diag;
+pub use lax::{Diag, Side};
 
 /// solve a triangular system with upper triangular matrix
 pub trait SolveTriangular<A, S, D>
@@ -142,6 +142,235 @@ where
     }
 }
 
+/// Inverse of triangular matrix reference
+pub trait InverseTriangular {
+    type Output;
+    /// Computes the inverse of the triangular matrix.
+    ///
+    /// Only the triangular part specified by `uplo` is referenced and inverted,
+    /// and the other part is copied as it is.
+    /// The diagonal elements are not referenced if `diag` is `Diag::Unit`.
+    fn inv_triangular(&self, uplo: UPLO, diag: Diag) -> Result<Self::Output>;
+}
+
+/// Inverse of triangular matrix
+pub trait InverseTriangularInto {
+    type Output;
+    /// Computes the inverse of the triangular matrix in place.
+    ///
+    /// Only the triangular part specified by `uplo` is referenced and overwritten,
+    /// and the other part is left unchanged.
+    /// The diagonal elements are not referenced if `diag` is `Diag::Unit`.
+    fn inv_triangular_into(self, uplo: UPLO, diag: Diag) -> Result<Self::Output>;
+}
+
+impl<A, S> InverseTriangularInto for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type Output = Self;
+
+    fn inv_triangular_into(mut self, uplo: UPLO, diag: Diag) -> Result<Self> {
+        A::inv_triangular(self.square_layout()?, uplo, diag, self.as_allocated_mut()?)?;
+        Ok(self)
+    }
+}
+
+impl<A, S> InverseTriangular for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn inv_triangular(&self, uplo: UPLO, diag: Diag) -> Result<Array2<A>> {
+        let a = replicate(self);
+        a.inv_triangular_into(uplo, diag)
+    }
+}
+
+/// *Estimates* the reciprocal condition number of triangular matrix
+pub trait ReciprocalConditionNumTriangular<A: Scalar> {
+    /// *Estimates* the reciprocal of the condition number of the triangular matrix in
+    /// 1-norm using the LAPACK `*trcon` routines.
+    ///
+    /// Only the triangular part specified by `uplo` is referenced.
+    ///
+    /// * If `rcond` is near `0.`, the matrix is badly conditioned.
+    /// * If `rcond` is near `1.`, the matrix is well conditioned.
+    fn rcond_triangular(&self, uplo: UPLO, diag: Diag) -> Result<A::Real>;
+}
+
+impl<A, S> ReciprocalConditionNumTriangular<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn rcond_triangular(&self, uplo: UPLO, diag: Diag) -> Result<A::Real> {
+        Ok(A::rcond_triangular(
+            self.square_layout()?,
+            uplo,
+            diag,
+            self.as_allocated()?,
+        )?)
+    }
+}
+
+/// multiply a triangular matrix to another matrix
+pub trait MultiplyTriangular<A, S, D>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Computes `A * b` for `Side::Left` or `b * A` for `Side::Right`,
+    /// where `A` is the triangular matrix `self`.
+    ///
+    /// Only the triangular part of `A` specified by `uplo` is referenced.
+    fn multiply_triangular(
+        &self,
+        side: Side,
+        uplo: UPLO,
+        diag: Diag,
+        b: &ArrayBase<S, D>,
+    ) -> Result<Array<A, D>>;
+}
+
+/// multiply a triangular matrix to another matrix
+pub trait MultiplyTriangularInto<S, D>
+where
+    S: DataMut,
+    D: Dimension,
+{
+    fn multiply_triangular_into(
+        &self,
+        side: Side,
+        uplo: UPLO,
+        diag: Diag,
+        b: ArrayBase<S, D>,
+    ) -> Result<ArrayBase<S, D>>;
+}
+
+/// multiply a triangular matrix to another matrix
+pub trait MultiplyTriangularInplace<S, D>
+where
+    S: DataMut,
+    D: Dimension,
+{
+    fn multiply_triangular_inplace<'a>(
+        &self,
+        side: Side,
+        uplo: UPLO,
+        diag: Diag,
+        b: &'a mut ArrayBase<S, D>,
+    ) -> Result<&'a mut ArrayBase<S, D>>;
+}
+
+impl<A, Si, So> MultiplyTriangularInplace<So, Ix2> for ArrayBase<Si, Ix2>
+where
+    A: Scalar + Lapack,
+    Si: Data<Elem = A>,
+    So: DataMut<Elem = A>,
+{
+    fn multiply_triangular_inplace<'a>(
+        &self,
+        side: Side,
+        uplo: UPLO,
+        diag: Diag,
+        b: &'a mut ArrayBase<So, Ix2>,
+    ) -> Result<&'a mut ArrayBase<So, Ix2>> {
+        let la = self.square_layout()?;
+        let lb = b.layout()?;
+        A::multiply_triangular(
+            la,
+            lb,
+            side,
+            uplo,
+            diag,
+            self.as_allocated()?,
+            b.as_allocated_mut()?,
+        )?;
+        Ok(b)
+    }
+}
+
+impl<A, Si, So> MultiplyTriangularInto<So, Ix2> for ArrayBase<Si, Ix2>
+where
+    A: Scalar + Lapack,
+    Si: Data<Elem = A>,
+    So: DataMut<Elem = A>,
+{
+    fn multiply_triangular_into(
+        &self,
+        side: Side,
+        uplo: UPLO,
+        diag: Diag,
+        mut b: ArrayBase<So, Ix2>,
+    ) -> Result<ArrayBase<So, Ix2>> {
+        self.multiply_triangular_inplace(side, uplo, diag, &mut b)?;
+        Ok(b)
+    }
+}
+
+impl<A, Si, So> MultiplyTriangular<A, So, Ix2> for ArrayBase<Si, Ix2>
+where
+    A: Scalar + Lapack,
+    Si: Data<Elem = A>,
+    So: Data<Elem = A>,
+{
+    fn multiply_triangular(
+        &self,
+        side: Side,
+        uplo: UPLO,
+        diag: Diag,
+        b: &ArrayBase<So, Ix2>,
+    ) -> Result<Array2<A>> {
+        let b = replicate(b);
+        self.multiply_triangular_into(side, uplo, diag, b)
+    }
+}
+
+impl<A, Si, So> MultiplyTriangularInto<So, Ix1> for ArrayBase<Si, Ix2>
+where
+    A: Scalar + Lapack,
+    Si: Data<Elem = A>,
+    So: DataMut<Elem = A>,
+{
+    fn multiply_triangular_into(
+        &self,
+        side: Side,
+        uplo: UPLO,
+        diag: Diag,
+        b: ArrayBase<So, Ix1>,
+    ) -> Result<ArrayBase<So, Ix1>> {
+        let b = match side {
+            Side::Left => into_col(b),
+            Side::Right => into_row(b),
+        };
+        let b = self.multiply_triangular_into(side, uplo, diag, b)?;
+        Ok(flatten(b))
+    }
+}
+
+impl<A, Si, So> MultiplyTriangular<A, So, Ix1> for ArrayBase<Si, Ix2>
+where
+    A: Scalar + Lapack,
+    Si: Data<Elem = A>,
+    So: Data<Elem = A>,
+{
+    fn multiply_triangular(
+        &self,
+        side: Side,
+        uplo: UPLO,
+        diag: Diag,
+        b: &ArrayBase<So, Ix1>,
+    ) -> Result<Array1<A>> {
+        let b = b.to_owned();
+        self.multiply_triangular_into(side, uplo, diag, b)
+    }
+}
+
 pub trait IntoTriangular<T> {
     fn into_triangular(self, uplo: UPLO) -> T;
 }
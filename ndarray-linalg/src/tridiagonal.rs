@@ -1,6 +1,6 @@
 //! Vectors as a Tridiagonal matrix
 //! &
-//! Methods for tridiagonal matrices
+//! Methods for tridiagonal matrices, including eigenvalue problem for symmetric/Hermitian ones
 
 use super::convert::*;
 use super::error::*;
@@ -693,3 +693,153 @@ where
         self.factorize_tridiagonal()?.rcond_tridiagonal_into()
     }
 }
+
+/// An interface for eigenvalue decomposition of symmetric/Hermitian tridiagonal matrices.
+///
+/// Only the diagonal `d` and the sub-diagonal `dl` are referenced,
+/// i.e. the super-diagonal `du` is assumed to be the complex conjugate of `dl`.
+pub trait EighTridiagonal<A: Scalar> {
+    /// Computes all eigenvalues in ascending order and the corresponding eigenvectors
+    /// using the implicit QL/QR method (`?stev`).
+    fn eigh_tridiagonal(&self) -> Result<(Array1<A::Real>, Array2<A>)>;
+
+    /// Computes the eigenvalues specified by `range` and the corresponding eigenvectors
+    /// using the Relatively Robust Representations algorithm (`?stemr`).
+    ///
+    /// Eigenvalues are returned in ascending order,
+    /// and the eigenvectors are stored as columns of `(n, m)` matrix
+    /// where `m` is the number of found eigenvalues.
+    ///
+    /// ```
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// // 1D discrete Laplacian
+    /// let n = 10;
+    /// let a = Tridiagonal {
+    ///     l: MatrixLayout::C { row: n, lda: n },
+    ///     d: vec![2.0; n as usize],
+    ///     dl: vec![-1.0; n as usize - 1],
+    ///     du: vec![-1.0; n as usize - 1],
+    /// };
+    /// // Two lowest eigenpairs
+    /// let (e, v) = a.eigh_tridiagonal_range(EigRange::Index(0, 1)).unwrap();
+    /// assert_eq!(v.dim(), (10, 2));
+    /// let k = std::f64::consts::PI / 11.0;
+    /// assert_close_l2!(&e, &array![2.0 - 2.0 * k.cos(), 2.0 - 2.0 * (2.0 * k).cos()], 1e-9);
+    /// ```
+    fn eigh_tridiagonal_range(
+        &self,
+        range: EigRange<A::Real>,
+    ) -> Result<(Array1<A::Real>, Array2<A>)>;
+
+    /// Computes the eigenvalues specified by `range` using bisection (`?stebz`)
+    /// and the corresponding eigenvectors using inverse iteration (`?stein`).
+    ///
+    /// This is efficient when only a few eigenvectors are required.
+    /// The result is returned in the same manner as [EighTridiagonal::eigh_tridiagonal_range].
+    fn eigh_tridiagonal_inverse_iteration(
+        &self,
+        range: EigRange<A::Real>,
+    ) -> Result<(Array1<A::Real>, Array2<A>)>;
+}
+
+impl<A> EighTridiagonal<A> for Tridiagonal<A>
+where
+    A: Scalar + Lapack,
+{
+    fn eigh_tridiagonal(&self) -> Result<(Array1<A::Real>, Array2<A>)> {
+        self.eigh_tridiagonal_range(EigRange::All)
+    }
+
+    fn eigh_tridiagonal_range(
+        &self,
+        range: EigRange<A::Real>,
+    ) -> Result<(Array1<A::Real>, Array2<A>)> {
+        let lax::EighRangeOwned { eigs, z } = A::eigh_tridiagonal(true, range, self)?;
+        let n = self.d.len();
+        let m = eigs.len();
+        Ok((
+            ArrayBase::from(eigs),
+            Array2::from_shape_vec((n, m).f(), z.unwrap()).unwrap(),
+        ))
+    }
+
+    fn eigh_tridiagonal_inverse_iteration(
+        &self,
+        range: EigRange<A::Real>,
+    ) -> Result<(Array1<A::Real>, Array2<A>)> {
+        let lax::EighRangeOwned { eigs, z } = A::eigh_tridiagonal_inverse_iteration(range, self)?;
+        let n = self.d.len();
+        let m = eigs.len();
+        Ok((
+            ArrayBase::from(eigs),
+            Array2::from_shape_vec((n, m).f(), z.unwrap()).unwrap(),
+        ))
+    }
+}
+
+impl<A, S> EighTridiagonal<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn eigh_tridiagonal(&self) -> Result<(Array1<A::Real>, Array2<A>)> {
+        self.extract_tridiagonal()?.eigh_tridiagonal()
+    }
+
+    fn eigh_tridiagonal_range(
+        &self,
+        range: EigRange<A::Real>,
+    ) -> Result<(Array1<A::Real>, Array2<A>)> {
+        self.extract_tridiagonal()?.eigh_tridiagonal_range(range)
+    }
+
+    fn eigh_tridiagonal_inverse_iteration(
+        &self,
+        range: EigRange<A::Real>,
+    ) -> Result<(Array1<A::Real>, Array2<A>)> {
+        self.extract_tridiagonal()?
+            .eigh_tridiagonal_inverse_iteration(range)
+    }
+}
+
+/// An interface for calculating eigenvalues of symmetric/Hermitian tridiagonal matrices
+/// without eigenvectors.
+pub trait EigValshTridiagonal<A: Scalar> {
+    /// Computes all eigenvalues in ascending order. See [EighTridiagonal::eigh_tridiagonal].
+    fn eigvalsh_tridiagonal(&self) -> Result<Array1<A::Real>>;
+
+    /// Computes the eigenvalues specified by `range` in ascending order.
+    /// See [EighTridiagonal::eigh_tridiagonal_range].
+    fn eigvalsh_tridiagonal_range(&self, range: EigRange<A::Real>) -> Result<Array1<A::Real>>;
+}
+
+impl<A> EigValshTridiagonal<A> for Tridiagonal<A>
+where
+    A: Scalar + Lapack,
+{
+    fn eigvalsh_tridiagonal(&self) -> Result<Array1<A::Real>> {
+        self.eigvalsh_tridiagonal_range(EigRange::All)
+    }
+
+    fn eigvalsh_tridiagonal_range(&self, range: EigRange<A::Real>) -> Result<Array1<A::Real>> {
+        let lax::EighRangeOwned { eigs, .. } = A::eigh_tridiagonal(false, range, self)?;
+        Ok(ArrayBase::from(eigs))
+    }
+}
+
+impl<A, S> EigValshTridiagonal<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn eigvalsh_tridiagonal(&self) -> Result<Array1<A::Real>> {
+        self.extract_tridiagonal()?.eigvalsh_tridiagonal()
+    }
+
+    fn eigvalsh_tridiagonal_range(&self, range: EigRange<A::Real>) -> Result<Array1<A::Real>> {
+        self.extract_tridiagonal()?
+            .eigvalsh_tridiagonal_range(range)
+    }
+}
@@ -0,0 +1,173 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// Drop elements out of the band
+fn band<A: Scalar>(mut a: Array2<A>, kl: usize, ku: usize) -> Array2<A> {
+    for ((i, j), x) in a.indexed_iter_mut() {
+        if i > j + kl || j > i + ku {
+            *x = A::zero();
+        }
+    }
+    a
+}
+
+#[test]
+fn extract_banded() {
+    let a: Array2<f64> = arr2(&[
+        [1.0, 2.0, 3.0, 4.0],
+        [5.0, 6.0, 7.0, 8.0],
+        [9.0, 10.0, 11.0, 12.0],
+        [13.0, 14.0, 15.0, 16.0],
+    ]);
+    let b = a.extract_banded(1, 2).unwrap();
+    assert_eq!(b.ldab(), 4);
+    #[rustfmt::skip]
+    let ab = vec![
+        0.0, 0.0, 1.0, 5.0,
+        0.0, 2.0, 6.0, 10.0,
+        3.0, 7.0, 11.0, 15.0,
+        8.0, 12.0, 16.0, 0.0,
+    ];
+    assert_eq!(b.ab, ab);
+}
+
+#[test]
+fn banded_index() {
+    let a: Array2<f64> = arr2(&[
+        [1.0, 2.0, 3.0, 4.0],
+        [5.0, 6.0, 7.0, 8.0],
+        [9.0, 10.0, 11.0, 12.0],
+        [13.0, 14.0, 15.0, 16.0],
+    ]);
+    let b1 = a.extract_banded(1, 2).unwrap();
+    let mut b2 = Array2::<f64>::zeros((4, 4)).extract_banded(1, 2).unwrap();
+    for i in 0..4 {
+        for j in 0..4 {
+            if i <= j + 1 && j <= i + 2 {
+                b2[[i, j]] = a[(i as usize, j as usize)];
+            }
+        }
+    }
+    assert_eq!(b1.ab, b2.ab);
+}
+
+#[should_panic]
+#[test]
+fn banded_index_out_of_band() {
+    let b = Array2::<f64>::eye(4).extract_banded(1, 2).unwrap();
+    let _ = b[[2, 0]];
+}
+
+#[test]
+fn opnorm_banded() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = band(random_using((6, 6), &mut rng), 1, 2);
+    let b = a.extract_banded(1, 2).unwrap();
+    assert_aclose!(a.opnorm_one().unwrap(), b.opnorm_one().unwrap(), 1e-7);
+    assert_aclose!(a.opnorm_inf().unwrap(), b.opnorm_inf().unwrap(), 1e-7);
+    assert_aclose!(a.opnorm_fro().unwrap(), b.opnorm_fro().unwrap(), 1e-7);
+}
+
+#[test]
+fn rcond_banded_identity() {
+    macro_rules! rcond_identity {
+        ($elem:ty, $rows:expr, $atol:expr) => {
+            let a = Array2::<$elem>::eye($rows);
+            let b = a.extract_banded(1, 2).unwrap();
+            assert_aclose!(b.rcond_banded().unwrap(), 1., $atol);
+        };
+    }
+    for rows in 1..6 {
+        rcond_identity!(f64, rows, 1e-9);
+        rcond_identity!(f32, rows, 1e-3);
+        rcond_identity!(c64, rows, 1e-9);
+        rcond_identity!(c32, rows, 1e-3);
+    }
+}
+
+macro_rules! impl_test {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<solve_banded_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = band(random_using((6, 6), &mut rng), 2, 1);
+                let f = a.extract_banded(2, 1).unwrap().factorize_banded_into().unwrap();
+
+                let x: Array1<$scalar> = random_using(6, &mut rng);
+                let y = f.solve_banded(&a.dot(&x)).unwrap();
+                assert_close_l2!(&x, &y, $rtol);
+                let y = f.solve_t_banded(&a.t().dot(&x)).unwrap();
+                assert_close_l2!(&x, &y, $rtol);
+                let ah = a.t().mapv(|x| x.conj());
+                let y = f.solve_h_banded(&ah.dot(&x)).unwrap();
+                assert_close_l2!(&x, &y, $rtol);
+
+                // multiple right hand sides in both C and F layout
+                let x: Array2<$scalar> = random_using((6, 3), &mut rng);
+                let y = f.solve_banded_into(a.dot(&x)).unwrap();
+                assert_close_l2!(&x, &y, $rtol);
+                let y = f.solve_banded_into(a.dot(&x).t().to_owned().reversed_axes()).unwrap();
+                assert_close_l2!(&x, &y, $rtol);
+            }
+
+            #[test]
+            fn [<solve_banded_cholesky_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                // Diagonally dominant Hermitian matrix is positive definite
+                let mut a: Array2<$scalar> = band(random_hermite_using(6, &mut rng), 2, 2);
+                for i in 0..6 {
+                    a[(i, i)] += <$scalar>::from_real(10.0);
+                }
+                let b = a.extract_banded(2, 2).unwrap();
+                let x: Array1<$scalar> = random_using(6, &mut rng);
+                for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                    let f = b.factorizec_banded(uplo).unwrap();
+                    let y = f.solve_banded(&a.dot(&x)).unwrap();
+                    assert_close_l2!(&x, &y, $rtol);
+                    let y = f.solve_t_banded(&a.t().dot(&x)).unwrap();
+                    assert_close_l2!(&x, &y, $rtol);
+                }
+            }
+
+            #[test]
+            fn [<eigh_banded_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = band(random_hermite_using(6, &mut rng), 2, 2);
+                let b = a.extract_banded(2, 2).unwrap();
+                let full = a.eigvalsh(UPLO::Lower).unwrap();
+                for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                    let (e, v) = b.eigh_banded(uplo).unwrap();
+                    assert_close_l2!(&e, &full, $rtol);
+                    assert_close_l2!(&b.eigvalsh_banded(uplo).unwrap(), &full, $rtol);
+                    let vh = v.t().mapv(|x| x.conj());
+                    assert_close_l2!(&vh.dot(&v), &Array2::eye(6), $rtol);
+                    for (&e, v) in e.iter().zip(v.axis_iter(Axis(1))) {
+                        let av = a.dot(&v);
+                        let ev = v.mapv(|x| x * <$scalar>::from_real(e));
+                        assert_close_l2!(&av, &ev, $rtol);
+                    }
+
+                    let (e, v) = b.eigh_banded_range(uplo, EigRange::Index(1, 3)).unwrap();
+                    assert_eq!(v.dim(), (6, 3));
+                    assert_close_l2!(&e, &full.slice(s![1..4]).to_owned(), $rtol);
+                    for (&e, v) in e.iter().zip(v.axis_iter(Axis(1))) {
+                        let av = a.dot(&v);
+                        let ev = v.mapv(|x| x * <$scalar>::from_real(e));
+                        assert_close_l2!(&av, &ev, $rtol);
+                    }
+
+                    let vl = (full[2] + full[3]) / 2.0;
+                    let vu = full[5] + 1.0;
+                    let e = b.eigvalsh_banded_range(uplo, EigRange::Value(vl, vu)).unwrap();
+                    assert_close_l2!(&e, &full.slice(s![3..]).to_owned(), $rtol);
+                }
+            }
+        } // paste::item!
+    };
+}
+
+impl_test!(f64, 1e-9);
+impl_test!(f32, 1e-3);
+impl_test!(c64, 1e-9);
+impl_test!(c32, 1e-3);
@@ -0,0 +1,116 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+// Test A = Q B P^H, Q^H Q = I, and P^H P = I
+fn test_bidiagonalize<T: Scalar + Lapack>(a: &Array2<T>, rtol: T::Real) {
+    let (m, n) = a.dim();
+    let k = std::cmp::min(m, n);
+    let (q, b, pt) = a.bidiagonalize().unwrap();
+    assert_eq!(q.dim(), (m, k));
+    assert_eq!(pt.dim(), (k, n));
+    assert_eq!(b.len(), k);
+    assert_eq!(b.uplo, if m >= n { UPLO::Upper } else { UPLO::Lower });
+    let qh = q.t().mapv(|x| x.conj());
+    let p = pt.t().mapv(|x| x.conj());
+    assert_close_l2!(&qh.dot(&q), &Array2::eye(k), rtol);
+    assert_close_l2!(&pt.dot(&p), &Array2::eye(k), rtol);
+    assert_close_l2!(&q.dot(&b.to_dense()).dot(&pt), a, rtol);
+}
+
+// Test B = U diag(s) V^T and compare singular values with the dense SVD
+fn test_svd_bidiagonal<T: Scalar + Lapack>(b: &Bidiagonal<T>, rtol: T::Real) {
+    let dense = b.to_dense();
+    let (_, s_dense, _) = dense.svd(false, false).unwrap();
+    for (u, s, vt) in [
+        b.svd_bidiagonal(true).unwrap(),
+        b.svddc_bidiagonal(true).unwrap(),
+    ] {
+        let u = u.unwrap();
+        let vt = vt.unwrap();
+        let sm = Array2::from_diag(&s.mapv(T::from_real));
+        assert_close_l2!(&u.dot(&sm).dot(&vt), &dense, rtol);
+        assert_close_l2!(&s.mapv(T::from_real), &s_dense.mapv(T::from_real), rtol);
+    }
+    for (u, s, vt) in [
+        b.svd_bidiagonal(false).unwrap(),
+        b.svddc_bidiagonal(false).unwrap(),
+    ] {
+        assert!(u.is_none());
+        assert!(vt.is_none());
+        assert_close_l2!(&s.mapv(T::from_real), &s_dense.mapv(T::from_real), rtol);
+    }
+}
+
+macro_rules! impl_test {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<bidiagonalize_tall_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((5, 3), &mut rng);
+                test_bidiagonalize(&a, $rtol);
+            }
+
+            #[test]
+            fn [<bidiagonalize_tall_ $scalar _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((5, 3).f(), &mut rng);
+                test_bidiagonalize(&a, $rtol);
+            }
+
+            #[test]
+            fn [<bidiagonalize_wide_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((3, 5), &mut rng);
+                test_bidiagonalize(&a, $rtol);
+            }
+
+            #[test]
+            fn [<bidiagonalize_wide_ $scalar _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((3, 5).f(), &mut rng);
+                test_bidiagonalize(&a, $rtol);
+            }
+
+            #[test]
+            fn [<svd_bidiagonal_upper_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((5, 4), &mut rng);
+                let (_, b, _) = a.bidiagonalize().unwrap();
+                test_svd_bidiagonal(&b, $rtol);
+            }
+
+            #[test]
+            fn [<svd_bidiagonal_lower_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((4, 5), &mut rng);
+                let (_, b, _) = a.bidiagonalize().unwrap();
+                test_svd_bidiagonal(&b, $rtol);
+            }
+        }
+    };
+}
+
+impl_test!(f64, 1e-9);
+impl_test!(f32, 1e-4);
+impl_test!(c64, 1e-9);
+impl_test!(c32, 1e-4);
+
+#[test]
+fn bidiagonal_index() {
+    let b = Bidiagonal::<f64> {
+        uplo: UPLO::Lower,
+        d: vec![1.0, 2.0, 3.0],
+        e: vec![4.0, 5.0],
+    };
+    let dense = b.to_dense();
+    assert_eq!(
+        dense,
+        array![[1.0, 0.0, 0.0], [4.0, 2.0, 0.0], [0.0, 5.0, 3.0]]
+    );
+    for ((i, j), x) in dense.indexed_iter() {
+        if i == j || i == j + 1 {
+            assert_eq!(b[(i as i32, j as i32)], *x);
+        }
+    }
+}
@@ -231,3 +231,174 @@ cholesky_solve!(f64, 1e-9);
 cholesky_solve!(f32, 1e-3);
 cholesky_solve!(c64, 1e-9);
 cholesky_solve!(c32, 1e-3);
+
+macro_rules! cholesky_solve_expert {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<cholesky_solve_expert_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hpd_using(3, &mut rng);
+                let x: Array1<$elem> = random_using(3, &mut rng);
+                let b = a.dot(&x);
+                let mut a_f = Array2::zeros(a.dim().f());
+                a_f.assign(&a);
+                for a in [a, a_f] {
+                    let result = a.solvec_expert(&b).unwrap();
+                    assert_close_l2!(&result.x, &x, $rtol);
+                    assert!(result.backward_error < $rtol);
+                    for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                        let f = a.factorizec(uplo).unwrap();
+                        let result = (&a, &f).solvec_expert(&b).unwrap();
+                        assert_close_l2!(&result.x, &x, $rtol);
+                        assert_eq!(result.equilibration, Equilibration::None);
+                    }
+                }
+            }
+        }
+    };
+}
+
+cholesky_solve_expert!(f64, 1e-7);
+cholesky_solve_expert!(f32, 1e-3);
+cholesky_solve_expert!(c64, 1e-7);
+cholesky_solve_expert!(c32, 1e-3);
+
+#[should_panic]
+#[test]
+fn cholesky_solve_expert_shape_mismatch() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_hpd_using(3, &mut rng);
+    let b: Array1<f64> = random_using(2, &mut rng);
+    let _ = a.solvec_expert(&b);
+}
+
+#[should_panic]
+#[test]
+fn cholesky_factorized_solve_expert_shape_mismatch() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_hpd_using(3, &mut rng);
+    let b: Array1<f64> = random_using(2, &mut rng);
+    let f = a.factorizec(UPLO::Upper).unwrap();
+    let _ = (&a, &f).solvec_expert(&b);
+}
+
+macro_rules! cholesky_rcond {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<cholesky_rcond_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hpd_using(5, &mut rng);
+                let rcond = 1. / (a.opnorm_one().unwrap() * a.inv().unwrap().opnorm_one().unwrap());
+                let mut a_f = Array2::zeros(a.dim().f());
+                a_f.assign(&a);
+                for a in [a, a_f] {
+                    for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                        // The estimate of `|A^-1|` is a lower bound
+                        let f = a.factorizec(uplo).unwrap();
+                        let est = (&a, &f).rcond().unwrap();
+                        assert!(est >= rcond * 0.999 && est <= rcond * 10.);
+                    }
+                }
+                let a = Array2::<$elem>::eye(5);
+                let f = a.factorizec(UPLO::Upper).unwrap();
+                assert_aclose!((&a, &f).rcond().unwrap(), 1., 1e-3);
+            }
+        }
+    };
+}
+
+cholesky_rcond!(f64);
+cholesky_rcond!(f32);
+cholesky_rcond!(c64);
+cholesky_rcond!(c32);
+
+macro_rules! cholesky_solve_mixed {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<cholesky_solve_mixed_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hpd_using(40, &mut rng);
+                let x: Array1<$elem> = random_using(40, &mut rng);
+                let b = a.dot(&x);
+                let mut a_f = Array2::zeros(a.dim().f());
+                a_f.assign(&a);
+                for a in [a, a_f] {
+                    let result = a.solvec_mixed(&b).unwrap();
+                    assert_close_l2!(&result.x, &x, 1e-7);
+                }
+            }
+        }
+    };
+}
+
+cholesky_solve_mixed!(f64);
+cholesky_solve_mixed!(c64);
+
+macro_rules! cholesky_pivoted {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<cholesky_pivoted_full_rank_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hpd_using(4, &mut rng);
+                let x: Array1<$elem> = random_using(4, &mut rng);
+                let b = a.dot(&x);
+                let mut a_f = Array2::zeros(a.dim().f());
+                a_f.assign(&a);
+                for a in [a, a_f] {
+                    for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                        let f = a.cholesky_pivoted(uplo, None).unwrap();
+                        assert_eq!(f.rank, 4);
+                        let lr = f.low_rank_factor();
+                        assert_close_l2!(&lr.dot(&lr.t().mapv(|elem| elem.conj())), &a, $rtol);
+                        assert_close_l2!(&f.solvec(&b).unwrap(), &x, $rtol);
+                        let ln_det = a.ln_detc().unwrap();
+                        assert!((f.ln_detc() - ln_det).abs() < $rtol * ln_det.abs().max(1.0));
+                        let ln_pdet = f.ln_pseudo_detc().unwrap();
+                        assert!((ln_pdet - ln_det).abs() < $rtol * ln_det.abs().max(1.0));
+                    }
+                }
+            }
+
+            #[test]
+            fn [<cholesky_pivoted_low_rank_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let g: Array2<$elem> = random_using((6, 3), &mut rng);
+                let a = g.dot(&g.t().mapv(|elem| elem.conj()));
+                let x: Array1<$elem> = random_using(6, &mut rng);
+                let b = a.dot(&x);
+                let mut a_f = Array2::zeros(a.dim().f());
+                a_f.assign(&a);
+                // Tolerance to cut off the rounding errors in the trailing part
+                let tol = a.diag().iter().map(|elem| elem.re()).fold(0.0, |x, y| y.max(x)) * $rtol;
+                for a in [a, a_f] {
+                    for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                        let f = a.cholesky_pivoted(uplo, Some(tol)).unwrap();
+                        assert_eq!(f.rank, 3);
+                        let lr = f.low_rank_factor();
+                        assert_eq!(lr.dim(), (6, 3));
+                        assert_close_l2!(&lr.dot(&lr.t().mapv(|elem| elem.conj())), &a, $rtol);
+                        // `b` is in the range of `A`, and the basic solution satisfies `A x = b`
+                        let y = f.solvec(&b).unwrap();
+                        assert_close_l2!(&a.dot(&y), &b, $rtol);
+                        assert!(f.ln_detc().is_infinite());
+                        // Pseudo-determinant is the product of the non-zero eigenvalues
+                        let mut eigs = a.eigvalsh(UPLO::Lower).unwrap().to_vec();
+                        eigs.sort_by(|x, y| y.partial_cmp(x).unwrap());
+                        let ln_pdet: <$elem as Scalar>::Real = eigs[..3].iter().map(|e| e.ln()).sum();
+                        let ln_pdet_f = f.ln_pseudo_detc().unwrap();
+                        assert!((ln_pdet_f - ln_pdet).abs() < $rtol * 10.0 * ln_pdet.abs().max(1.0));
+                    }
+                }
+            }
+        }
+    };
+}
+
+cholesky_pivoted!(f64, 1e-7);
+cholesky_pivoted!(f32, 1e-3);
+cholesky_pivoted!(c64, 1e-7);
+cholesky_pivoted!(c32, 1e-3);
@@ -1,4 +1,5 @@
 use ndarray::*;
+use ndarray_linalg::error::LinalgError;
 use ndarray_linalg::*;
 
 fn sorted_eigvals<T: Scalar>(eigvals: ArrayView1<'_, T>) -> Array1<T> {
@@ -330,3 +331,271 @@ macro_rules! impl_test_complex {
 
 impl_test_complex!(c32);
 impl_test_complex!(c64);
+
+// Test the eigenpairs and the condition numbers computed by `?geevx` for each balancing option
+fn test_eig_expert<T: Scalar + Lapack>(a: &Array2<T>, answer: &Array1<T::Complex>)
+where
+    T::Complex: Lapack,
+{
+    let ac: Array2<T::Complex> = a.map(|v| v.as_c());
+    let ah = ac.t().mapv(|x| x.conj());
+    for &balance in &[
+        Balance::None,
+        Balance::Permute,
+        Balance::Scale,
+        Balance::Both,
+    ] {
+        let result = a.eig_expert(balance).unwrap();
+        assert_close_l2!(
+            &sorted_eigvals(result.eigvals.view()),
+            &sorted_eigvals(answer.view()),
+            T::real(1e-3)
+        );
+        test_eig(a.view(), result.eigvals.view(), result.right_eigvecs.view());
+        // u_i^H A = λ_i u_i^H <=> A^H u_i = conj(λ_i) u_i
+        let left_eigvals = result.eigvals.mapv(|e| e.conj());
+        test_eig(ah.view(), left_eigvals.view(), result.left_eigvecs.view());
+
+        for i in 0..a.nrows() {
+            let u = result.left_eigvecs.column(i);
+            let v = result.right_eigvecs.column(i);
+            let uv = u
+                .iter()
+                .zip(v)
+                .map(|(x, y)| x.conj() * *y)
+                .sum::<T::Complex>();
+            assert_aclose!(result.eigval_rcond[i], uv.abs(), T::real(1e-3));
+            assert!(result.eigvec_rcond[i] > T::real(0.0));
+        }
+        assert!(result
+            .eigval_error_bounds()
+            .iter()
+            .all(|&e| e < T::real(1e-3)));
+        assert!(result
+            .eigvec_error_bounds()
+            .iter()
+            .all(|&e| e < T::real(1e-3)));
+    }
+}
+
+macro_rules! impl_test_eig_expert {
+    ($elem:ty, $matrix:ident, $matrix_t:ident, $answer:ident) => {
+        paste::item! {
+            #[test]
+            fn [<$elem _eig_expert>]() {
+                test_eig_expert(&$matrix::<$elem>(), &$answer::<$elem>());
+                test_eig_expert(&$matrix_t::<$elem>(), &$answer::<$elem>());
+            }
+
+            #[test]
+            fn [<$elem _eig_expert_ill_conditioned>]() {
+                // Eigenvalues of a non-normal matrix are sensitive to perturbations
+                let a: Array2<$elem> = array![[1.0, 1e3], [0.0, 2.0]].mapv(<$elem>::from_real);
+                let result = a.eig_expert(Balance::None).unwrap();
+                for &r in &result.eigval_rcond {
+                    assert_aclose!(r, 1e-3, 1e-5);
+                }
+                let a: Array2<$elem> = Array2::from_diag(&array![1.0, 2.0].mapv(<$elem>::from_real));
+                let result = a.eig_expert(Balance::Both).unwrap();
+                for &r in &result.eigval_rcond {
+                    assert_aclose!(r, 1.0, 1e-5);
+                }
+            }
+        }
+    };
+}
+
+impl_test_eig_expert!(f32, test_matrix_real, test_matrix_real_t, answer_eig_real);
+impl_test_eig_expert!(f64, test_matrix_real, test_matrix_real_t, answer_eig_real);
+impl_test_eig_expert!(
+    c32,
+    test_matrix_complex,
+    test_matrix_complex_t,
+    answer_eig_complex
+);
+impl_test_eig_expert!(
+    c64,
+    test_matrix_complex,
+    test_matrix_complex_t,
+    answer_eig_complex
+);
+
+// Test the left and right eigenvectors and their normalization u_i^H v_i = 1
+fn test_eig_left_right<T: Scalar + Lapack>(a: &Array2<T>, answer: &Array1<T::Complex>)
+where
+    T::Complex: Lapack,
+{
+    let (eigs, vl, vr) = a.eig_left_right().unwrap();
+    assert_close_l2!(
+        &sorted_eigvals(eigs.view()),
+        &sorted_eigvals(answer.view()),
+        T::real(1e-3)
+    );
+    test_eig(a.view(), eigs.view(), vr.view());
+    // u_i^H A = λ_i u_i^H <=> A^H u_i = conj(λ_i) u_i
+    let ah: Array2<T> = a.t().mapv(|x| x.conj());
+    let left_eigs = eigs.mapv(|e| e.conj());
+    test_eig(ah.view(), left_eigs.view(), vl.view());
+
+    let n = a.nrows();
+    let vlh = vl.t().mapv(|x| x.conj());
+    assert_close_l2!(&vlh.dot(&vr), &Array2::eye(n), T::real(1e-3));
+}
+
+macro_rules! impl_test_eig_left_right {
+    ($elem:ty, $matrix:ident, $matrix_t:ident, $answer:ident) => {
+        paste::item! {
+            #[test]
+            fn [<$elem _eig_left_right>]() {
+                test_eig_left_right(&$matrix::<$elem>(), &$answer::<$elem>());
+            }
+
+            #[test]
+            fn [<$elem _eig_left_right_t>]() {
+                test_eig_left_right(&$matrix_t::<$elem>(), &$answer::<$elem>());
+            }
+        }
+    };
+}
+
+impl_test_eig_left_right!(f32, test_matrix_real, test_matrix_real_t, answer_eig_real);
+impl_test_eig_left_right!(f64, test_matrix_real, test_matrix_real_t, answer_eig_real);
+impl_test_eig_left_right!(
+    c32,
+    test_matrix_complex,
+    test_matrix_complex_t,
+    answer_eig_complex
+);
+impl_test_eig_left_right!(
+    c64,
+    test_matrix_complex,
+    test_matrix_complex_t,
+    answer_eig_complex
+);
+
+macro_rules! impl_test_eig_left_right_defective {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<$elem _eig_left_right_defective>]() {
+                // Jordan block
+                let mut a = Array2::<$elem>::eye(2);
+                a[(0, 1)] = a[(0, 0)];
+                assert!(matches!(
+                    a.eig_left_right(),
+                    Err(LinalgError::DefectiveEigenvalue)
+                ));
+            }
+        }
+    };
+}
+
+impl_test_eig_left_right_defective!(f32);
+impl_test_eig_left_right_defective!(f64);
+impl_test_eig_left_right_defective!(c32);
+impl_test_eig_left_right_defective!(c64);
+
+// Pin where lax::eig::EigWork returns the eigenvectors of a row-major matrix
+fn test_eig_work_row_major<T: Scalar + Lapack>(a: &Array2<T>)
+where
+    T::Complex: Lapack,
+    lax::eig::EigWork<T>: lax::eig::EigWorkImpl<Elem = T>,
+{
+    use lax::eig::*;
+    let n = a.nrows();
+    let l = a.layout().unwrap();
+    assert!(matches!(l, MatrixLayout::C { .. }));
+    let ah: Array2<T> = a.t().mapv(|x| x.conj());
+
+    // The right eigenvectors are returned in `vl` by `EigWork::new`
+    let mut a_ = a.clone();
+    let work = EigWork::<T>::new(true, l).unwrap();
+    let EigOwned { eigs, vr, vl } = work.eval(a_.as_slice_mut().unwrap()).unwrap();
+    assert!(vr.is_none());
+    let v = Array2::from_shape_vec((n, n).f(), vl.unwrap()).unwrap();
+    test_eig(a.view(), ArrayView1::from(&eigs), v.view());
+
+    // and in `vr` by `EigWork::new_left_right`
+    let mut a_ = a.clone();
+    let work = EigWork::<T>::new_left_right(l).unwrap();
+    let EigOwned { eigs, vr, vl } = work.eval(a_.as_slice_mut().unwrap()).unwrap();
+    let vr = Array2::from_shape_vec((n, n).f(), vr.unwrap()).unwrap();
+    test_eig(a.view(), ArrayView1::from(&eigs), vr.view());
+    let vl = Array2::from_shape_vec((n, n).f(), vl.unwrap()).unwrap();
+    let left_eigs = Array1::from(eigs).mapv(|e| e.conj());
+    test_eig(ah.view(), left_eigs.view(), vl.view());
+}
+
+macro_rules! impl_test_eig_work_row_major {
+    ($elem:ty, $matrix:ident) => {
+        paste::item! {
+            #[test]
+            fn [<$elem _eig_work_row_major>]() {
+                test_eig_work_row_major(&$matrix::<$elem>());
+            }
+        }
+    };
+}
+
+impl_test_eig_work_row_major!(f32, test_matrix_real);
+impl_test_eig_work_row_major!(f64, test_matrix_real);
+impl_test_eig_work_row_major!(c32, test_matrix_complex);
+impl_test_eig_work_row_major!(c64, test_matrix_complex);
+
+// Test the real representation A V = V Λ and its consistency with the complex eigenpairs
+fn test_eig_real<T: Scalar<Real = T> + LapackReal + PartialOrd>(
+    a: &Array2<T>,
+    answer: &Array1<T::Complex>,
+) where
+    T::Complex: Lapack,
+{
+    let result = a.eig_real().unwrap();
+    let eigs = result.eigvals();
+    assert_close_l2!(
+        &sorted_eigvals(eigs.view()),
+        &sorted_eigvals(answer.view()),
+        T::real(1e-3)
+    );
+    let v = &result.eigvecs;
+    assert_close_l2!(&a.dot(v), &v.dot(&result.block_diagonal()), T::real(1e-3));
+
+    // Reconstruct complex eigenvectors from the pairs of real columns (Re v, Im v)
+    let n = a.nrows();
+    let mut vc: Array2<T::Complex> = v.map(|x| x.as_c());
+    let mut j = 0;
+    while j < n {
+        let im = result.eigvals_im[j];
+        if im == T::zero() {
+            j += 1;
+            continue;
+        }
+        assert!(im > T::zero());
+        assert_aclose!(result.eigvals_im[j + 1], -im, T::real(1e-5));
+        for i in 0..n {
+            let x = T::complex(v[(i, j)], v[(i, j + 1)]);
+            vc[(i, j)] = x;
+            vc[(i, j + 1)] = x.conj();
+        }
+        j += 2;
+    }
+    test_eig(a.view(), eigs.view(), vc.view());
+}
+
+macro_rules! impl_test_eig_real {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<$elem _eig_real>]() {
+                test_eig_real(&test_matrix_real::<$elem>(), &answer_eig_real::<$elem>());
+            }
+
+            #[test]
+            fn [<$elem _eig_real_t>]() {
+                test_eig_real(&test_matrix_real_t::<$elem>(), &answer_eig_real::<$elem>());
+            }
+        }
+    };
+}
+
+impl_test_eig_real!(f32);
+impl_test_eig_real!(f64);
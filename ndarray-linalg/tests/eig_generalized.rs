@@ -0,0 +1,66 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+// Test beta_i A v_i = alpha_i B v_i for i = 0..n
+fn test_eig_generalized<T: Scalar>(
+    a: ArrayView2<'_, T>,
+    b: ArrayView2<'_, T>,
+    alpha: ArrayView1<'_, T::Complex>,
+    beta: ArrayView1<'_, T>,
+    vecs: ArrayView2<'_, T::Complex>,
+) where
+    T::Complex: Lapack,
+{
+    let a: Array2<T::Complex> = a.map(|v| v.as_c());
+    let b: Array2<T::Complex> = b.map(|v| v.as_c());
+    for ((&alpha, &beta), v) in alpha.iter().zip(beta.iter()).zip(vecs.axis_iter(Axis(1))) {
+        let av = a.dot(&v).mapv(|val| val * beta.as_c());
+        let bv = b.dot(&v).mapv(|val| val * alpha);
+        assert_close_l2!(&av, &bv, T::real(1e-3));
+    }
+}
+
+macro_rules! impl_test {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<eig_generalized_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((4, 4), &mut rng);
+                let b: Array2<$scalar> = random_using((4, 4), &mut rng);
+                let ((alpha, beta), vecs) = (a.view(), b.view()).eig_generalized().unwrap();
+                test_eig_generalized(a.view(), b.view(), alpha.view(), beta.view(), vecs.view());
+
+                let (alpha2, beta2) = (a.view(), b.view()).eigvals_generalized().unwrap();
+                assert_close_l2!(&alpha2, &alpha, 1e-5);
+                assert_close_l2!(&beta2, &beta, 1e-5);
+            }
+
+            #[test]
+            fn [<eig_generalized_ $scalar _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((4, 4).f(), &mut rng);
+                let b: Array2<$scalar> = random_using((4, 4), &mut rng);
+                let ((alpha, beta), vecs) = (a.view(), b.view()).eig_generalized().unwrap();
+                test_eig_generalized(a.view(), b.view(), alpha.view(), beta.view(), vecs.view());
+            }
+
+            #[test]
+            fn [<eig_generalized_ $scalar _infinite>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((4, 4), &mut rng);
+                // singular B has an infinite eigenvalue
+                let mut b: Array2<$scalar> = Array2::eye(4);
+                b[(3, 3)] = <$scalar>::from_real(0.0);
+                let ((alpha, beta), vecs) = (a.view(), b.view()).eig_generalized().unwrap();
+                assert_eq!(beta.iter().filter(|b| b.abs() < 1e-5).count(), 1);
+                test_eig_generalized(a.view(), b.view(), alpha.view(), beta.view(), vecs.view());
+            }
+        } // paste::item!
+    };
+}
+
+impl_test!(f32);
+impl_test!(f64);
+impl_test!(c32);
+impl_test!(c64);
@@ -132,3 +132,119 @@ fn ssqrt_t_lower() {
     println!("ss = {:?}", &ss);
     assert_close_l2!(&ss, &ans, 1e-7);
 }
+
+macro_rules! impl_test_range {
+    ($scalar:ty) => {
+        paste::item! {
+            fn [<test_eigh_range_ $scalar>](a: Array2<$scalar>, uplo: UPLO) {
+                let full = a.eigvalsh(uplo).unwrap();
+
+                let (e, v) = a.eigh_range(uplo, EigRange::Index(1, 3)).unwrap();
+                assert_eq!(v.dim(), (6, 3));
+                assert_close_l2!(&e, &full.slice(s![1..4]).to_owned(), 1e-5);
+                let vh = v.t().mapv(|x| x.conj());
+                assert_close_l2!(&vh.dot(&v), &Array2::eye(3), 1e-5);
+                for (&e, v) in e.iter().zip(v.axis_iter(Axis(1))) {
+                    let av = a.dot(&v);
+                    let ev = v.mapv(|x| x * <$scalar>::from_real(e));
+                    assert_close_l2!(&av, &ev, 1e-5);
+                }
+
+                // interval containing the 3 largest eigenvalues
+                let vl = (full[2] + full[3]) / 2.0;
+                let vu = full[5] + 1.0;
+                let e = a.eigvalsh_range(uplo, EigRange::Value(vl, vu)).unwrap();
+                assert_close_l2!(&e, &full.slice(s![3..]).to_owned(), 1e-5);
+
+                let (e, v) = a.eigh_range(uplo, EigRange::All).unwrap();
+                assert_eq!(v.dim(), (6, 6));
+                assert_close_l2!(&e, &full, 1e-5);
+            }
+
+            #[test]
+            fn [<eigh_range_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_hermite_using(6, &mut rng);
+                [<test_eigh_range_ $scalar>](a.clone(), UPLO::Upper);
+                [<test_eigh_range_ $scalar>](a, UPLO::Lower);
+            }
+
+            #[test]
+            fn [<eigh_range_ $scalar _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_hermite_using(6, &mut rng).reversed_axes();
+                [<test_eigh_range_ $scalar>](a.clone(), UPLO::Upper);
+                [<test_eigh_range_ $scalar>](a, UPLO::Lower);
+            }
+        } // paste::item!
+    };
+}
+
+impl_test_range!(f32);
+impl_test_range!(f64);
+impl_test_range!(c32);
+impl_test_range!(c64);
+
+macro_rules! impl_test_driver {
+    ($scalar:ty) => {
+        paste::item! {
+            fn [<test_eigh_driver_ $scalar>](a: Array2<$scalar>, uplo: UPLO, driver: EighDriver) {
+                let full = a.eigvalsh(uplo).unwrap();
+                let (e, v) = a.eigh_with_driver(uplo, driver).unwrap();
+                assert_close_l2!(&e, &full, 1e-5);
+                let vh = v.t().mapv(|x| x.conj());
+                assert_close_l2!(&vh.dot(&v), &Array2::eye(6), 1e-5);
+                for (&e, v) in e.iter().zip(v.axis_iter(Axis(1))) {
+                    let av = a.dot(&v);
+                    let ev = v.mapv(|x| x * <$scalar>::from_real(e));
+                    assert_close_l2!(&av, &ev, 1e-5);
+                }
+            }
+
+            fn [<test_eigh_generalized_driver_ $scalar>](
+                a: Array2<$scalar>,
+                b: Array2<$scalar>,
+                uplo: UPLO,
+                driver: EighDriver,
+            ) {
+                let (full, _) = (a.clone(), b.clone()).eigh(uplo).unwrap();
+                let (e, (v, _)) = (a.clone(), b.clone()).eigh_with_driver(uplo, driver).unwrap();
+                assert_close_l2!(&e, &full, 1e-4);
+                // Eigenvectors are B-orthonormal
+                let vh = v.t().mapv(|x| x.conj());
+                assert_close_l2!(&vh.dot(&b).dot(&v), &Array2::eye(6), 1e-4);
+                for (&e, v) in e.iter().zip(v.axis_iter(Axis(1))) {
+                    let av = a.dot(&v);
+                    let ebv = b.dot(&v).mapv(|x| x * <$scalar>::from_real(e));
+                    assert_close_l2!(&av, &ebv, 1e-4);
+                }
+            }
+
+            #[test]
+            fn [<eigh_driver_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_hermite_using(6, &mut rng);
+                for &driver in &[EighDriver::QR, EighDriver::DivideAndConquer, EighDriver::RRR] {
+                    [<test_eigh_driver_ $scalar>](a.clone(), UPLO::Upper, driver);
+                    [<test_eigh_driver_ $scalar>](a.clone(), UPLO::Lower, driver);
+                }
+            }
+
+            #[test]
+            fn [<eigh_generalized_driver_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_hermite_using(6, &mut rng);
+                let b: Array2<$scalar> = random_hpd_using(6, &mut rng);
+                for &driver in &[EighDriver::QR, EighDriver::DivideAndConquer, EighDriver::RRR] {
+                    [<test_eigh_generalized_driver_ $scalar>](a.clone(), b.clone(), UPLO::Upper, driver);
+                    [<test_eigh_generalized_driver_ $scalar>](a.clone(), b.clone(), UPLO::Lower, driver);
+                }
+            }
+        } // paste::item!
+    };
+}
+
+impl_test_driver!(f32);
+impl_test_driver!(f64);
+impl_test_driver!(c32);
+impl_test_driver!(c64);
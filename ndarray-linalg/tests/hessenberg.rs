@@ -0,0 +1,62 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+// Test A = Q H Q^H, Q^H Q = I, and H[i, j] = 0 for i > j + 1
+fn test_hessenberg<T: Scalar + Lapack>(a: &Array2<T>, q: &Array2<T>, h: &Array2<T>, rtol: T::Real) {
+    let n = a.nrows();
+    let qh = q.t().mapv(|x| x.conj());
+    assert_close_l2!(&qh.dot(q), &Array2::eye(n), rtol);
+    assert_close_l2!(&q.dot(h).dot(&qh), a, rtol);
+    for ((i, j), h) in h.indexed_iter() {
+        if i > j + 1 {
+            assert_eq!(*h, T::zero(), "h[{}, {}] = {}", i, j, h);
+        }
+    }
+}
+
+macro_rules! impl_test {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<hessenberg_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((6, 6), &mut rng);
+                let (q, h) = a.hessenberg().unwrap();
+                test_hessenberg(&a, &q, &h, $rtol);
+            }
+
+            #[test]
+            fn [<hessenberg_ $scalar _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((6, 6).f(), &mut rng);
+                let (q, h) = a.hessenberg().unwrap();
+                test_hessenberg(&a, &q, &h, $rtol);
+            }
+
+            #[test]
+            fn [<hessenberg_into_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((6, 6), &mut rng);
+                let (q, h) = a.clone().hessenberg_into().unwrap();
+                test_hessenberg(&a, &q, &h, $rtol);
+            }
+        }
+    };
+}
+
+impl_test!(f64, 1e-9);
+impl_test!(f32, 1e-4);
+impl_test!(c64, 1e-9);
+impl_test!(c32, 1e-4);
+
+#[test]
+fn hessenberg_symmetric_is_tridiagonal() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_hermite_using(6, &mut rng);
+    let (_q, h) = a.hessenberg().unwrap();
+    for ((i, j), h) in h.indexed_iter() {
+        if j > i + 1 {
+            assert!(h.abs() < 1e-9, "h[{}, {}] = {}", i, j, h);
+        }
+    }
+}
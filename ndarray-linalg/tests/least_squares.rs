@@ -136,3 +136,105 @@ impl_underdetermined!(f32);
 impl_underdetermined!(f64);
 impl_underdetermined!(c32);
 impl_underdetermined!(c64);
+
+const DRIVERS: [LeastSquaresDriver; 4] = [
+    LeastSquaresDriver::QR,
+    LeastSquaresDriver::CompleteOrthogonal,
+    LeastSquaresDriver::DivideAndConquer,
+    LeastSquaresDriver::SVD,
+];
+
+/// All drivers give the same solution for full-rank matrix
+fn test_driver<T: Scalar + Lapack>(a: Array2<T>) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let b: Array1<T> = random_using(a.nrows(), &mut rng);
+    let expected = a.least_squares(&b).unwrap();
+    for &driver in &DRIVERS {
+        let options = LeastSquaresOptions {
+            driver,
+            rcond: None,
+        };
+        let result = a.least_squares_with_options(&b, options).unwrap();
+        assert_eq!(result.driver, driver);
+        assert_eq!(result.rank, expected.rank);
+        assert_close_max!(&result.solution, &expected.solution, T::real(1.0e-4));
+        match driver {
+            LeastSquaresDriver::DivideAndConquer | LeastSquaresDriver::SVD => {
+                assert_eq!(result.singular_values.len(), 3)
+            }
+            _ => assert!(result.singular_values.is_empty()),
+        }
+    }
+}
+
+/// Rank-deficient matrix `A = u v^T` where the minimum norm solution of `Ax = u` is `v / |v|^2`
+fn test_rank_deficient<T: Scalar + Lapack>() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let u: Array1<T> = random_using(4, &mut rng);
+    let v: Array1<T> = random_using(3, &mut rng);
+    let vh = v.mapv(|x| x.conj());
+    let a = u.clone().insert_axis(Axis(1)).dot(&vh.insert_axis(Axis(0)));
+    let expected = v.mapv(|x| x / T::from_real(v.norm_l2().powi(2)));
+    for &driver in &DRIVERS[1..] {
+        let options = LeastSquaresOptions {
+            driver,
+            rcond: Some(T::real(1.0e-4)),
+        };
+        let result = a.least_squares_with_options(&u, options).unwrap();
+        assert_eq!(result.rank, 1);
+        assert!(result.residual_sum_of_squares.is_none());
+        assert_close_max!(&result.solution, &expected, T::real(1.0e-4));
+    }
+}
+
+/// Matrix with a zero column is rank-deficient also in floating point,
+/// and the rank is detected with the default `rcond` by every rank-revealing driver
+fn test_rank_deficient_default_rcond<T: Scalar + Lapack>() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let mut a: Array2<T> = random_using((4, 3), &mut rng);
+    a.column_mut(2).fill(T::from_real(T::real(0.0)));
+    let b: Array1<T> = random_using(4, &mut rng);
+    let expected = a.slice(s![.., ..2]).least_squares(&b).unwrap();
+    for &driver in &DRIVERS[1..] {
+        let options = LeastSquaresOptions {
+            driver,
+            rcond: None,
+        };
+        let result = a.least_squares_with_options(&b, options).unwrap();
+        assert_eq!(result.rank, 2);
+        assert_close_max!(
+            &result.solution.slice(s![..2]),
+            &expected.solution,
+            T::real(1.0e-4)
+        );
+    }
+}
+
+macro_rules! impl_driver {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<least_squares_ $scalar _driver>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                test_driver::<$scalar>(random_using((3, 3), &mut rng));
+                test_driver::<$scalar>(random_using((4, 3), &mut rng));
+                test_driver::<$scalar>(random_using((3, 4).f(), &mut rng));
+            }
+
+            #[test]
+            fn [<least_squares_ $scalar _rank_deficient>]() {
+                test_rank_deficient::<$scalar>();
+            }
+
+            #[test]
+            fn [<least_squares_ $scalar _rank_deficient_default_rcond>]() {
+                test_rank_deficient_default_rcond::<$scalar>();
+            }
+        }
+    };
+}
+
+impl_driver!(f32);
+impl_driver!(f64);
+impl_driver!(c32);
+impl_driver!(c64);
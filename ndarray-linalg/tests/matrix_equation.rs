@@ -0,0 +1,185 @@
+use ndarray::*;
+use ndarray_linalg::error::LinalgError;
+use ndarray_linalg::*;
+
+macro_rules! matrix_equation {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<sylvester_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                // Shift the spectra of A and -B apart
+                let a: Array2<$elem> =
+                    random_using((4, 4), &mut rng) + Array2::<$elem>::eye(4).mapv(|x| x * <$elem>::from_real(3.0));
+                let b: Array2<$elem> = random_using((3, 3), &mut rng);
+                let c: Array2<$elem> = random_using((4, 3), &mut rng);
+                let mut a_f = Array2::zeros((4, 4).f());
+                a_f.assign(&a);
+                let mut b_f = Array2::zeros((3, 3).f());
+                b_f.assign(&b);
+                for (a, b) in [(&a, &b), (&a_f, &b), (&a, &b_f), (&a_f, &b_f)] {
+                    let x = solve_sylvester(a, b, &c).unwrap();
+                    assert_close_l2!(&(a.dot(&x) + x.dot(b)), &c, $rtol);
+                }
+            }
+
+            #[test]
+            fn [<continuous_lyapunov_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                // Stable matrix
+                let a: Array2<$elem> =
+                    random_using((5, 5), &mut rng) - Array2::<$elem>::eye(5).mapv(|x| x * <$elem>::from_real(3.0));
+                let q: Array2<$elem> = random_hpd_using(5, &mut rng);
+                let mut a_f = Array2::zeros((5, 5).f());
+                a_f.assign(&a);
+                for a in [a, a_f] {
+                    let x = solve_continuous_lyapunov(&a, &q).unwrap();
+                    let ah = a.t().mapv(|x| x.conj());
+                    let xh = x.t().mapv(|x| x.conj());
+                    assert_eq!(x, xh);
+                    assert_close_l2!(&(a.dot(&x) + x.dot(&ah)), &-&q, $rtol);
+                    // Positive definite solution for stable A and positive definite Q
+                    assert!(x.eigvalsh(UPLO::Upper).unwrap().iter().all(|&e| e > 0.0));
+                }
+            }
+
+            #[test]
+            fn [<discrete_lyapunov_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                // Stable matrix in the discrete sense, i.e. the spectral radius is less than 1
+                let a: Array2<$elem> = random_using((5, 5), &mut rng);
+                let a = a.mapv(|x| x * <$elem>::from_real(0.2));
+                let q: Array2<$elem> = random_hpd_using(5, &mut rng);
+                let mut a_f = Array2::zeros((5, 5).f());
+                a_f.assign(&a);
+                for a in [a, a_f] {
+                    let x = solve_discrete_lyapunov(&a, &q).unwrap();
+                    let ah = a.t().mapv(|x| x.conj());
+                    let xh = x.t().mapv(|x| x.conj());
+                    assert_eq!(x, xh);
+                    assert_close_l2!(&(a.dot(&x).dot(&ah) - &x), &-&q, $rtol);
+                    assert!(x.eigvalsh(UPLO::Upper).unwrap().iter().all(|&e| e > 0.0));
+                }
+            }
+
+            #[test]
+            fn [<care_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_using((4, 4), &mut rng);
+                let b: Array2<$elem> = random_using((4, 2), &mut rng);
+                let q: Array2<$elem> = random_hpd_using(4, &mut rng);
+                let r: Array2<$elem> = random_hpd_using(2, &mut rng);
+                let mut a_f = Array2::zeros((4, 4).f());
+                a_f.assign(&a);
+                for a in [a, a_f] {
+                    let (x, k) = solve_care(&a, &b, &q, &r).unwrap();
+                    let ah = a.t().mapv(|x| x.conj());
+                    let bh = b.t().mapv(|x| x.conj());
+                    let xh = x.t().mapv(|x| x.conj());
+                    assert_eq!(x, xh);
+                    assert_close_l2!(&k, &r.inv().unwrap().dot(&bh).dot(&x), $rtol);
+                    let residual = ah.dot(&x) + x.dot(&a) - x.dot(&b).dot(&k);
+                    assert_close_l2!(&residual, &-&q, $rtol);
+                    // Closed-loop system is stable
+                    let eigs = (&a - &b.dot(&k)).eigvals().unwrap();
+                    assert!(eigs.iter().all(|e| e.re < 0.0));
+                }
+            }
+
+            #[test]
+            fn [<dare_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_using((4, 4), &mut rng);
+                // Singular A is also allowed
+                let mut a_singular = a.clone();
+                a_singular.column_mut(0).fill(<$elem>::from_real(0.0));
+                let b: Array2<$elem> = random_using((4, 2), &mut rng);
+                let q: Array2<$elem> = random_hpd_using(4, &mut rng);
+                let r: Array2<$elem> = random_hpd_using(2, &mut rng);
+                let mut a_f = Array2::zeros((4, 4).f());
+                a_f.assign(&a);
+                for a in [a, a_f, a_singular] {
+                    let (x, k) = solve_dare(&a, &b, &q, &r).unwrap();
+                    let ah = a.t().mapv(|x| x.conj());
+                    let bh = b.t().mapv(|x| x.conj());
+                    let xh = x.t().mapv(|x| x.conj());
+                    assert_eq!(x, xh);
+                    let g = &r + &bh.dot(&x).dot(&b);
+                    assert_close_l2!(&g.dot(&k), &bh.dot(&x).dot(&a), $rtol);
+                    let residual = ah.dot(&x).dot(&a) - &x - ah.dot(&x).dot(&b).dot(&k);
+                    assert_close_l2!(&residual, &-&q, $rtol);
+                    // Closed-loop system is stable
+                    let eigs = (&a - &b.dot(&k)).eigvals().unwrap();
+                    assert!(eigs.iter().all(|e| e.norm() < 1.0));
+                }
+            }
+
+            #[test]
+            fn [<riccati_not_stabilizable_ $elem>]() {
+                // Unstable modes cannot be controlled with B = 0
+                let a: Array2<$elem> = Array2::eye(2).mapv(|x: $elem| x * <$elem>::from_real(2.0));
+                let b: Array2<$elem> = Array2::zeros((2, 1));
+                let q: Array2<$elem> = Array2::eye(2);
+                let r: Array2<$elem> = Array2::eye(1);
+                assert!(matches!(
+                    solve_care(&a, &b, &q, &r),
+                    Err(LinalgError::NoStabilizingSolution)
+                ));
+                assert!(matches!(
+                    solve_dare(&a, &b, &q, &r),
+                    Err(LinalgError::NoStabilizingSolution)
+                ));
+                // Eigenvalues of the Hamiltonian matrix on the imaginary axis
+                let zero: Array2<$elem> = Array2::zeros((2, 2));
+                assert!(matches!(
+                    solve_care(&zero, &b, &zero, &r),
+                    Err(LinalgError::NoStabilizingSolution)
+                ));
+                assert!(matches!(
+                    solve_dare(&Array2::eye(2), &b, &zero, &r),
+                    Err(LinalgError::NoStabilizingSolution)
+                ));
+            }
+
+            #[test]
+            fn [<sylvester_singular_ $elem>]() {
+                // A and -B share the eigenvalue 1
+                let a: Array2<$elem> = Array2::eye(3);
+                let b: Array2<$elem> = Array2::eye(2).mapv(|x: $elem| -x);
+                let c: Array2<$elem> = Array2::ones((3, 2));
+                assert!(matches!(
+                    solve_sylvester(&a, &b, &c),
+                    Err(LinalgError::SingularMatrixEquation)
+                ));
+            }
+
+            #[test]
+            fn [<discrete_lyapunov_singular_ $elem>]() {
+                // -1 is an eigenvalue of A
+                let a: Array2<$elem> = Array2::from_diag(&array![-1.0, 0.5].mapv(<$elem>::from_real));
+                let q: Array2<$elem> = Array2::eye(2);
+                assert!(matches!(
+                    solve_discrete_lyapunov(&a, &q),
+                    Err(LinalgError::SingularMatrixEquation)
+                ));
+            }
+
+            #[test]
+            fn [<sylvester_shape_mismatch_ $elem>]() {
+                let a: Array2<$elem> = Array2::eye(3);
+                let b: Array2<$elem> = Array2::eye(2);
+                let c: Array2<$elem> = Array2::ones((2, 3));
+                assert!(solve_sylvester(&a, &b, &c).is_err());
+                assert!(solve_continuous_lyapunov(&a, &b).is_err());
+                assert!(solve_discrete_lyapunov(&a, &b).is_err());
+                assert!(solve_care(&a, &c, &a, &b).is_err());
+                assert!(solve_dare(&a, &a, &a, &b).is_err());
+            }
+        }
+    };
+}
+
+matrix_equation!(f64, 1e-7);
+matrix_equation!(f32, 1e-3);
+matrix_equation!(c64, 1e-7);
+matrix_equation!(c32, 1e-3);
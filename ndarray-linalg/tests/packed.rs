@@ -0,0 +1,118 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+#[test]
+fn extract_packed() {
+    let a: Array2<f64> = arr2(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    let p = a.extract_packed(UPLO::Upper).unwrap();
+    assert_eq!(p.ap, vec![1.0, 2.0, 5.0, 3.0, 6.0, 9.0]);
+    let p = a.extract_packed(UPLO::Lower).unwrap();
+    assert_eq!(p.ap, vec![1.0, 4.0, 7.0, 5.0, 8.0, 9.0]);
+}
+
+#[test]
+fn packed_index() {
+    let a: Array2<f64> = arr2(&[[1.0, 2.0, 3.0], [2.0, 5.0, 6.0], [3.0, 6.0, 9.0]]);
+    for &uplo in &[UPLO::Upper, UPLO::Lower] {
+        let p1 = a.extract_packed(uplo).unwrap();
+        let mut p2 = Array2::<f64>::zeros((3, 3)).extract_packed(uplo).unwrap();
+        for i in 0..3 {
+            for j in 0..3 {
+                if (uplo == UPLO::Upper) == (i <= j) || i == j {
+                    p2[(i, j)] = a[(i as usize, j as usize)];
+                }
+            }
+        }
+        assert_eq!(p1.ap, p2.ap);
+    }
+}
+
+#[should_panic]
+#[test]
+fn packed_index_out_of_triangle() {
+    let p = Array2::<f64>::eye(3).extract_packed(UPLO::Upper).unwrap();
+    let _ = p[(2, 0)];
+}
+
+macro_rules! impl_test {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<to_dense_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_hermite_using(5, &mut rng);
+                for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                    let p = a.extract_packed(uplo).unwrap();
+                    assert_eq!(p.ap.len(), 15);
+                    assert_close_l2!(&p.to_dense(), &a, $rtol);
+                }
+            }
+
+            #[test]
+            fn [<solvec_packed_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_hpd_using(5, &mut rng);
+                let x: Array1<$scalar> = random_using(5, &mut rng);
+                let b = a.dot(&x);
+                for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                    let p = a.extract_packed(uplo).unwrap();
+                    let y = p.solvec(&b).unwrap();
+                    assert_close_l2!(&y, &x, $rtol);
+                    let y = p.factorizec_packed().unwrap().solvec(&b).unwrap();
+                    assert_close_l2!(&y, &x, $rtol);
+                }
+            }
+
+            #[test]
+            fn [<invc_packed_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_hpd_using(5, &mut rng);
+                let ans = a.invc().unwrap();
+                for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                    let p = a.extract_packed(uplo).unwrap();
+                    let inv = p.invc().unwrap();
+                    assert_eq!(inv.uplo, uplo);
+                    assert_close_l2!(&inv.to_dense(), &ans, $rtol);
+                }
+            }
+
+            #[test]
+            fn [<solveh_packed_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_hermite_using(5, &mut rng);
+                let x: Array1<$scalar> = random_using(5, &mut rng);
+                let b = a.dot(&x);
+                for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                    let p = a.extract_packed(uplo).unwrap();
+                    let y = p.solveh(&b).unwrap();
+                    assert_close_l2!(&y, &x, $rtol);
+                    let y = p.factorizeh_packed().unwrap().solveh(&b).unwrap();
+                    assert_close_l2!(&y, &x, $rtol);
+                }
+            }
+
+            #[test]
+            fn [<eigh_packed_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_hermite_using(5, &mut rng);
+                let ans = a.eigvalsh(UPLO::Upper).unwrap();
+                for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                    let p = a.extract_packed(uplo).unwrap();
+                    assert_close_l2!(&p.eigvalsh_packed().unwrap(), &ans, $rtol);
+                    let (e, v) = p.eigh_packed().unwrap();
+                    assert_close_l2!(&e, &ans, $rtol);
+                    for (&e, v) in e.iter().zip(v.axis_iter(Axis(1))) {
+                        let av = a.dot(&v);
+                        let ev = v.mapv(|x| x * <$scalar>::from_real(e));
+                        assert_close_l2!(&av, &ev, $rtol);
+                    }
+                }
+            }
+        } // paste::item!
+    };
+}
+
+impl_test!(f32, 1e-3);
+impl_test!(f64, 1e-7);
+impl_test!(c32, 1e-3);
+impl_test!(c64, 1e-7);
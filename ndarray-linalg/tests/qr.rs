@@ -79,3 +79,46 @@ fn qr_4x3_t() {
     let a = random_using((4, 3).f(), &mut rng);
     test(&a, 4, 3);
 }
+
+fn test_pivoted(a: &Array2<f64>, n: usize, m: usize, mode: QRMode) {
+    let ans = a.clone();
+    let (q, r, p) = a.qr_pivoted(mode).unwrap();
+    let k = match mode {
+        QRMode::Economy => min(n, m),
+        QRMode::Full => n,
+    };
+    assert_eq!(q.dim(), (n, k));
+    assert_eq!(r.dim(), (k, m));
+    assert_close_l2!(&q.t().dot(&q), &Array::eye(k), 1e-7);
+    assert_close_l2!(&q.dot(&r), &ans.select(Axis(1), &p), 1e-7);
+    assert_close_l2!(&r.clone().into_triangular(UPLO::Upper), &r, 1e-7);
+    let d = r.diag();
+    for i in 1..d.len() {
+        assert!(d[i].abs() <= d[i - 1].abs());
+    }
+}
+
+#[test]
+fn qr_pivoted() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    for &(n, m) in &[(3, 3), (3, 4), (4, 3)] {
+        for &mode in &[QRMode::Economy, QRMode::Full] {
+            let a = random_using((n, m), &mut rng);
+            test_pivoted(&a, n, m, mode);
+            let a = random_using((n, m).f(), &mut rng);
+            test_pivoted(&a, n, m, mode);
+        }
+    }
+}
+
+#[test]
+fn rank() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let x: Array2<f64> = random_using((5, 2), &mut rng);
+    let y: Array2<f64> = random_using((2, 4), &mut rng);
+    let a = x.dot(&y);
+    assert_eq!(a.rank(1e-9).unwrap(), 2);
+    assert_eq!(a.t().rank(1e-9).unwrap(), 2);
+    let a: Array2<f64> = random_using((5, 4), &mut rng);
+    assert_eq!(a.rank(1e-9).unwrap(), 4);
+}
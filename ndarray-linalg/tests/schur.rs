@@ -0,0 +1,105 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+// Test A = Q T Z^H and Q^H Q = I
+fn test_schur<T: Scalar + Lapack>(a: &Array2<T>, t: &Array2<T>, q: &Array2<T>, zh: &Array2<T>) {
+    let n = a.nrows();
+    let qh = q.t().mapv(|x| x.conj());
+    assert_close_l2!(&qh.dot(q), &Array2::eye(n), T::real(1e-5));
+    assert_close_l2!(&q.dot(t).dot(zh), a, T::real(1e-5));
+}
+
+// T[i, j] vanishes for j + offset <= i,
+// i.e. `offset = 1` for triangular and `offset = 2` for quasi-triangular matrix
+fn test_quasi_triangular<T: Scalar>(t: &Array2<T>, offset: usize) {
+    let n = t.nrows();
+    for i in 0..n {
+        for j in 0..(i + 1).saturating_sub(offset) {
+            assert!(
+                t[(i, j)].abs() < T::real(1e-7),
+                "t[{}, {}] = {}",
+                i,
+                j,
+                t[(i, j)]
+            );
+        }
+    }
+}
+
+macro_rules! impl_test {
+    ($scalar:ty, $offset:expr) => {
+        paste::item! {
+            #[test]
+            fn [<schur_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((6, 6), &mut rng);
+                let (t, z) = a.schur().unwrap();
+                test_quasi_triangular(&t, $offset);
+                test_schur(&a, &t, &z, &z.t().mapv(|x| x.conj()));
+            }
+
+            #[test]
+            fn [<schur_ $scalar _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((6, 6).f(), &mut rng);
+                let (t, z) = a.schur().unwrap();
+                test_quasi_triangular(&t, $offset);
+                test_schur(&a, &t, &z, &z.t().mapv(|x| x.conj()));
+            }
+
+            #[test]
+            fn [<schur_generalized_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((6, 6), &mut rng);
+                let b: Array2<$scalar> = random_using((6, 6).f(), &mut rng);
+                let (s, t, q, z) = (a.view(), b.view()).schur_generalized().unwrap();
+                test_quasi_triangular(&s, $offset);
+                test_quasi_triangular(&t, 1);
+                let zh = z.t().mapv(|x| x.conj());
+                test_schur(&a, &s, &q, &zh);
+                test_schur(&b, &t, &q, &zh);
+            }
+
+            #[test]
+            fn [<schur_select_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((6, 6), &mut rng);
+                let (t, z, m) = a.schur_select(|e| e.re() < 0.0).unwrap();
+                test_quasi_triangular(&t, $offset);
+                test_schur(&a, &t, &z, &z.t().mapv(|x| x.conj()));
+
+                let stable = a.eigvals().unwrap().iter().filter(|e| e.re() < 0.0).count();
+                assert_eq!(m, stable);
+                let eigs = t.slice(s![..m, ..m]).eigvals().unwrap();
+                assert!(eigs.iter().all(|e| e.re() < 0.0));
+            }
+
+            #[test]
+            fn [<schur_generalized_select_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((6, 6), &mut rng);
+                let b: Array2<$scalar> = random_using((6, 6).f(), &mut rng);
+                let (s, t, q, z, m) = (a.view(), b.view())
+                    .schur_generalized_select(|alpha, beta| alpha.abs() < beta.abs())
+                    .unwrap();
+                test_quasi_triangular(&s, $offset);
+                test_quasi_triangular(&t, 1);
+                let zh = z.t().mapv(|x| x.conj());
+                test_schur(&a, &s, &q, &zh);
+                test_schur(&b, &t, &q, &zh);
+
+                let inside = b.inv().unwrap().dot(&a).eigvals().unwrap().iter().filter(|e| e.abs() < 1.0).count();
+                assert_eq!(m, inside);
+                let s11 = s.slice(s![..m, ..m]);
+                let t11 = t.slice(s![..m, ..m]);
+                let eigs = t11.inv().unwrap().dot(&s11).eigvals().unwrap();
+                assert!(eigs.iter().all(|e| e.abs() < 1.0));
+            }
+        }
+    };
+}
+
+impl_test!(f32, 2);
+impl_test!(f64, 2);
+impl_test!(c32, 1);
+impl_test!(c64, 1);
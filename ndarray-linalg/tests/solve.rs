@@ -280,3 +280,79 @@ fn rcond_identity() {
         rcond_identity!(c32, rows, 1e-3);
     }
 }
+
+#[test]
+fn solve_expert() {
+    macro_rules! solve_expert {
+        ($elem:ty, $rtol:expr) => {
+            let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+            let a_c: Array2<$elem> = random_using((3, 3), &mut rng);
+            let a_f: Array2<$elem> = random_using((3, 3).f(), &mut rng);
+            for a in [a_c, a_f] {
+                let x: Array1<$elem> = random_using(3, &mut rng);
+                let b = a.dot(&x);
+                let result = a.solve_expert(&b).unwrap();
+                assert_close_l2!(&result.x, &x, $rtol);
+                assert!(result.forward_error < 1.0);
+                assert!(result.backward_error < $rtol);
+
+                let f = a.factorize().unwrap();
+                let result = (&a, &f).solve_expert(&b).unwrap();
+                assert_close_l2!(&result.x, &x, $rtol);
+                assert_eq!(result.equilibration, Equilibration::None);
+                assert_aclose!(result.rcond, f.rcond().unwrap(), 0.2);
+            }
+        };
+    }
+    solve_expert!(f64, 1e-7);
+    solve_expert!(f32, 1e-3);
+    solve_expert!(c64, 1e-7);
+    solve_expert!(c32, 1e-3);
+}
+
+#[test]
+fn solve_expert_equilibration() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let mut a: Array2<f64> = random_using((3, 3), &mut rng);
+    a.row_mut(0).mapv_inplace(|x| x * 1e10);
+    let x: Array1<f64> = random_using(3, &mut rng);
+    let b = a.dot(&x);
+    let result = a.solve_expert(&b).unwrap();
+    assert_eq!(result.equilibration, Equilibration::Row);
+    assert_close_l2!(&result.x, &x, 1e-7);
+
+    let result = a.t().solve_expert(&a.t().dot(&x)).unwrap();
+    assert_eq!(result.equilibration, Equilibration::Column);
+    assert_close_l2!(&result.x, &x, 1e-7);
+}
+
+#[test]
+fn solve_mixed() {
+    macro_rules! solve_mixed {
+        ($elem:ty) => {
+            let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+            let a_c: Array2<$elem> = random_using((40, 40), &mut rng);
+            let a_f: Array2<$elem> = random_using((40, 40).f(), &mut rng);
+            for a in [a_c, a_f] {
+                let x: Array1<$elem> = random_using(40, &mut rng);
+                let b = a.dot(&x);
+                let result = a.solve_mixed(&b).unwrap();
+                assert_close_l2!(&result.x, &x, 1e-7);
+            }
+        };
+    }
+    solve_mixed!(f64);
+    solve_mixed!(c64);
+}
+
+#[test]
+fn solve_mixed_fallback() {
+    // Hilbert matrix is too ill-conditioned to be refined from single precision
+    let n = 8;
+    let a: Array2<f64> = Array2::from_shape_fn((n, n), |(i, j)| 1.0 / (i + j + 1) as f64);
+    let b: Array1<f64> = Array1::ones(n);
+    let result = a.solve_mixed(&b).unwrap();
+    assert!(matches!(result.refinement, Refinement::FullPrecision(_)));
+    assert_eq!(result.refinement.iterations(), None);
+    assert_close_l2!(&a.dot(&result.x), &b, 1e-6);
+}
@@ -1,5 +1,6 @@
 use ndarray::*;
 use ndarray_linalg::*;
+use num_traits::Zero;
 
 #[should_panic]
 #[test]
@@ -68,3 +69,258 @@ fn solveh_random_t() {
     let y = f.solveh_into(b).unwrap();
     assert_close_l2!(&x, &y, 1e-7);
 }
+
+#[test]
+fn solveh_random_c64() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_hpd_using(3, &mut rng);
+    let x: Array1<c64> = random_using(3, &mut rng);
+    for a in [a.clone(), a.reversed_axes()] {
+        let b = a.dot(&x);
+        let y = a.solveh(&b).unwrap();
+        assert_close_l2!(&x, &y, 1e-7);
+
+        // Lapack::solveh must regard a row-major matrix in the same way as Lapack::bk
+        let mut f = a.clone();
+        let l = f.layout().unwrap();
+        let ipiv = c64::bk(l, UPLO::Upper, f.as_allocated_mut().unwrap()).unwrap();
+        let mut y = b.clone();
+        c64::solveh(
+            l,
+            UPLO::Upper,
+            f.as_allocated().unwrap(),
+            &ipiv,
+            y.as_slice_mut().unwrap(),
+        )
+        .unwrap();
+        assert_close_l2!(&x, &y, 1e-7);
+    }
+}
+
+#[should_panic]
+#[test]
+fn solveh_expert_shape_mismatch() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_hermite_using(3, &mut rng);
+    let b: Array1<f64> = random_using(2, &mut rng);
+    let _ = a.solveh_expert(&b);
+}
+
+#[should_panic]
+#[test]
+fn factorizeh_solveh_expert_shape_mismatch() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_hermite_using(3, &mut rng);
+    let b: Array1<f64> = random_using(2, &mut rng);
+    let f = a.factorizeh().unwrap();
+    let _ = (&a, &f).solveh_expert(&b);
+}
+
+macro_rules! solveh_expert {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<solveh_expert_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hermite_using(3, &mut rng);
+                let x: Array1<$elem> = random_using(3, &mut rng);
+                let b = a.dot(&x);
+                let mut a_t = Array2::zeros((3, 3).f());
+                a_t.assign(&a);
+                for a in [a, a_t] {
+                    let result = a.solveh_expert(&b).unwrap();
+                    assert_close_l2!(&result.x, &x, $rtol);
+                    assert_eq!(result.equilibration, Equilibration::None);
+
+                    let f = a.factorizeh().unwrap();
+                    let result = (&a, &f).solveh_expert(&b).unwrap();
+                    assert_close_l2!(&result.x, &x, $rtol);
+                }
+            }
+        }
+    };
+}
+
+solveh_expert!(f64, 1e-7);
+solveh_expert!(f32, 1e-3);
+solveh_expert!(c64, 1e-7);
+solveh_expert!(c32, 1e-3);
+
+// Test A = P L D L^H P^T, where L is unit lower triangular and D is tridiagonal
+fn test_ldl<T: Scalar + Lapack>(a: &Array2<T>, f: &BKFactorized<OwnedRepr<T>>, rtol: T::Real) {
+    let n = a.nrows();
+    let (l, d, p) = (f.l(), f.d(), f.p());
+    let lh = l.t().mapv(|x| x.conj());
+    assert_close_l2!(&p.dot(&l).dot(&d).dot(&lh).dot(&p.t()), a, rtol);
+    assert_close_l2!(&p.t().dot(&p), &Array2::eye(n), rtol);
+    for ((i, j), x) in l.indexed_iter() {
+        if i == j {
+            assert_eq!(*x, T::one());
+        } else if i < j {
+            assert_eq!(*x, T::zero());
+        }
+    }
+    for ((i, j), x) in d.indexed_iter() {
+        if i > j + 1 || j > i + 1 {
+            assert_eq!(*x, T::zero());
+        }
+    }
+    if f.strategy() != PivotStrategy::Aasen {
+        // D is block diagonal with 1x1 and 2x2 blocks
+        for i in 1..n.saturating_sub(1) {
+            assert!(d[(i, i - 1)] == T::zero() || d[(i + 1, i)] == T::zero());
+        }
+    }
+}
+
+macro_rules! factorizeh_with {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<factorizeh_with_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hermite_using(5, &mut rng);
+                // Zero diagonal elements force 2x2 pivots
+                let mut a_zero_diag = a.clone();
+                a_zero_diag.diag_mut().fill(Zero::zero());
+                let x: Array1<$elem> = random_using(5, &mut rng);
+                for a in [a, a_zero_diag] {
+                    let b = a.dot(&x);
+                    let eigs = a.eigvalsh(UPLO::Upper).unwrap();
+                    let sign = eigs.iter().map(|e| e.signum()).product::<<$elem as Scalar>::Real>();
+                    let ln_det = eigs.iter().map(|e| e.abs().ln()).sum::<<$elem as Scalar>::Real>();
+                    let mut a_t = Array2::zeros((5, 5).f());
+                    a_t.assign(&a);
+                    for a in [a, a_t] {
+                        for &strategy in &[
+                            PivotStrategy::BunchKaufman,
+                            PivotStrategy::Rook,
+                            PivotStrategy::Aasen,
+                        ] {
+                            let f = a.factorizeh_with(strategy).unwrap();
+                            test_ldl(&a, &f, $rtol);
+                            assert_close_l2!(&f.solveh(&b).unwrap(), &x, $rtol);
+                            assert_close_l2!(&a.dot(&f.invh().unwrap()), &Array2::eye(5), $rtol);
+                            let (s, l) = f.sln_deth();
+                            assert_eq!(s, sign);
+                            assert_aclose!(l, ln_det, $rtol * ln_det.abs().max(1.0));
+
+                            let f = a.clone().factorizeh_into_with(strategy).unwrap();
+                            assert_close_l2!(&f.solveh(&b).unwrap(), &x, $rtol);
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+factorizeh_with!(f64, 1e-7);
+factorizeh_with!(f32, 1e-3);
+factorizeh_with!(c64, 1e-7);
+factorizeh_with!(c32, 1e-3);
+
+macro_rules! inertia {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<inertia_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hermite_using(6, &mut rng);
+                let mut a_zero_diag = a.clone();
+                a_zero_diag.diag_mut().fill(Zero::zero());
+                // Singular matrix of rank 2 with one positive and one negative eigenvalue
+                let v: Array2<$elem> = random_using((6, 2), &mut rng);
+                let vh = v.t().mapv(|x| x.conj());
+                let s: Array2<$elem> = Array2::from_diag(&array![1.0, -1.0].mapv(<$elem>::from_real));
+                let a_singular = v.dot(&s).dot(&vh);
+                for (a, singular) in [(a, false), (a_zero_diag, false), (a_singular, true)] {
+                    let eigs = a.eigvalsh(UPLO::Upper).unwrap();
+                    let mut a_t = Array2::zeros((6, 6).f());
+                    a_t.assign(&a);
+                    for a in [a, a_t] {
+                        let (n_pos, n_neg, n_zero) = a.inertia().unwrap();
+                        assert_eq!(n_pos + n_neg + n_zero, 6);
+                        if singular {
+                            // Zero eigenvalues are perturbed by rounding errors
+                            assert!(n_pos >= 1 && n_neg >= 1);
+                        } else {
+                            assert_eq!(n_pos, eigs.iter().filter(|&&e| e > 0.0).count());
+                            assert_eq!(n_neg, eigs.iter().filter(|&&e| e < 0.0).count());
+                            assert_eq!(n_zero, 0);
+                            for &strategy in &[
+                                PivotStrategy::BunchKaufman,
+                                PivotStrategy::Rook,
+                                PivotStrategy::Aasen,
+                            ] {
+                                let f = a.factorizeh_with(strategy).unwrap();
+                                assert_eq!(f.inertia().unwrap(), (n_pos, n_neg, n_zero));
+                            }
+                        }
+                        // Shift between the sorted eigenvalues
+                        for k in 0..=6 {
+                            let sigma = match k {
+                                0 => eigs[0] - 1.0,
+                                6 => eigs[5] + 1.0,
+                                _ => (eigs[k - 1] + eigs[k]) / 2.0,
+                            };
+                            if k > 0 && k < 6 && (eigs[k] - eigs[k - 1]).abs() < 1e-3 {
+                                continue;
+                            }
+                            assert_eq!(a.count_eigenvalues_below(sigma).unwrap(), k);
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+inertia!(f64);
+inertia!(f32);
+inertia!(c64);
+inertia!(c32);
+
+#[test]
+fn inertia_exact_zero() {
+    let a: Array2<f64> = array![[1.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, -2.0]];
+    assert_eq!(a.inertia().unwrap(), (1, 1, 1));
+    assert!(a.factorizeh().is_err());
+    assert!(a.factorizeh_with(PivotStrategy::Rook).is_err());
+    assert!(a.factorizeh_with(PivotStrategy::Aasen).is_err());
+    assert_eq!(a.count_eigenvalues_below(1.0).unwrap(), 2);
+    assert_eq!(a.count_eigenvalues_below(-3.0).unwrap(), 0);
+}
+
+macro_rules! rcondh {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<rcondh_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hermite_using(5, &mut rng);
+                let rcond = 1. / (a.opnorm_one().unwrap() * a.inv().unwrap().opnorm_one().unwrap());
+                let mut a_t = Array2::zeros((5, 5).f());
+                a_t.assign(&a);
+                for a in [a, a_t] {
+                    // The estimate of `|A^-1|` is a lower bound
+                    let f = a.factorizeh().unwrap();
+                    let est = (&a, &f).rcond().unwrap();
+                    assert!(est >= rcond * 0.999 && est <= rcond * 10.);
+                    for &strategy in &[PivotStrategy::Rook, PivotStrategy::Aasen] {
+                        let f = a.factorizeh_with(strategy).unwrap();
+                        assert_aclose!((&a, &f).rcond().unwrap(), rcond, rcond * 1e-2);
+                    }
+                }
+                let a = Array2::<$elem>::eye(5);
+                let f = a.factorizeh().unwrap();
+                assert_aclose!((&a, &f).rcond().unwrap(), 1., 1e-3);
+            }
+        }
+    };
+}
+
+rcondh!(f64);
+rcondh!(f32);
+rcondh!(c64);
+rcondh!(c32);
@@ -92,3 +92,75 @@ test_svd_impl!(c64, test, 3, 4);
 test_svd_impl!(c64, test_no_vt, 3, 4);
 test_svd_impl!(c64, test_no_u, 3, 4);
 test_svd_impl!(c64, test_diag_only, 3, 4);
+
+fn test_gsvd<T: Scalar + Lapack>(a: &Array2<T>, b: &Array2<T>) {
+    let (m, n) = a.dim();
+    let p = b.nrows();
+    let GSVDResult {
+        u,
+        v,
+        q,
+        alpha,
+        beta,
+        k,
+        l,
+        r,
+    } = (a.clone(), b.clone()).gsvd(true, true, true).unwrap();
+    let u = u.unwrap();
+    let v = v.unwrap();
+    let q = q.unwrap();
+    assert_eq!(u.dim(), (m, m));
+    assert_eq!(v.dim(), (p, p));
+    assert_eq!(q.dim(), (n, n));
+    assert_eq!(r.dim(), (k + l, k + l));
+    for i in 0..(k + l) {
+        assert_rclose!(
+            alpha[i].powi(2) + beta[i].powi(2),
+            T::real(1.0),
+            T::real(1e-7)
+        );
+    }
+
+    let mut d1 = Array2::<T>::zeros((m, k + l));
+    for i in 0..min(m, k + l) {
+        d1[(i, i)] = T::from_real(alpha[i]);
+    }
+    let mut d2 = Array2::<T>::zeros((p, k + l));
+    for i in 0..l {
+        d2[(i, k + i)] = T::from_real(beta[k + i]);
+    }
+    let mut zr = Array2::<T>::zeros((k + l, n));
+    zr.slice_mut(s![.., (n - k - l)..]).assign(&r);
+    let qh = q.t().mapv(|x| x.conj());
+    assert_close_l2!(&u.dot(&d1).dot(&zr).dot(&qh), a, T::real(1e-7));
+    assert_close_l2!(&v.dot(&d2).dot(&zr).dot(&qh), b, T::real(1e-7));
+}
+
+macro_rules! test_gsvd_impl {
+    ($type:ty, $m:expr, $n:expr, $p:expr) => {
+        paste::item! {
+            #[test]
+            fn [<gsvd_ $type _ $m x $n x $p>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a = random_using(($m, $n), &mut rng);
+                let b = random_using(($p, $n), &mut rng);
+                test_gsvd::<$type>(&a, &b);
+            }
+
+            #[test]
+            fn [<gsvd_ $type _ $m x $n x $p _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a = random_using(($m, $n).f(), &mut rng);
+                let b = random_using(($p, $n).f(), &mut rng);
+                test_gsvd::<$type>(&a, &b);
+            }
+        }
+    };
+}
+
+test_gsvd_impl!(f64, 5, 3, 4);
+test_gsvd_impl!(f64, 3, 3, 3);
+test_gsvd_impl!(f64, 2, 4, 3);
+test_gsvd_impl!(c64, 5, 3, 4);
+test_gsvd_impl!(c64, 3, 3, 3);
+test_gsvd_impl!(c64, 2, 4, 3);
@@ -1,5 +1,6 @@
 use ndarray::*;
 use ndarray_linalg::*;
+use num_traits::One;
 
 fn test1d<A, Sa, Sb>(uplo: UPLO, a: &ArrayBase<Sa, Ix2>, b: &ArrayBase<Sb, Ix1>, tol: A::Real)
 where
@@ -130,3 +131,141 @@ fn triangular_2d_upper_t_bt() {
     let a: Array2<f64> = random_using((3, 3).f(), &mut rng).into_triangular(UPLO::Upper);
     test2d(UPLO::Upper, &a, &b, 1e-7);
 }
+
+// Triangular matrix with random elements in the other triangular part,
+// which must not be referenced
+fn random_triangular<A: Scalar + Lapack>(
+    n: usize,
+    uplo: UPLO,
+    diag: Diag,
+    f: bool,
+    rng: &mut rand_pcg::Mcg128Xsl64,
+) -> (Array2<A>, Array2<A>) {
+    let mut a: Array2<A> = if f {
+        random_using((n, n).f(), rng)
+    } else {
+        random_using((n, n), rng)
+    };
+    // Shift diagonal elements to be well-conditioned
+    for i in 0..n {
+        a[(i, i)] += A::from_real(A::real(n as f64));
+    }
+    let mut t = a.clone().into_triangular(uplo);
+    if diag == Diag::Unit {
+        t.diag_mut().fill(A::one());
+    }
+    (a, t)
+}
+
+macro_rules! triangular_ext {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<inv_triangular_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                    for &diag in &[Diag::NonUnit, Diag::Unit] {
+                        for &f in &[false, true] {
+                            let (a, t) = random_triangular::<$elem>(4, uplo, diag, f, &mut rng);
+                            let inv = a.inv_triangular(uplo, diag).unwrap();
+                            let mut inv = inv.into_triangular(uplo);
+                            if diag == Diag::Unit {
+                                inv.diag_mut().fill(One::one());
+                            }
+                            assert_close_l2!(&t.dot(&inv), &Array2::eye(4), $rtol);
+                            // The other triangular part is left unchanged
+                            let inv = a.clone().inv_triangular_into(uplo, diag).unwrap();
+                            for ((i, j), x) in inv.indexed_iter() {
+                                if (uplo == UPLO::Upper && i > j) || (uplo == UPLO::Lower && i < j) {
+                                    assert_eq!(*x, a[(i, j)]);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            fn [<rcond_triangular_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                    for &diag in &[Diag::NonUnit, Diag::Unit] {
+                        for &f in &[false, true] {
+                            let (a, t) = random_triangular::<$elem>(4, uplo, diag, f, &mut rng);
+                            let rcond = 1. / (t.opnorm_one().unwrap() * t.inv().unwrap().opnorm_one().unwrap());
+                            // The estimate of `|A^-1|` is a lower bound
+                            let est = a.rcond_triangular(uplo, diag).unwrap();
+                            assert!(est >= rcond * 0.999 && est <= rcond * 10.);
+                        }
+                    }
+                }
+                let a = Array2::<$elem>::eye(4);
+                assert_aclose!(a.rcond_triangular(UPLO::Upper, Diag::NonUnit).unwrap(), 1., 1e-3);
+            }
+
+            #[test]
+            fn [<multiply_triangular_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                    for &diag in &[Diag::NonUnit, Diag::Unit] {
+                        for &fa in &[false, true] {
+                            let (a, t) = random_triangular::<$elem>(4, uplo, diag, fa, &mut rng);
+                            for &fb in &[false, true] {
+                                let (b_left, b_right): (Array2<$elem>, Array2<$elem>) = if fb {
+                                    (random_using((4, 3).f(), &mut rng), random_using((3, 4).f(), &mut rng))
+                                } else {
+                                    (random_using((4, 3), &mut rng), random_using((3, 4), &mut rng))
+                                };
+                                let ab = a.multiply_triangular(Side::Left, uplo, diag, &b_left).unwrap();
+                                assert_close_l2!(&ab, &t.dot(&b_left), $rtol);
+                                let ba = a.multiply_triangular(Side::Right, uplo, diag, &b_right).unwrap();
+                                assert_close_l2!(&ba, &b_right.dot(&t), $rtol);
+                                let mut b = b_left.clone();
+                                a.multiply_triangular_inplace(Side::Left, uplo, diag, &mut b).unwrap();
+                                assert_close_l2!(&b, &t.dot(&b_left), $rtol);
+                            }
+                            let x: Array1<$elem> = random_using(4, &mut rng);
+                            let ax = a.multiply_triangular(Side::Left, uplo, diag, &x).unwrap();
+                            assert_close_l2!(&ax, &t.dot(&x), $rtol);
+                            let xa = a.multiply_triangular(Side::Right, uplo, diag, &x).unwrap();
+                            assert_close_l2!(&xa, &x.dot(&t), $rtol);
+                        }
+                    }
+                }
+                // Mismatched shape is an error
+                let a = Array2::<$elem>::eye(4);
+                let b: Array2<$elem> = Array2::zeros((3, 4));
+                assert!(a.multiply_triangular(Side::Left, UPLO::Upper, Diag::NonUnit, &b).is_err());
+            }
+
+            #[test]
+            fn [<triangular_factors_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                // R of QR decomposition
+                let a: Array2<$elem> = random_using((6, 4), &mut rng);
+                let (q, r) = a.qr().unwrap();
+                let r_inv = r.inv_triangular(UPLO::Upper, Diag::NonUnit).unwrap();
+                assert_close_l2!(&r.dot(&r_inv), &Array2::eye(4), $rtol);
+                let qr = r.multiply_triangular(Side::Right, UPLO::Upper, Diag::NonUnit, &q).unwrap();
+                assert_close_l2!(&qr, &a, $rtol);
+                assert!(r.rcond_triangular(UPLO::Upper, Diag::NonUnit).unwrap() > 0.);
+
+                // U of Cholesky decomposition A = U^H U
+                let a: Array2<$elem> = random_hpd_using(4, &mut rng);
+                for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                    let u = a.factorizec(uplo).unwrap().into_upper();
+                    let uh = u.t().mapv(|x| x.conj());
+                    let uhu = u.multiply_triangular(Side::Right, UPLO::Upper, Diag::NonUnit, &uh).unwrap();
+                    assert_close_l2!(&uhu, &a, $rtol);
+                    let u_inv = u.inv_triangular(UPLO::Upper, Diag::NonUnit).unwrap();
+                    assert_close_l2!(&u.dot(&u_inv), &Array2::eye(4), $rtol);
+                }
+            }
+        }
+    };
+}
+
+triangular_ext!(f64, 1e-9);
+triangular_ext!(f32, 1e-4);
+triangular_ext!(c64, 1e-9);
+triangular_ext!(c32, 1e-4);
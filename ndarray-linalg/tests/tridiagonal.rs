@@ -265,3 +265,72 @@ fn rcond_tridiagonal_identity() {
         rcond_identity!(c32, rows, 1e-3);
     }
 }
+
+/// Random symmetric/Hermitian tridiagonal matrix as a dense matrix
+fn random_hermite_tridiagonal<A: Scalar + Lapack>(n: usize) -> Array2<A> {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<A> = random_hermite_using(n, &mut rng);
+    Array2::from_shape_fn((n, n), |(i, j)| {
+        if i.abs_diff(j) <= 1 {
+            a[(i, j)]
+        } else {
+            A::zero()
+        }
+    })
+}
+
+macro_rules! eigh_tridiagonal {
+    ($elem:ty, $atol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<eigh_tridiagonal_ $elem>]() {
+                let a: Array2<$elem> = random_hermite_tridiagonal(6);
+                let t = a.extract_tridiagonal().unwrap();
+                let e_dense = a.eigvalsh(UPLO::Lower).unwrap();
+
+                let (e, v) = t.eigh_tridiagonal().unwrap();
+                assert_close_l2!(&e, &e_dense, $atol);
+                assert_close_l2!(&a.dot(&v), &v.dot(&Array2::from_diag(&e.mapv(<$elem>::from_real))), $atol);
+                assert_close_l2!(&t.eigvalsh_tridiagonal().unwrap(), &e_dense, $atol);
+
+                let (e, v) = t.eigh_tridiagonal_range(EigRange::Index(1, 3)).unwrap();
+                assert_eq!(v.dim(), (6, 3));
+                assert_close_l2!(&e, &e_dense.slice(s![1..4]).to_owned(), $atol);
+                assert_close_l2!(&a.dot(&v), &v.dot(&Array2::from_diag(&e.mapv(<$elem>::from_real))), $atol);
+
+                let (e, v) = t.eigh_tridiagonal_inverse_iteration(EigRange::Index(1, 3)).unwrap();
+                assert_eq!(v.dim(), (6, 3));
+                assert_close_l2!(&e, &e_dense.slice(s![1..4]).to_owned(), $atol);
+                assert_close_l2!(&a.dot(&v), &v.dot(&Array2::from_diag(&e.mapv(<$elem>::from_real))), $atol);
+
+                // Eigenvalues in (vl, vu] between the 2nd and 5th eigenvalues
+                let vl = (e_dense[1] + e_dense[2]) / 2.0;
+                let vu = (e_dense[4] + e_dense[5]) / 2.0;
+                let e = t.eigvalsh_tridiagonal_range(EigRange::Value(vl, vu)).unwrap();
+                assert_close_l2!(&e, &e_dense.slice(s![2..5]).to_owned(), $atol);
+                let (e, _) = t.eigh_tridiagonal_inverse_iteration(EigRange::Value(vl, vu)).unwrap();
+                assert_close_l2!(&e, &e_dense.slice(s![2..5]).to_owned(), $atol);
+            }
+        }
+    };
+}
+
+eigh_tridiagonal!(f64, 1e-9);
+eigh_tridiagonal!(f32, 1e-4);
+eigh_tridiagonal!(c64, 1e-9);
+eigh_tridiagonal!(c32, 1e-4);
+
+#[test]
+fn eigh_tridiagonal_split() {
+    // Block diagonal matrix consists of two split-off blocks
+    let a: Array2<f64> = arr2(&[
+        [4.0, 1.0, 0.0, 0.0],
+        [1.0, 4.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 1.0],
+        [0.0, 0.0, 1.0, 1.0],
+    ]);
+    let t = a.extract_tridiagonal().unwrap();
+    let (e, v) = t.eigh_tridiagonal_inverse_iteration(EigRange::All).unwrap();
+    assert_close_l2!(&e, &arr1(&[0.0, 2.0, 3.0, 5.0]), 1e-9);
+    assert_close_l2!(&a.dot(&v), &v.dot(&Array2::from_diag(&e)), 1e-9);
+}